@@ -0,0 +1,152 @@
+//! 以表格、JSON 或 YAML 输出系统信息的命令行工具
+
+mod report;
+mod table;
+
+use std::io::{self, IsTerminal, Write};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use system_info::{Subsystem, SystemInfo, parse_interval};
+
+use crate::report::Report;
+
+#[derive(Debug, Parser)]
+#[command(
+	name = "system-info",
+	version,
+	about = "Print system information as tables, JSON or YAML"
+)]
+struct Args {
+	#[command(subcommand)]
+	command: Command,
+	/// Print JSON instead of a table
+	#[arg(long, global = true, conflicts_with = "yaml")]
+	json: bool,
+	/// Print YAML instead of a table
+	#[arg(long, global = true)]
+	yaml: bool,
+	/// Sample again every given number of seconds until interrupted
+	#[arg(short, long, global = true, value_name = "SECONDS", value_parser = parse_interval)]
+	watch: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, Subcommand)]
+pub(crate) enum Command {
+	/// Host name, operating system, uptime and virtualization
+	Host,
+	/// CPU model, usage, frequency and per-core statistics
+	Cpu,
+	/// Memory and swap usage
+	Memory,
+	/// Mounted partitions and disk throughput
+	Disk,
+	/// Network interfaces, addresses and throughput
+	Network,
+	/// All processes as a tree, or a single process and its children
+	Process {
+		/// Process ID, lists all processes when omitted
+		pid: Option<u32>,
+	},
+	/// Model, memory and usage of every GPU
+	Gpu,
+	/// Everything above plus the system load
+	All,
+}
+
+impl Command {
+	/// 需要刷新的模块
+	fn subsystems(self) -> Vec<Subsystem> {
+		match self {
+			Self::Host => vec![Subsystem::Host],
+			Self::Cpu => vec![Subsystem::Cpu],
+			Self::Memory => vec![Subsystem::Memory],
+			Self::Disk => vec![Subsystem::Disk],
+			Self::Network => vec![Subsystem::Network],
+			// 进程表包含各进程的GPU占用
+			Self::Process { .. } => vec![Subsystem::Process, Subsystem::Gpu],
+			Self::Gpu => vec![Subsystem::Gpu],
+			Self::All => Subsystem::ALL.to_vec(),
+		}
+	}
+}
+
+/// 输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+	Table,
+	Json,
+	Yaml,
+}
+
+impl Format {
+	/// 持续刷新时 JSON 每次输出一行，YAML 每次输出一个以 `---` 开头的文档
+	fn render(self, report: &Report, watch: bool) -> Result<String, String> {
+		match self {
+			Self::Table => Ok(table::render(report)),
+			Self::Json if watch => {
+				serde_json::to_string(report).map(|json| json + "\n").map_err(|err| err.to_string())
+			}
+			Self::Json => serde_json::to_string_pretty(report)
+				.map(|json| json + "\n")
+				.map_err(|err| err.to_string()),
+			Self::Yaml => serde_norway::to_string(report)
+				.map(|yaml| if watch { format!("---\n{yaml}") } else { yaml })
+				.map_err(|err| err.to_string()),
+		}
+	}
+}
+
+fn main() -> ExitCode {
+	let args = Args::parse();
+	let format = if args.json {
+		Format::Json
+	} else if args.yaml {
+		Format::Yaml
+	} else {
+		Format::Table
+	};
+	// 终端中持续刷新表格时清屏后重绘，重定向到文件或管道时依次追加
+	let clear = format == Format::Table && args.watch.is_some() && io::stdout().is_terminal();
+
+	let subsystems = args.command.subsystems();
+	let mut system_info = SystemInfo::sampled(&subsystems);
+
+	let mut stdout = io::stdout().lock();
+	loop {
+		let output = match Report::collect(&system_info, args.command)
+			.and_then(|report| format.render(&report, args.watch.is_some()))
+		{
+			Ok(output) => output,
+			Err(err) => {
+				eprintln!("system-info: {err}");
+				return ExitCode::FAILURE;
+			}
+		};
+		let written = if clear {
+			write!(stdout, "\x1b[2J\x1b[H{output}")
+		} else {
+			write!(stdout, "{output}")
+		};
+		match written.and_then(|()| stdout.flush()) {
+			Ok(()) => {}
+			// 输出被 `head` 等提前关闭时正常退出
+			Err(err) if err.kind() == io::ErrorKind::BrokenPipe => return ExitCode::SUCCESS,
+			Err(err) => {
+				eprintln!("system-info: failed to write output: {err}");
+				return ExitCode::FAILURE;
+			}
+		}
+
+		let Some(interval) = args.watch else {
+			return ExitCode::SUCCESS;
+		};
+		thread::sleep(interval);
+		if format == Format::Table && !clear {
+			let _ = writeln!(stdout);
+		}
+		system_info.refresh(&subsystems);
+	}
+}
@@ -0,0 +1,47 @@
+use serde::Serialize;
+use system_info::{
+	CpuInfo, DiskInfo, GpuInfo, HostInfo, MemoryInfo, NetworkInfo, ProcessInfo, Snapshot,
+	SystemInfo,
+};
+
+use crate::Command;
+
+/// 单次采集的结果，序列化时直接输出内部的信息类型
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub(crate) enum Report {
+	Host(HostInfo),
+	Cpu(CpuInfo),
+	Memory(MemoryInfo),
+	Disk(DiskInfo),
+	Network(Vec<NetworkInfo>),
+	/// 指定进程及其子进程
+	Process(ProcessInfo),
+	/// 所有顶层进程，子进程位于各进程的 `sub_list` 中
+	Processes(Vec<ProcessInfo>),
+	/// 未检测到GPU时为空列表
+	Gpu(Vec<GpuInfo>),
+	All(Box<Snapshot>),
+}
+
+impl Report {
+	/// 基于采样器最近一次刷新的数据构建，指定的进程不存在时返回错误
+	pub(crate) fn collect(system_info: &SystemInfo, command: Command) -> Result<Self, String> {
+		Ok(match command {
+			Command::Host => Self::Host(system_info.host()),
+			Command::Cpu => Self::Cpu(system_info.cpu()),
+			Command::Memory => Self::Memory(system_info.memory()),
+			Command::Disk => Self::Disk(system_info.disk()),
+			Command::Network => Self::Network(system_info.network()),
+			Command::Process { pid: None } => Self::Processes(system_info.process_all()),
+			Command::Process { pid: Some(pid) } => Self::Process(
+				system_info
+					.process_tree()
+					.subtree(pid)
+					.ok_or_else(|| format!("no process with pid {pid}"))?,
+			),
+			Command::Gpu => Self::Gpu(system_info.gpus()),
+			Command::All => Self::All(Box::new(system_info.snapshot())),
+		})
+	}
+}
@@ -0,0 +1,429 @@
+use std::fmt::Write;
+
+use system_info::{
+	ByteSize, CpuInfo, DiskInfo, GpuInfo, HostInfo, LoadInfo, MemoryInfo, NetworkInfo, Pressure,
+	ProcessInfo, Snapshot, format_duration,
+};
+
+use crate::report::Report;
+
+/// 缺失值的占位符
+const NONE: &str = "-";
+
+/// 列对齐方式，数值列右对齐
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+	Left,
+	Right,
+}
+
+/// 带表头、按列宽对齐的表格
+#[derive(Debug)]
+struct Table {
+	columns: Vec<(&'static str, Align)>,
+	rows: Vec<Vec<String>>,
+}
+
+impl Table {
+	fn new(columns: &[(&'static str, Align)]) -> Self {
+		Self { columns: columns.to_vec(), rows: Vec::new() }
+	}
+
+	fn row(&mut self, cells: Vec<String>) {
+		self.rows.push(cells);
+	}
+
+	fn render(&self, out: &mut String) {
+		let mut widths: Vec<usize> = self.columns.iter().map(|(name, _)| name.len()).collect();
+		for row in &self.rows {
+			for (width, cell) in widths.iter_mut().zip(row) {
+				*width = (*width).max(cell.chars().count());
+			}
+		}
+
+		let header = self.columns.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+		for cells in std::iter::once(&header).chain(&self.rows) {
+			let mut line = String::new();
+			for (index, ((_, align), cell)) in self.columns.iter().zip(cells).enumerate() {
+				if index > 0 {
+					line.push_str("  ");
+				}
+				let pad = widths[index].saturating_sub(cell.chars().count());
+				match align {
+					Align::Left => {
+						line.push_str(cell);
+						line.extend(std::iter::repeat_n(' ', pad));
+					}
+					Align::Right => {
+						line.extend(std::iter::repeat_n(' ', pad));
+						line.push_str(cell);
+					}
+				}
+			}
+			out.push_str(line.trim_end());
+			out.push('\n');
+		}
+	}
+}
+
+/// 名称对齐的键值列表
+fn fields(out: &mut String, fields: &[(&str, String)]) {
+	let width = fields.iter().map(|(name, _)| name.chars().count()).max().unwrap_or(0);
+	for (name, value) in fields {
+		let _ = writeln!(out, "{name:<width$}  {value}");
+	}
+}
+
+/// 按报告类型渲染为表格
+pub(crate) fn render(report: &Report) -> String {
+	let mut out = String::new();
+	match report {
+		Report::Host(host) => render_host(&mut out, host),
+		Report::Cpu(cpu) => render_cpu(&mut out, cpu),
+		Report::Memory(memory) => render_memory(&mut out, memory),
+		Report::Disk(disk) => render_disk(&mut out, disk),
+		Report::Network(networks) => render_networks(&mut out, networks),
+		Report::Process(process) => render_process(&mut out, process),
+		Report::Processes(processes) => render_processes(&mut out, processes),
+		Report::Gpu(gpus) => render_gpus(&mut out, gpus),
+		Report::All(snapshot) => render_snapshot(&mut out, snapshot),
+	}
+	out
+}
+
+fn render_snapshot(out: &mut String, snapshot: &Snapshot) {
+	let mut section = |title: &str, render: &dyn Fn(&mut String)| {
+		if !out.is_empty() {
+			out.push('\n');
+		}
+		let _ = writeln!(out, "{title}\n{}", "-".repeat(title.len()));
+		render(out);
+	};
+
+	section("Host", &|out| render_host(out, &snapshot.host));
+	section("Load", &|out| render_load(out, &snapshot.load));
+	section("CPU", &|out| render_cpu(out, &snapshot.cpu));
+	section("Memory", &|out| render_memory(out, &snapshot.memory));
+	section("Disk", &|out| render_disk(out, &snapshot.disk));
+	section("Network", &|out| render_networks(out, &snapshot.networks));
+	section("GPU", &|out| render_gpus(out, &snapshot.gpus));
+	section("Processes", &|out| render_processes(out, &snapshot.processes));
+}
+
+fn render_host(out: &mut String, host: &HostInfo) {
+	fields(
+		out,
+		&[
+			("Host name", host.host_name.clone()),
+			("OS", format!("{} {}", host.os_name, host.os_version)),
+			("OS type", host.os_type.clone()),
+			("Arch", host.arch.clone()),
+			("Time zone", host.time_zone.clone()),
+			("Boot time", host.boot_time.format("%Y-%m-%d %H:%M:%S UTC").to_string()),
+			("Uptime", format_duration(host.uptime)),
+			("Virtualization", host.virtualization.to_string()),
+		],
+	);
+}
+
+fn render_load(out: &mut String, load: &LoadInfo) {
+	let mut rows = vec![
+		("Load average", format!("{:.2} {:.2} {:.2}", load.one, load.five, load.fifteen)),
+		(
+			"Tasks",
+			match (load.running_tasks, load.total_tasks) {
+				(Some(running), Some(total)) => format!("{running} running / {total} total"),
+				_ => NONE.to_string(),
+			},
+		),
+	];
+	if let Some(pressure) = &load.pressure {
+		let avg10 = |pressure: Option<Pressure>| {
+			pressure.map_or_else(|| NONE.to_string(), |p| format!("{:.2}%", p.some.avg10))
+		};
+		rows.push(("CPU pressure", avg10(pressure.cpu)));
+		rows.push(("Memory pressure", avg10(pressure.memory)));
+		rows.push(("IO pressure", avg10(pressure.io)));
+	}
+	fields(out, &rows);
+}
+
+fn render_cpu(out: &mut String, cpu: &CpuInfo) {
+	let mut rows = vec![
+		("Model", cpu.model_name.clone()),
+		("Cores", format!("{} physical / {} logical", cpu.physical_cores, cpu.logical_cores)),
+		("Frequency", format!("{:.2} GHz", cpu.frequency)),
+		("Usage", percent(cpu.usage)),
+		("Temperature", cpu.temperature.map_or_else(|| NONE.to_string(), |t| format!("{t:.1} °C"))),
+	];
+	if let Some(times) = &cpu.times {
+		rows.push((
+			"Time",
+			format!(
+				"{:.1}% user, {:.1}% system, {:.1}% idle, {:.1}% iowait, {:.1}% steal",
+				times.user, times.system, times.idle, times.iowait, times.steal
+			),
+		));
+	}
+	fields(out, &rows);
+
+	if cpu.cores.is_empty() {
+		return;
+	}
+	out.push('\n');
+	let mut table = Table::new(&[
+		("CPU", Align::Right),
+		("CORE", Align::Right),
+		("SOCKET", Align::Right),
+		("USAGE", Align::Right),
+		("FREQ", Align::Right),
+	]);
+	for core in &cpu.cores {
+		table.row(vec![
+			core.id.to_string(),
+			optional(core.core_id),
+			optional(core.package_id),
+			percent(Some(core.usage)),
+			format!("{:.2} GHz", core.frequency),
+		]);
+	}
+	table.render(out);
+}
+
+fn render_memory(out: &mut String, memory: &MemoryInfo) {
+	let mut rows = vec![
+		("Total", memory.total.to_string()),
+		("Used", format!("{} ({})", memory.used, percent(Some(memory.usage)))),
+		("Free", memory.free.to_string()),
+		("Available", bytes(memory.available)),
+		("Buffers", bytes(memory.buffers)),
+		("Cached", bytes(memory.cached)),
+		("Shared", bytes(memory.shared)),
+	];
+	if let Some(swap_total) = memory.swap_total {
+		rows.push(("Swap total", swap_total.to_string()));
+		rows.push((
+			"Swap used",
+			format!("{} ({})", bytes(memory.swap_used), percent(memory.swap_usage)),
+		));
+		rows.push(("Swap free", bytes(memory.swap_free)));
+	}
+	fields(out, &rows);
+}
+
+fn render_disk(out: &mut String, disk: &DiskInfo) {
+	let mut table = Table::new(&[
+		("DEVICE", Align::Left),
+		("MOUNT", Align::Left),
+		("TYPE", Align::Left),
+		("KIND", Align::Left),
+		("SIZE", Align::Right),
+		("USED", Align::Right),
+		("FREE", Align::Right),
+		("USE%", Align::Right),
+	]);
+	for detail in &disk.disks {
+		table.row(vec![
+			detail.name.clone(),
+			detail.mount.clone(),
+			detail.file_system.clone(),
+			detail.kind.to_string(),
+			detail.total_space.to_string(),
+			detail.used_space.to_string(),
+			detail.free_space.to_string(),
+			percent(Some(detail.usage)),
+		]);
+	}
+	table.render(out);
+	out.push('\n');
+	fields(
+		out,
+		&[
+			(
+				"Total",
+				format!(
+					"{} used of {} ({:.2}%), {} free",
+					disk.total_used_space,
+					disk.total_space,
+					disk.total_usage,
+					disk.total_free_space
+				),
+			),
+			("Read", ByteSize::display_rate(disk.read_bytes_per_second).to_string()),
+			("Write", ByteSize::display_rate(disk.write_bytes_per_second).to_string()),
+		],
+	);
+}
+
+fn render_networks(out: &mut String, networks: &[NetworkInfo]) {
+	let mut table = Table::new(&[
+		("INTERFACE", Align::Left),
+		("ADDRESS", Align::Left),
+		("MAC", Align::Left),
+		("RX/S", Align::Right),
+		("TX/S", Align::Right),
+		("RX", Align::Right),
+		("TX", Align::Right),
+	]);
+	for network in networks {
+		let mut addresses =
+			network.ip_info.iter().map(|ip| format!("{}/{}", ip.ip_address, ip.netmask));
+		table.row(vec![
+			network.name.clone(),
+			addresses.next().unwrap_or_else(|| NONE.to_string()),
+			network.mac_addr.to_string(),
+			ByteSize::display_rate(network.download_bytes_per_second).to_string(),
+			ByteSize::display_rate(network.upload_bytes_per_second).to_string(),
+			network.total_download.to_string(),
+			network.total_upload.to_string(),
+		]);
+		// 其余地址各占一行，只填写地址列
+		for address in addresses {
+			let mut row = vec![String::new(); 7];
+			row[1] = address;
+			table.row(row);
+		}
+	}
+	table.render(out);
+}
+
+fn render_process(out: &mut String, process: &ProcessInfo) {
+	let mut rows = vec![
+		("PID", process.pid.to_string()),
+		("Name", process.name.clone()),
+		("CPU", percent(process.cpu_usage)),
+		("Memory", format!("{} ({})", process.used_memory, percent(process.memory_usage))),
+		("Run time", format_duration(process.run_time)),
+	];
+	// 每块GPU一行，如 `0000:01:00.0 512.00 MiB 37.5%`
+	for gpu in process.gpus.iter().flatten() {
+		rows.push((
+			"GPU",
+			format!(
+				"{} {} {}",
+				gpu.pci_bus_id.as_deref().unwrap_or(NONE),
+				bytes(gpu.memory),
+				percent(gpu.usage)
+			),
+		));
+	}
+	fields(out, &rows);
+	if let Some(children) = &process.sub_list {
+		out.push('\n');
+		render_processes(out, children);
+	}
+}
+
+/// 按进程树深度优先输出，子进程名称按层级缩进
+fn render_processes(out: &mut String, processes: &[ProcessInfo]) {
+	fn visit(table: &mut Table, processes: &[ProcessInfo], depth: usize) {
+		let mut processes: Vec<&ProcessInfo> = processes.iter().collect();
+		processes.sort_by_key(|process| process.pid);
+		for process in processes {
+			table.row(vec![
+				process.pid.to_string(),
+				percent(process.cpu_usage),
+				percent(process.memory_usage),
+				process.used_memory.to_string(),
+				bytes(process.gpu_memory()),
+				format_duration(process.run_time),
+				format!("{}{}", "  ".repeat(depth), process.name),
+			]);
+			if let Some(children) = &process.sub_list {
+				visit(table, children, depth + 1);
+			}
+		}
+	}
+
+	let mut table = Table::new(&[
+		("PID", Align::Right),
+		("CPU%", Align::Right),
+		("MEM%", Align::Right),
+		("MEMORY", Align::Right),
+		("GPU MEM", Align::Right),
+		("TIME", Align::Right),
+		("NAME", Align::Left),
+	]);
+	visit(&mut table, processes, 0);
+	table.render(out);
+}
+
+/// 每个GPU一组字段，之间以空行分隔
+fn render_gpus(out: &mut String, gpus: &[GpuInfo]) {
+	if gpus.is_empty() {
+		out.push_str("no GPU detected\n");
+	}
+	for (index, gpu) in gpus.iter().enumerate() {
+		if index > 0 {
+			out.push('\n');
+		}
+		render_gpu(out, gpu);
+	}
+}
+
+fn render_gpu(out: &mut String, gpu: &GpuInfo) {
+	let mut rows = vec![
+		("Index", gpu.index.to_string()),
+		("Model", gpu.model.clone()),
+		("Vendor", gpu.vendor.clone().unwrap_or_else(|| NONE.to_string())),
+		("PCI bus ID", gpu.pci_bus_id.clone().unwrap_or_else(|| NONE.to_string())),
+		("Driver", gpu.driver.clone().unwrap_or_else(|| NONE.to_string())),
+		("Memory total", bytes(gpu.memory_total)),
+		("Memory used", bytes(gpu.memory_used)),
+		("Memory free", bytes(gpu.memory_free)),
+		("Usage", gpu.usage.map_or_else(|| NONE.to_string(), |usage| format!("{usage}%"))),
+		("Temperature", gpu.temperature.map_or_else(|| NONE.to_string(), |t| format!("{t:.1} °C"))),
+	];
+	// 以下读数只有 NVML 提供，其余显卡不显示对应的行
+	let mut push = |label, value: Option<String>| {
+		if let Some(value) = value {
+			rows.push((label, value));
+		}
+	};
+	push("Driver version", gpu.driver_version.clone());
+	push("CUDA version", gpu.cuda_version.clone());
+	push("Memory controller", gpu.memory_controller_usage.map(|usage| format!("{usage}%")));
+	push("Encoder", gpu.encoder_usage.map(|usage| format!("{usage}%")));
+	push("Decoder", gpu.decoder_usage.map(|usage| format!("{usage}%")));
+	push("Fan speed", gpu.fan_speed.map(|speed| format!("{speed}%")));
+	push(
+		"Power",
+		gpu.power_usage.map(|power| match gpu.power_limit {
+			Some(limit) => format!("{power:.1} W / {limit:.1} W"),
+			None => format!("{power:.1} W"),
+		}),
+	);
+	push("Graphics clock", gpu.graphics_clock.map(|clock| format!("{clock} MHz")));
+	push("Memory clock", gpu.memory_clock.map(|clock| format!("{clock} MHz")));
+	push("PCIe TX", gpu.pcie_tx.map(|tx| ByteSize::display_rate(tx as f64 * 1024.0).to_string()));
+	push("PCIe RX", gpu.pcie_rx.map(|rx| ByteSize::display_rate(rx as f64 * 1024.0).to_string()));
+	push(
+		"Processes",
+		gpu.processes.as_ref().map(|processes| {
+			if processes.is_empty() {
+				return NONE.to_string();
+			}
+			processes
+				.iter()
+				.map(|process| match process.used_memory {
+					Some(memory) => format!("{} ({memory})", process.pid),
+					None => process.pid.to_string(),
+				})
+				.collect::<Vec<_>>()
+				.join(", ")
+		}),
+	);
+	fields(out, &rows);
+}
+
+fn optional(value: Option<u32>) -> String {
+	value.map_or_else(|| NONE.to_string(), |value| value.to_string())
+}
+
+fn percent(value: Option<f32>) -> String {
+	value.map_or_else(|| NONE.to_string(), |value| format!("{value:.1}%"))
+}
+
+fn bytes(value: Option<ByteSize>) -> String {
+	value.map_or_else(|| NONE.to_string(), |value| value.to_string())
+}
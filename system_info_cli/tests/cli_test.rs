@@ -0,0 +1,103 @@
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str]) -> Output {
+	Command::new(env!("CARGO_BIN_EXE_system-info")).args(args).output().unwrap()
+}
+
+fn stdout(args: &[&str]) -> String {
+	let output = run(args);
+	assert!(
+		output.status.success(),
+		"{args:?} failed: {}",
+		String::from_utf8_lossy(&output.stderr)
+	);
+	String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_table_output() {
+	let output = stdout(&["memory"]);
+	let total = output.lines().find(|line| line.starts_with("Total")).unwrap();
+	assert!(total.ends_with("iB"), "unexpected total `{total}`");
+	let used = output.lines().find(|line| line.starts_with("Used")).unwrap();
+	// 名称列对齐
+	assert_eq!(total.find(char::is_numeric), used.find(char::is_numeric));
+
+	let output = stdout(&["network"]);
+	let header = output.lines().next().unwrap();
+	assert!(header.starts_with("INTERFACE"));
+	assert!(header.contains("RX/S"));
+
+	let output = stdout(&["all"]);
+	for title in ["Host", "Load", "CPU", "Memory", "Disk", "Network", "GPU", "Processes"] {
+		assert!(output.lines().any(|line| line == title), "missing section `{title}`");
+	}
+}
+
+#[test]
+fn test_json_and_yaml_output() {
+	let memory: serde_json::Value = serde_json::from_str(&stdout(&["memory", "--json"])).unwrap();
+	assert!(memory["total"].as_u64().unwrap() > 0);
+
+	let networks: serde_json::Value =
+		serde_json::from_str(&stdout(&["--json", "network"])).unwrap();
+	assert!(networks.is_array());
+
+	let all: serde_json::Value = serde_json::from_str(&stdout(&["all", "--json"])).unwrap();
+	assert!(all["timestamp"].is_string());
+	assert!(all["processes"].is_array());
+
+	let host: serde_norway::Value = serde_norway::from_str(&stdout(&["host", "--yaml"])).unwrap();
+	assert!(host["host_name"].is_string());
+	assert!(host["uptime"].is_u64());
+}
+
+#[test]
+fn test_process() {
+	let pid = std::process::id();
+	let process: serde_json::Value =
+		serde_json::from_str(&stdout(&["process", &pid.to_string(), "--json"])).unwrap();
+	assert_eq!(process["pid"].as_u64(), Some(pid as u64));
+
+	let output = stdout(&["process", &pid.to_string()]);
+	assert!(output.starts_with(&format!("PID       {pid}\n")));
+
+	let processes: serde_json::Value =
+		serde_json::from_str(&stdout(&["process", "--json"])).unwrap();
+	assert!(!processes.as_array().unwrap().is_empty());
+
+	let output = run(&["process", "4294967295"]);
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stderr).contains("no process with pid 4294967295"));
+}
+
+#[test]
+fn test_watch() {
+	let mut child = Command::new(env!("CARGO_BIN_EXE_system-info"))
+		.args(["memory", "--json", "--watch", "0.2"])
+		.stdout(Stdio::piped())
+		.spawn()
+		.unwrap();
+
+	// 持续刷新时每次输出一行 JSON
+	let mut lines = BufReader::new(child.stdout.take().unwrap()).lines();
+	for _ in 0..2 {
+		let line = lines.next().unwrap().unwrap();
+		let memory: serde_json::Value = serde_json::from_str(&line).unwrap();
+		assert!(memory["total"].as_u64().unwrap() > 0);
+	}
+	child.kill().unwrap();
+	child.wait().unwrap();
+}
+
+#[test]
+fn test_invalid_arguments() {
+	let output = run(&["memory", "--json", "--yaml"]);
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stderr).contains("cannot be used with"));
+
+	assert!(!run(&["memory", "--watch", "0"]).status.success());
+	assert!(!run(&["bogus"]).status.success());
+	assert!(!run(&[]).status.success());
+}
@@ -0,0 +1,217 @@
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Sub};
+
+/// 以字节为单位的容量
+///
+/// 各信息类型中的容量字段均以字节保存，需要其他单位时通过 `as_*` 方法换算：
+/// `as_kb`、`as_mb`、`as_gb` 为十进制单位(1 MB = 1000² 字节)，
+/// `as_kib`、`as_mib`、`as_gib` 为二进制单位(1 MiB = 1024² 字节)。
+/// 旧版本中标注为 MB、GB 的整数字段按 1024 进制换算，对应 [ByteSize::whole_mib]、[ByteSize::whole_gib]，
+/// 各信息类型也提供与旧字段数值相同的 `*_mb()`、`*_gb()` 访问器。注意 `as_mb` 为十进制单位，与旧字段的数值不同。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct ByteSize(u64);
+
+/// 容量的单位制
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum UnitSystem {
+	/// 十进制单位，如 kB、MB、GB
+	Si,
+	/// 二进制单位，如 KiB、MiB、GiB
+	#[default]
+	Iec,
+}
+
+const KB: f64 = 1000.0;
+const KIB: f64 = 1024.0;
+
+impl ByteSize {
+	/// 0 字节
+	pub const ZERO: Self = Self(0);
+
+	pub const fn from_bytes(bytes: u64) -> Self {
+		Self(bytes)
+	}
+
+	pub const fn from_kib(kib: u64) -> Self {
+		Self(kib * 1024)
+	}
+
+	pub const fn from_mib(mib: u64) -> Self {
+		Self(mib * 1024 * 1024)
+	}
+
+	pub const fn from_gib(gib: u64) -> Self {
+		Self(gib * 1024 * 1024 * 1024)
+	}
+
+	/// 字节数
+	pub const fn as_bytes(self) -> u64 {
+		self.0
+	}
+
+	/// 千字节(单位: kB，1000 字节)
+	pub fn as_kb(self) -> f64 {
+		self.0 as f64 / KB
+	}
+
+	/// 兆字节(单位: MB，1000² 字节)
+	pub fn as_mb(self) -> f64 {
+		self.0 as f64 / KB.powi(2)
+	}
+
+	/// 吉字节(单位: GB，1000³ 字节)
+	pub fn as_gb(self) -> f64 {
+		self.0 as f64 / KB.powi(3)
+	}
+
+	/// 单位: KiB(1024 字节)
+	pub fn as_kib(self) -> f64 {
+		self.0 as f64 / KIB
+	}
+
+	/// 单位: MiB(1024² 字节)
+	pub fn as_mib(self) -> f64 {
+		self.0 as f64 / KIB.powi(2)
+	}
+
+	/// 单位: GiB(1024³ 字节)
+	pub fn as_gib(self) -> f64 {
+		self.0 as f64 / KIB.powi(3)
+	}
+
+	/// 向下取整的 MiB 数，与旧版本整数 MB 字段的换算方式相同
+	pub const fn whole_mib(self) -> u64 {
+		self.0 / (1024 * 1024)
+	}
+
+	/// 向下取整的 GiB 数，与旧版本整数 GB 字段的换算方式相同
+	pub const fn whole_gib(self) -> u64 {
+		self.0 / (1024 * 1024 * 1024)
+	}
+
+	/// 相减，结果小于 0 时为 0
+	pub const fn saturating_sub(self, rhs: Self) -> Self {
+		Self(self.0.saturating_sub(rhs.0))
+	}
+
+	/// 相减，结果小于 0 时返回 None
+	pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+		match self.0.checked_sub(rhs.0) {
+			Some(bytes) => Some(Self(bytes)),
+			None => None,
+		}
+	}
+
+	/// 占 `total` 的百分比，`total` 为 0 时返回 0
+	pub fn percent_of(self, total: Self) -> f64 {
+		if total.0 == 0 { 0.0 } else { self.0 as f64 / total.0 as f64 * 100.0 }
+	}
+
+	/// 按指定单位制格式化，如 `1.50 GiB` 或 `1.61 GB`
+	pub fn display(self, unit_system: UnitSystem) -> ByteSizeDisplay {
+		ByteSizeDisplay { size: self, unit_system }
+	}
+
+	/// 将每秒的字节数格式化为速率，如 `1.50 MiB/s`，小数部分的字节被舍去
+	pub fn display_rate(bytes_per_second: f64) -> RateDisplay {
+		RateDisplay(Self::from_bytes(bytes_per_second as u64))
+	}
+}
+
+/// [ByteSize::display] 的返回值，精度默认保留两位小数，可通过 `{:.1}` 等指定
+#[derive(Debug, Clone, Copy)]
+pub struct ByteSizeDisplay {
+	size: ByteSize,
+	unit_system: UnitSystem,
+}
+
+impl fmt::Display for ByteSizeDisplay {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let (base, units) = match self.unit_system {
+			UnitSystem::Si => (KB, ["B", "kB", "MB", "GB", "TB", "PB", "EB"]),
+			UnitSystem::Iec => (KIB, ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"]),
+		};
+		let bytes = self.size.0;
+		if (bytes as f64) < base {
+			return write!(f, "{bytes} B");
+		}
+		let mut value = bytes as f64;
+		let mut exponent = 0;
+		while value >= base && exponent < units.len() - 1 {
+			value /= base;
+			exponent += 1;
+		}
+		write!(f, "{value:.precision$} {}", units[exponent], precision = f.precision().unwrap_or(2))
+	}
+}
+
+impl fmt::Display for ByteSize {
+	/// 以二进制单位格式化，如 `1.50 GiB`，十进制单位请使用 [ByteSize::display]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.display(UnitSystem::Iec), f)
+	}
+}
+
+/// [ByteSize::display_rate] 的返回值，以二进制单位格式化，精度与 [ByteSize] 相同
+#[derive(Debug, Clone, Copy)]
+pub struct RateDisplay(ByteSize);
+
+impl fmt::Display for RateDisplay {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		fmt::Display::fmt(&self.0, f)?;
+		f.write_str("/s")
+	}
+}
+
+/// 将秒数格式化为时长，如 `04:05:06`，超过一天时为 `3d 04:05:06`
+pub fn format_duration(secs: u64) -> String {
+	let (days, secs) = (secs / 86_400, secs % 86_400);
+	let time = format!("{:02}:{:02}:{:02}", secs / 3600, secs % 3600 / 60, secs % 60);
+	if days > 0 { format!("{days}d {time}") } else { time }
+}
+
+impl From<u64> for ByteSize {
+	fn from(bytes: u64) -> Self {
+		Self(bytes)
+	}
+}
+
+impl From<ByteSize> for u64 {
+	fn from(size: ByteSize) -> Self {
+		size.0
+	}
+}
+
+impl Add for ByteSize {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self(self.0 + rhs.0)
+	}
+}
+
+impl AddAssign for ByteSize {
+	fn add_assign(&mut self, rhs: Self) {
+		self.0 += rhs.0;
+	}
+}
+
+impl Sub for ByteSize {
+	type Output = Self;
+
+	/// 与 [ByteSize::saturating_sub] 相同，结果小于 0 时为 0，需要区分时使用 [ByteSize::checked_sub]
+	fn sub(self, rhs: Self) -> Self {
+		self.saturating_sub(rhs)
+	}
+}
+
+impl Sum for ByteSize {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::ZERO, Add::add)
+	}
+}
@@ -0,0 +1,291 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::error::read_file;
+
+/// cgroup 版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum CgroupVersion {
+	/// cgroup v1，各控制器独立挂载
+	V1,
+	/// cgroup v2，统一层级
+	V2,
+}
+
+/// 当前进程所在 cgroup 的资源限制与用量
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContainerInfo {
+	/// cgroup 版本
+	pub version: CgroupVersion,
+	/// 内存上限(单位: 字节)，未限制时为 None
+	pub memory_limit: Option<u64>,
+	/// 当前内存用量(单位: 字节)，含页缓存
+	pub memory_usage: Option<u64>,
+	/// `memory.stat` 中的统计项
+	pub memory_stat: BTreeMap<String, u64>,
+	/// CPU配额(单位: 核)，即 quota / period，未限制时为 None
+	pub cpu_quota: Option<f64>,
+	/// 可使用的逻辑CPU编号，无法读取时为 None
+	pub cpuset: Option<Vec<u32>>,
+	/// 累计CPU使用时间(单位: 微秒)
+	pub cpu_usage_usec: Option<u64>,
+}
+
+impl ContainerInfo {
+	/// 检测当前进程所在 cgroup 的资源限制，非 Linux 或未挂载 cgroup 时返回 None
+	pub fn detect() -> Option<Self> {
+		#[cfg(target_os = "linux")]
+		{
+			let self_cgroup = read_file("/proc/self/cgroup").unwrap_or_default();
+			Self::from_root_with_cgroup("/sys/fs/cgroup", &self_cgroup)
+		}
+
+		#[cfg(not(target_os = "linux"))]
+		{
+			None
+		}
+	}
+
+	/// 从指定的 cgroup 文件系统根目录读取资源限制，假定进程位于各层级的根 cgroup
+	///
+	/// 容器内启用 cgroup 命名空间时即为这种情况，也便于使用伪造的目录结构进行测试
+	pub fn from_root(root: impl AsRef<Path>) -> Option<Self> {
+		Self::from_root_with_cgroup(root, "")
+	}
+
+	/// 从指定的 cgroup 文件系统根目录读取资源限制
+	///
+	/// `self_cgroup` 为 `/proc/self/cgroup` 格式的文本，用于定位进程所在的 cgroup；
+	/// 对应目录不存在时回退到层级的根目录。内存上限与CPU配额取所在 cgroup 及其各级父 cgroup
+	/// (直到层级根目录)中最小的限制
+	pub fn from_root_with_cgroup(root: impl AsRef<Path>, self_cgroup: &str) -> Option<Self> {
+		let root = root.as_ref();
+		let paths = parse_self_cgroup(self_cgroup);
+
+		if root.join("cgroup.controllers").is_file() {
+			return Some(Self::read_v2(&CgroupDir::new(root, paths.get(""))));
+		}
+
+		let controller_dir = |controller: &str| {
+			let hierarchy = root.join(controller);
+			hierarchy.is_dir().then(|| CgroupDir::new(&hierarchy, paths.get(controller)))
+		};
+		let memory = controller_dir("memory");
+		let cpu = controller_dir("cpu");
+		let cpuacct = controller_dir("cpuacct");
+		let cpuset = controller_dir("cpuset");
+		if memory.is_none() && cpu.is_none() && cpuacct.is_none() && cpuset.is_none() {
+			return None;
+		}
+		Some(Self::read_v1(memory.as_ref(), cpu.as_ref(), cpuacct.as_ref(), cpuset.as_ref()))
+	}
+
+	fn read_v2(dir: &CgroupDir) -> Self {
+		let cpu_quota = dir.min_limit(|dir| {
+			let text = read_file(dir.join("cpu.max")).ok()?;
+			let mut fields = text.split_whitespace();
+			let quota = fields.next()?.parse::<f64>().ok()?;
+			let period =
+				fields.next().map_or(Some(100_000.0), |period| period.parse::<f64>().ok())?;
+			(period > 0.0).then(|| quota / period)
+		});
+
+		Self {
+			version: CgroupVersion::V2,
+			memory_limit: dir.min_limit(|dir| read_u64(dir.join("memory.max"))),
+			memory_usage: read_u64(dir.join("memory.current")),
+			memory_stat: read_flat_keyed(dir.join("memory.stat")),
+			cpu_quota,
+			cpuset: read_file(dir.join("cpuset.cpus.effective"))
+				.ok()
+				.and_then(|text| parse_cpu_list(&text)),
+			cpu_usage_usec: read_flat_keyed(dir.join("cpu.stat")).get("usage_usec").copied(),
+		}
+	}
+
+	fn read_v1(
+		memory: Option<&CgroupDir>,
+		cpu: Option<&CgroupDir>,
+		cpuacct: Option<&CgroupDir>,
+		cpuset: Option<&CgroupDir>,
+	) -> Self {
+		// 未限制时 memory.limit_in_bytes 为接近 i64::MAX 的页对齐值
+		const UNLIMITED_MEMORY: u64 = 1 << 62;
+		let cpu_quota = cpu.and_then(|dir| {
+			dir.min_limit(|dir| {
+				let quota =
+					read_file(dir.join("cpu.cfs_quota_us")).ok()?.trim().parse::<i64>().ok()?;
+				let period = read_u64(dir.join("cpu.cfs_period_us"))?;
+				(quota > 0 && period > 0).then(|| quota as f64 / period as f64)
+			})
+		});
+		let cpuset = cpuset.and_then(|dir| {
+			read_file(dir.join("cpuset.effective_cpus"))
+				.or_else(|_| read_file(dir.join("cpuset.cpus")))
+				.ok()
+				.and_then(|text| parse_cpu_list(&text))
+		});
+
+		Self {
+			version: CgroupVersion::V1,
+			memory_limit: memory.and_then(|dir| {
+				dir.min_limit(|dir| {
+					read_u64(dir.join("memory.limit_in_bytes"))
+						.filter(|limit| *limit < UNLIMITED_MEMORY)
+				})
+			}),
+			memory_usage: memory.and_then(|dir| read_u64(dir.join("memory.usage_in_bytes"))),
+			memory_stat: memory
+				.map(|dir| read_flat_keyed(dir.join("memory.stat")))
+				.unwrap_or_default(),
+			cpu_quota,
+			cpuset,
+			cpu_usage_usec: cpuacct
+				.and_then(|dir| read_u64(dir.join("cpuacct.usage")))
+				.map(|nanos| nanos / 1000),
+		}
+	}
+
+	/// 容器可使用的CPU核数，取CPU配额与 cpuset 中较小者，均未限制时为 None
+	pub fn effective_cpus(&self) -> Option<f64> {
+		let cpuset = self.cpuset.as_ref().map(|cpus| cpus.len() as f64);
+		match (self.cpu_quota, cpuset) {
+			(Some(quota), Some(cpuset)) => Some(quota.min(cpuset)),
+			(quota, cpuset) => quota.or(cpuset),
+		}
+	}
+
+	/// 内存工作集(单位: 字节)，即当前用量减去非活跃的文件页缓存
+	pub fn memory_working_set(&self) -> Option<u64> {
+		let usage = self.memory_usage?;
+		let inactive_file = match self.version {
+			CgroupVersion::V1 => self.memory_stat.get("total_inactive_file"),
+			CgroupVersion::V2 => self.memory_stat.get("inactive_file"),
+		};
+		Some(usage.saturating_sub(inactive_file.copied().unwrap_or(0)))
+	}
+
+	/// 计算自 `previous` 以来容器的CPU使用率(相对于可用核数)
+	pub fn cpu_usage_since(&self, previous: &ContainerInfo, elapsed: Duration) -> Option<f32> {
+		let used = self.cpu_usage_usec?.saturating_sub(previous.cpu_usage_usec?) as f64;
+		let available = elapsed.as_micros() as f64 * self.effective_cpus()?;
+		(available > 0.0).then(|| crate::round((used / available * 100.0).min(100.0)) as f32)
+	}
+}
+
+/// 最近两次容器资源采样
+#[derive(Debug, Default)]
+pub(crate) struct ContainerSample {
+	previous: Option<(ContainerInfo, Instant)>,
+	current: Option<(ContainerInfo, Instant)>,
+}
+
+impl ContainerSample {
+	pub(crate) fn refresh(&mut self) {
+		self.previous = self.current.take();
+		self.current = ContainerInfo::detect().map(|info| (info, Instant::now()));
+	}
+
+	pub(crate) fn current(&self) -> Option<&ContainerInfo> {
+		self.current.as_ref().map(|(info, _)| info)
+	}
+
+	#[cfg(feature = "cpu")]
+	pub(crate) fn previous(&self) -> Option<(&ContainerInfo, Duration)> {
+		let (previous, previous_at) = self.previous.as_ref()?;
+		let (_, current_at) = self.current.as_ref()?;
+		Some((previous, current_at.duration_since(*previous_at)))
+	}
+}
+
+/// 解析 `/proc/self/cgroup`，返回控制器到 cgroup 路径的映射，v2 的控制器名为空字符串
+fn parse_self_cgroup(text: &str) -> HashMap<String, String> {
+	let mut paths = HashMap::new();
+	for line in text.lines() {
+		let mut fields = line.splitn(3, ':');
+		let (Some(_), Some(controllers), Some(path)) =
+			(fields.next(), fields.next(), fields.next())
+		else {
+			continue;
+		};
+		for controller in controllers.split(',') {
+			paths.insert(controller.to_string(), path.to_string());
+		}
+	}
+	paths
+}
+
+/// 某个 cgroup 层级中进程所在的目录
+#[derive(Debug)]
+struct CgroupDir {
+	/// 层级根目录，启用 cgroup 命名空间时即为命名空间的根
+	hierarchy: PathBuf,
+	/// 进程所在的 cgroup 目录，不存在时与 `hierarchy` 相同
+	dir: PathBuf,
+}
+
+impl CgroupDir {
+	fn new(hierarchy: &Path, path: Option<&String>) -> Self {
+		let dir = path
+			.map(|path| hierarchy.join(path.trim_start_matches('/')))
+			.filter(|dir| dir.is_dir())
+			.unwrap_or_else(|| hierarchy.to_path_buf());
+		Self { hierarchy: hierarchy.to_path_buf(), dir }
+	}
+
+	fn join(&self, name: &str) -> PathBuf {
+		self.dir.join(name)
+	}
+
+	/// 从所在 cgroup 向上直到层级根目录逐级读取限制，返回其中最小的一个
+	///
+	/// 父 cgroup(如 systemd slice 或 Pod 级 cgroup)的限制同样作用于其中的所有进程
+	fn min_limit<T: PartialOrd>(&self, read: impl FnMut(&Path) -> Option<T>) -> Option<T> {
+		self.dir
+			.ancestors()
+			.take_while(|dir| dir.starts_with(&self.hierarchy))
+			.filter_map(read)
+			.reduce(|min, limit| if limit < min { limit } else { min })
+	}
+}
+
+/// 读取单个数值，值为 `max`(未限制)或无法解析时返回 None
+fn read_u64(path: PathBuf) -> Option<u64> {
+	read_file(path).ok()?.trim().parse().ok()
+}
+
+/// 读取 `key value` 格式的统计文件
+fn read_flat_keyed(path: PathBuf) -> BTreeMap<String, u64> {
+	read_file(path)
+		.map(|text| {
+			text.lines()
+				.filter_map(|line| {
+					let (key, value) = line.split_once(' ')?;
+					Some((key.to_string(), value.trim().parse().ok()?))
+				})
+				.collect()
+		})
+		.unwrap_or_default()
+}
+
+/// 解析 `0-3,8,10-11` 格式的CPU列表
+fn parse_cpu_list(text: &str) -> Option<Vec<u32>> {
+	let text = text.trim();
+	if text.is_empty() {
+		return None;
+	}
+	let mut cpus = Vec::new();
+	for range in text.split(',') {
+		match range.split_once('-') {
+			Some((start, end)) => {
+				cpus.extend(start.parse::<u32>().ok()?..=end.parse::<u32>().ok()?)
+			}
+			None => cpus.push(range.parse().ok()?),
+		}
+	}
+	Some(cpus)
+}
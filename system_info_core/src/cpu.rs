@@ -1,4 +1,13 @@
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use sysinfo::{Components, Cpu, System};
+
+use crate::{ContainerInfo, SystemInfoError};
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpuInfo {
 	/// CPU名称
 	pub model_name: String,
@@ -12,82 +21,416 @@ pub struct CpuInfo {
 	pub usage: Option<f32>,
 	/// CPU温度(单位: °C)，无法读取时为 None
 	pub temperature: Option<f32>,
+	/// 各逻辑CPU详细信息
+	pub cores: Vec<CoreInfo>,
+	/// CPU时间占比，仅 Linux 下基于两次 `/proc/stat` 采样计算，其余平台为 None
+	pub times: Option<CpuTimePercent>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoreInfo {
+	/// 逻辑CPU编号
+	pub id: u32,
+	/// 使用率
+	pub usage: f32,
+	/// 当前频率(单位: GHz)
+	pub frequency: f32,
+	/// 所属物理核心编号，无法读取时为 None
+	pub core_id: Option<u32>,
+	/// 所属CPU封装(插槽)编号，无法读取时为 None
+	pub package_id: Option<u32>,
+	/// 所属NUMA节点编号，无法读取时为 None
+	pub numa_node: Option<u32>,
+	/// CPU时间占比，仅 Linux 下可用
+	pub times: Option<CpuTimePercent>,
+}
+
+/// `/proc/stat` 中的CPU累计时间(单位: USER_HZ)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuTimes {
+	/// 用户态时间(含 guest)
+	pub user: u64,
+	/// 低优先级用户态时间(含 guest_nice)
+	pub nice: u64,
+	/// 内核态时间
+	pub system: u64,
+	/// 空闲时间
+	pub idle: u64,
+	/// 等待IO时间
+	pub iowait: u64,
+	/// 硬中断时间
+	pub irq: u64,
+	/// 软中断时间
+	pub softirq: u64,
+	/// 被虚拟化宿主占用的时间
+	pub steal: u64,
+	/// 运行虚拟机的时间
+	pub guest: u64,
+	/// 运行低优先级虚拟机的时间
+	pub guest_nice: u64,
+}
+
+/// 两次采样之间各类CPU时间的占比(单位: %)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuTimePercent {
+	/// 用户态(含 guest)
+	pub user: f32,
+	/// 低优先级用户态
+	pub nice: f32,
+	/// 内核态
+	pub system: f32,
+	/// 空闲
+	pub idle: f32,
+	/// 等待IO
+	pub iowait: f32,
+	/// 硬中断
+	pub irq: f32,
+	/// 软中断
+	pub softirq: f32,
+	/// 被虚拟化宿主占用
+	pub steal: f32,
+	/// 运行虚拟机
+	pub guest: f32,
+}
+
+/// `/proc/stat` 中CPU相关数据的快照
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CpuStat {
+	/// 所有CPU的汇总时间
+	pub total: CpuTimes,
+	/// 各逻辑CPU的时间，键为逻辑CPU编号
+	pub cores: BTreeMap<u32, CpuTimes>,
 }
 
 impl Default for CpuInfo {
+	/// 获取CPU信息，未检测到CPU时型号为 `"Unknown"`、频率为 0、使用率与温度为 `None`
 	fn default() -> Self {
-		use std::thread::sleep;
-		use sysinfo::System;
-		let mut system = System::new();
-		system.refresh_cpu_all();
+		let (system, components, stat) = sample();
+		Self::from_system(&system, &components, stat.pair())
+	}
+}
 
-		sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
-		system.refresh_cpu_usage();
-		let cpu = &system.cpus()[0];
+impl CpuInfo {
+	pub fn new() -> Self {
+		Self::default()
+	}
 
-		Self {
+	/// 获取CPU信息，未检测到CPU时返回 [SystemInfoError::CpuNotFound]
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		let (system, components, stat) = sample();
+		Self::try_from_system(&system, &components, stat.pair())
+	}
+
+	/// 从已刷新的 [System] 与 [Components] 中构建CPU信息
+	///
+	/// 使用率基于调用方两次刷新之间的差值计算，此处不会等待
+	///
+	/// `stat` 为前后两次 `/proc/stat` 采样，用于计算CPU时间占比
+	pub(crate) fn from_system(
+		system: &System,
+		components: &Components,
+		stat: Option<(&CpuStat, &CpuStat)>,
+	) -> Self {
+		Self::try_from_system(system, components, stat).unwrap_or_else(|_| Self::unknown())
+	}
+
+	pub(crate) fn try_from_system(
+		system: &System,
+		components: &Components,
+		stat: Option<(&CpuStat, &CpuStat)>,
+	) -> Result<Self, SystemInfoError> {
+		let cpu = system.cpus().first().ok_or(SystemInfoError::CpuNotFound)?;
+		let ids: Vec<u32> =
+			system.cpus().iter().enumerate().map(|(index, cpu)| cpu_number(index, cpu)).collect();
+		let topology = CpuTopology::all(&ids);
+
+		Ok(Self {
 			model_name: cpu.brand().to_string(),
 			physical_cores: num_cpus::get_physical() as u32,
 			logical_cores: num_cpus::get() as u32,
 			frequency: cpu.frequency() as f32 / 1000.0,
 			usage: Some(system.global_cpu_usage().round()),
-			temperature: read_cpu_temperature(),
+			temperature: read_cpu_temperature(components),
+			cores: system
+				.cpus()
+				.iter()
+				.zip(ids)
+				.map(|(cpu, id)| {
+					let times = stat.and_then(|(previous, current)| {
+						current.cores.get(&id)?.percent_since(previous.cores.get(&id)?)
+					});
+					let topology =
+						topology.get(&id).copied().unwrap_or_else(|| CpuTopology::read(id));
+					CoreInfo::from_cpu(id, cpu, topology, times)
+				})
+				.collect(),
+			times: stat
+				.and_then(|(previous, current)| current.total.percent_since(&previous.total)),
+		})
+	}
+
+	/// 按容器的资源限制调整CPU信息
+	///
+	/// 逻辑核数取容器可用核数(向上取整)与主机核数中较小者；
+	/// 提供上一次容器采样及其间隔时，使用率改为容器相对于可用核数的使用率
+	pub fn with_container(
+		self,
+		container: &ContainerInfo,
+		previous: Option<(&ContainerInfo, Duration)>,
+	) -> Self {
+		let logical_cores = container
+			.effective_cpus()
+			.map_or(self.logical_cores, |cpus| (cpus.ceil() as u32).clamp(1, self.logical_cores));
+		let usage = previous
+			.and_then(|(previous, elapsed)| container.cpu_usage_since(previous, elapsed))
+			.or(self.usage);
+		Self { logical_cores, usage, ..self }
+	}
+
+	fn unknown() -> Self {
+		Self {
+			model_name: "Unknown".to_string(),
+			physical_cores: num_cpus::get_physical() as u32,
+			logical_cores: num_cpus::get() as u32,
+			frequency: 0.0,
+			usage: None,
+			temperature: None,
+			cores: Vec::new(),
+			times: None,
 		}
 	}
 }
 
-impl CpuInfo {
-	pub fn new() -> Self {
+/// 逻辑CPU编号，取自 sysinfo 报告的名称 `cpuN`
+///
+/// 存在离线CPU时编号不连续，不能使用枚举序号；名称无法解析时(非 Linux 平台)退回序号
+fn cpu_number(index: usize, cpu: &Cpu) -> u32 {
+	cpu.name().strip_prefix("cpu").and_then(|id| id.parse().ok()).unwrap_or(index as u32)
+}
+
+impl CoreInfo {
+	fn from_cpu(id: u32, cpu: &Cpu, topology: CpuTopology, times: Option<CpuTimePercent>) -> Self {
+		Self {
+			id,
+			usage: crate::round(cpu.cpu_usage() as f64) as f32,
+			frequency: cpu.frequency() as f32 / 1000.0,
+			core_id: topology.core_id,
+			package_id: topology.package_id,
+			numa_node: topology.numa_node,
+			times,
+		}
+	}
+}
+
+/// 逻辑CPU与物理核心、封装、NUMA节点的对应关系
+#[derive(Debug, Clone, Copy, Default)]
+struct CpuTopology {
+	core_id: Option<u32>,
+	package_id: Option<u32>,
+	numa_node: Option<u32>,
+}
+
+impl CpuTopology {
+	/// 拓扑在运行期间不变，首次调用时读取 `ids` 中的逻辑CPU并缓存，之后不再访问 sysfs
+	fn all(ids: &[u32]) -> &'static BTreeMap<u32, CpuTopology> {
+		static TOPOLOGY: OnceLock<BTreeMap<u32, CpuTopology>> = OnceLock::new();
+		TOPOLOGY.get_or_init(|| ids.iter().map(|&id| (id, Self::read(id))).collect())
+	}
+
+	#[cfg(target_os = "linux")]
+	fn read(id: u32) -> Self {
+		use std::fs;
+		use std::path::Path;
+
+		let cpu_dir = Path::new("/sys/devices/system/cpu").join(format!("cpu{id}"));
+		let read_id = |name: &str| {
+			fs::read_to_string(cpu_dir.join("topology").join(name))
+				.ok()
+				.and_then(|value| value.trim().parse::<u32>().ok())
+		};
+		let numa_node = fs::read_dir(&cpu_dir).ok().and_then(|entries| {
+			entries.flatten().find_map(|entry| {
+				entry.file_name().to_str()?.strip_prefix("node")?.parse::<u32>().ok()
+			})
+		});
+
+		Self { core_id: read_id("core_id"), package_id: read_id("physical_package_id"), numa_node }
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	fn read(_id: u32) -> Self {
 		Self::default()
 	}
 }
 
-fn read_cpu_temperature() -> Option<f32> {
-	use sysinfo::Components;
+/// 一次性采样CPU数据，等待 [sysinfo::MINIMUM_CPU_UPDATE_INTERVAL] 以获得有效的使用率
+fn sample() -> (System, Components, CpuStatSample) {
+	use std::thread::sleep;
+	let mut system = System::new();
+	let mut stat = CpuStatSample::default();
+	system.refresh_cpu_all();
+	stat.refresh();
+
+	sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+	system.refresh_cpu_usage();
+	stat.refresh();
+	(system, Components::new_with_refreshed_list(), stat)
+}
+
+impl CpuTimes {
+	/// 计算自 `previous` 以来各类CPU时间的占比，两次采样之间无时间流逝时返回 None
+	pub fn percent_since(&self, previous: &CpuTimes) -> Option<CpuTimePercent> {
+		let delta = |current: u64, previous: u64| current.saturating_sub(previous) as f64;
+		let user = delta(self.user, previous.user);
+		let nice = delta(self.nice, previous.nice);
+		let system = delta(self.system, previous.system);
+		let idle = delta(self.idle, previous.idle);
+		let iowait = delta(self.iowait, previous.iowait);
+		let irq = delta(self.irq, previous.irq);
+		let softirq = delta(self.softirq, previous.softirq);
+		let steal = delta(self.steal, previous.steal);
+		let guest = delta(self.guest, previous.guest);
+
+		// guest 与 guest_nice 已计入 user 与 nice，不重复累加
+		let total = user + nice + system + idle + iowait + irq + softirq + steal;
+		if total <= 0.0 {
+			return None;
+		}
+		let percent = |value: f64| crate::round(value / total * 100.0) as f32;
+
+		Some(CpuTimePercent {
+			user: percent(user),
+			nice: percent(nice),
+			system: percent(system),
+			idle: percent(idle),
+			iowait: percent(iowait),
+			irq: percent(irq),
+			softirq: percent(softirq),
+			steal: percent(steal),
+			guest: percent(guest),
+		})
+	}
+}
+
+impl CpuStat {
+	/// 解析 `/proc/stat` 格式的文本，忽略与CPU无关的行
+	///
+	/// 缺失的列(旧内核)按 0 处理
+	pub fn parse(text: &str) -> Result<Self, SystemInfoError> {
+		const SOURCE: &str = "/proc/stat";
+		let mut total = None;
+		let mut cores = BTreeMap::new();
+
+		for line in text.lines() {
+			let mut fields = line.split_whitespace();
+			let Some(label) = fields.next().and_then(|label| label.strip_prefix("cpu")) else {
+				continue;
+			};
+			let values = fields
+				.map(|value| value.parse::<u64>())
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|_| SystemInfoError::Parse(SOURCE))?;
+			if values.len() < 4 {
+				return Err(SystemInfoError::Parse(SOURCE));
+			}
+			let value = |index: usize| values.get(index).copied().unwrap_or(0);
+			let times = CpuTimes {
+				user: value(0),
+				nice: value(1),
+				system: value(2),
+				idle: value(3),
+				iowait: value(4),
+				irq: value(5),
+				softirq: value(6),
+				steal: value(7),
+				guest: value(8),
+				guest_nice: value(9),
+			};
+
+			if label.is_empty() {
+				total = Some(times);
+			} else {
+				let id = label.parse::<u32>().map_err(|_| SystemInfoError::Parse(SOURCE))?;
+				cores.insert(id, times);
+			}
+		}
+
+		Ok(Self { total: total.ok_or(SystemInfoError::Parse(SOURCE))?, cores })
+	}
+
+	/// 读取当前的 `/proc/stat`
+	#[cfg(target_os = "linux")]
+	pub fn read() -> Result<Self, SystemInfoError> {
+		Self::parse(&crate::error::read_file("/proc/stat")?)
+	}
+}
+
+/// 最近两次 `/proc/stat` 采样，非 Linux 平台下始终为空
+#[derive(Debug, Default)]
+pub(crate) struct CpuStatSample {
+	previous: Option<CpuStat>,
+	current: Option<CpuStat>,
+}
+
+impl CpuStatSample {
+	pub(crate) fn refresh(&mut self) {
+		#[cfg(target_os = "linux")]
+		{
+			self.previous = self.current.take();
+			self.current = CpuStat::read().ok();
+		}
+	}
 
-	let components = Components::new_with_refreshed_list();
+	pub(crate) fn pair(&self) -> Option<(&CpuStat, &CpuStat)> {
+		Some((self.previous.as_ref()?, self.current.as_ref()?))
+	}
+}
 
+fn read_cpu_temperature(components: &Components) -> Option<f32> {
 	const PREFERRED_IDS: &[&str] = &["TC0P", "TC0D", "TC0E", "TC0F"];
 	for id in PREFERRED_IDS {
-		for component in &components {
-			if component.id() == Some(*id) {
-				if let Some(temp) = component.temperature() {
-					return Some(crate::round(temp as f64) as f32);
-				}
+		for component in components {
+			if component.id() == Some(*id)
+				&& let Some(temp) = component.temperature()
+			{
+				return Some(crate::round(temp as f64) as f32);
 			}
 		}
 	}
 
 	let keywords = ["cpu", "core", "package", "processor", "soc", "tctl", "tdie"];
 	let mut best: Option<f32> = None;
-	for component in &components {
+	for component in components {
 		let label = component.label().to_ascii_lowercase();
 		let id = component.id().unwrap_or("").to_ascii_lowercase();
-		if keywords.iter().any(|keyword| label.contains(keyword) || id.contains(keyword)) {
-			if let Some(temp) = component.temperature() {
-				best = Some(best.map_or(temp, |current| current.max(temp)));
-			}
+		if keywords.iter().any(|keyword| label.contains(keyword) || id.contains(keyword))
+			&& let Some(temp) = component.temperature()
+		{
+			best = Some(best.map_or(temp, |current| current.max(temp)));
 		}
 	}
 	if let Some(temp) = best {
 		return Some(crate::round(temp as f64) as f32);
 	}
 
-	for component in &components {
-		if let Some(id) = component.id() {
-			if id.starts_with("TXC") {
-				if let Some(temp) = component.temperature() {
-					return Some(crate::round(temp as f64) as f32);
-				}
-			}
+	for component in components {
+		if let Some(id) = component.id()
+			&& id.starts_with("TXC")
+			&& let Some(temp) = component.temperature()
+		{
+			return Some(crate::round(temp as f64) as f32);
 		}
 	}
 
-	for component in &components {
-		if component.id() == Some("thermal_zone0") {
-			if let Some(temp) = component.temperature() {
-				return Some(crate::round(temp as f64) as f32);
-			}
+	for component in components {
+		if component.id() == Some("thermal_zone0")
+			&& let Some(temp) = component.temperature()
+		{
+			return Some(crate::round(temp as f64) as f32);
 		}
 	}
 
@@ -1,108 +1,620 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use sysinfo::{Disk, Disks};
+
+use crate::{ByteSize, SystemInfoError};
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiskInfo {
-	/// 总磁盘空间(单位: GB)
-	pub total_space: u64,
-	/// 总已用磁盘空间(单位: GB)
-	pub total_used_space: u64,
-	/// 总可用磁盘空间(单位: GB)
-	pub total_free_space: u64,
+	/// 总磁盘空间
+	pub total_space: ByteSize,
+	/// 总已用磁盘空间
+	pub total_used_space: ByteSize,
+	/// 总可用磁盘空间
+	pub total_free_space: ByteSize,
 	/// 总体磁盘使用率
 	pub total_usage: f64,
 	/// 磁盘读速度(单位: KB/S)
 	pub read_speed: f32,
 	/// 磁盘写入速度(单位: KB/S)
 	pub write_speed: f32,
+	/// 磁盘读速度(单位: 字节/秒)，未经取整
+	pub read_bytes_per_second: f64,
+	/// 磁盘写入速度(单位: 字节/秒)，未经取整
+	pub write_bytes_per_second: f64,
 	/// 各个磁盘详细信息
 	pub disks: Vec<DiskDetail>,
 }
 
-
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg(feature = "disk")]
 pub struct DiskDetail {
 	/// 磁盘名称
 	pub name: String,
 	/// 磁盘挂载点
 	pub mount: String,
-	/// 总磁盘空间(单位: GB)
-	pub total_space: u64,
-	/// 已用磁盘空间(单位: GB)
-	pub used_space: u64,
-	/// 可用磁盘空间(单位: GB)
-	pub free_space: u64,
+	/// 总磁盘空间
+	pub total_space: ByteSize,
+	/// 已用磁盘空间
+	pub used_space: ByteSize,
+	/// 可用磁盘空间
+	pub free_space: ByteSize,
 	/// 磁盘使用率
 	pub usage: f32,
+	/// 文件系统类型，如 `ext4`、`ntfs`、`tmpfs`
+	pub file_system: String,
+	/// 底层设备类型
+	pub kind: DeviceKind,
+	/// 是否为可移动设备
+	pub removable: bool,
+	/// 是否以只读方式挂载
+	pub read_only: bool,
+	/// 挂载选项，如 `rw`、`relatime`，仅 Linux 下可用
+	pub mount_options: Vec<String>,
+	/// inode 总数，仅类 Unix 系统且文件系统支持时可用，网络文件系统不读取
+	pub inodes_total: Option<u64>,
+	/// 已用 inode 数
+	pub inodes_used: Option<u64>,
+	/// 可用 inode 数
+	pub inodes_free: Option<u64>,
+	/// inode 使用率
+	pub inode_usage: Option<f32>,
+	/// 块设备在采样间隔内的IO统计，仅 Linux 下可用
+	pub io: Option<DiskIoStats>,
+}
+
+/// 磁盘的底层设备类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum DeviceKind {
+	/// 固态硬盘
+	Ssd,
+	/// 机械硬盘
+	Hdd,
+	/// NVMe 固态硬盘
+	Nvme,
+	/// 网络文件系统，如 NFS、SMB
+	Network,
+	/// 内存文件系统，如 tmpfs
+	Tmpfs,
+	/// 无法识别
+	Unknown,
+}
+
+/// 块设备在采样间隔内的IO统计，由两次 `/proc/diskstats` 采样的差值计算
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskIoStats {
+	/// 完成的读请求数
+	pub reads_completed: u64,
+	/// 完成的写请求数
+	pub writes_completed: u64,
+	/// 读取的扇区数(每扇区 512 字节)
+	pub sectors_read: u64,
+	/// 写入的扇区数(每扇区 512 字节)
+	pub sectors_written: u64,
+	/// 读速度(单位: KB/S)
+	pub read_speed: f32,
+	/// 写入速度(单位: KB/S)
+	pub write_speed: f32,
+	/// 读速度(单位: 字节/秒)，未经取整
+	pub read_bytes_per_second: f64,
+	/// 写入速度(单位: 字节/秒)，未经取整
+	pub write_bytes_per_second: f64,
+	/// 设备处理IO的时间(单位: 毫秒)
+	pub io_time: u64,
+	/// 采样结束时正在处理的请求数
+	pub in_flight: u64,
+	/// 设备繁忙时间占比(单位: %)
+	pub utilization: f32,
+	/// 读请求平均延迟(单位: 毫秒)，间隔内无读请求时为 None
+	pub read_latency: Option<f32>,
+	/// 写请求平均延迟(单位: 毫秒)，间隔内无写请求时为 None
+	pub write_latency: Option<f32>,
+}
+
+/// `/proc/diskstats` 中单个块设备的累计计数
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskStat {
+	/// 完成的读请求数
+	pub reads_completed: u64,
+	/// 读取的扇区数
+	pub sectors_read: u64,
+	/// 读请求耗时(单位: 毫秒)
+	pub read_time: u64,
+	/// 完成的写请求数
+	pub writes_completed: u64,
+	/// 写入的扇区数
+	pub sectors_written: u64,
+	/// 写请求耗时(单位: 毫秒)
+	pub write_time: u64,
+	/// 正在处理的请求数
+	pub in_flight: u64,
+	/// 设备处理IO的时间(单位: 毫秒)
+	pub io_time: u64,
+}
+
+/// 一次 `/proc/diskstats` 采样
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskStats {
+	/// 各块设备的累计计数，键为设备名，如 `sda`、`nvme0n1p1`
+	pub devices: BTreeMap<String, DiskStat>,
 }
 
 impl Default for DiskInfo {
+	/// 获取磁盘信息，未检测到磁盘时各项均为 0，磁盘列表为空
 	fn default() -> Self {
-		use sysinfo::{Disks, ProcessesToUpdate, System};
-		let mut s = System::new_all();
-		s.refresh_processes(ProcessesToUpdate::All, true);
-		
-		let mut read_speed = 0f32;
-		let mut write_speed = 0f32;
-		for process in s.processes() {
-			let disk_usage = process.1.disk_usage();
-			read_speed += disk_usage.read_bytes as f32 / 1024.0;
-			write_speed += disk_usage.written_bytes as f32 / 1024.0;
+		Self::with_interval(DEFAULT_INTERVAL)
+	}
+}
+
+impl DiskInfo {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 获取磁盘信息，未检测到磁盘时返回 [SystemInfoError::DiskNotFound]
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		Self::try_with_interval(DEFAULT_INTERVAL)
+	}
+
+	/// 获取磁盘信息，读写速度与IO统计基于间隔 `interval` 的两次采样
+	///
+	/// 此函数会阻塞 `interval` 时长
+	pub fn with_interval(interval: Duration) -> Self {
+		let (disks, stat, mounts) = sample(interval);
+		Self::from_disks(&disks, stat.pair(), &mounts, interval)
+	}
+
+	/// 获取磁盘信息，读写速度与IO统计基于间隔 `interval` 的两次采样，
+	/// 未检测到磁盘时返回 [SystemInfoError::DiskNotFound]
+	pub fn try_with_interval(interval: Duration) -> Result<Self, SystemInfoError> {
+		let (disks, stat, mounts) = sample(interval);
+		Self::try_from_disks(&disks, stat.pair(), &mounts, interval)
+	}
+
+	pub(crate) fn try_from_disks(
+		disks: &Disks,
+		stat: Option<(&DiskStats, &DiskStats)>,
+		mounts: &MountSample,
+		elapsed: Duration,
+	) -> Result<Self, SystemInfoError> {
+		if disks.list().is_empty() {
+			return Err(SystemInfoError::DiskNotFound);
 		}
-		
-		let disks = Disks::new_with_refreshed_list();
-		
-		let mut total_disk_space = 0u64;
-		let mut total_used_space = 0u64;
-		let mut total_free_space = 0u64;
+		Ok(Self::from_disks(disks, stat, mounts, elapsed))
+	}
+
+	/// 从已刷新的 [Disks] 与挂载信息中构建磁盘信息，不会读取文件或调用 `statvfs`
+	///
+	/// 提供两次 `/proc/diskstats` 采样时，各磁盘附带IO统计，总读写速度为各块设备速度之和(同一设备只计一次)；
+	/// 否则读写速度由 `sysinfo` 最近一次刷新的增量除以 `elapsed` 得到。`elapsed` 为零时速度为 0
+	pub(crate) fn from_disks(
+		disks: &Disks,
+		stat: Option<(&DiskStats, &DiskStats)>,
+		mounts: &MountSample,
+		elapsed: Duration,
+	) -> Self {
+		let secs = elapsed.as_secs_f64();
+		let mut read_bytes = 0f64;
+		let mut write_bytes = 0f64;
+		let mut counted_devices = HashSet::new();
+
 		let mut disk_details = Vec::new();
-		
+
 		for disk in disks.list() {
-			let total_space = disk.total_space() / (1024 * 1024 * 1024);
-			let free_space = disk.available_space() / (1024 * 1024 * 1024);
-			let used_space = total_space - free_space;
-			
-			let usage = if total_space > 0 {
-				(used_space as f64 / total_space as f64) * 100.0
-			} else {
-				0.0
-			};
-			
+			let name = disk.name().to_string_lossy().to_string();
+			let device = block_device_name(&name);
+			let io = stat.filter(|_| secs > 0.0).and_then(|(previous, current)| {
+				let device = device.as_deref()?;
+				Some(current.devices.get(device)?.io_since(previous.devices.get(device)?, elapsed))
+			});
+			let kind = DeviceKind::detect(disk, device.as_deref());
+
+			if let Some(io) = &io {
+				if device.is_some_and(|device| counted_devices.insert(device)) {
+					read_bytes += io.read_bytes_per_second;
+					write_bytes += io.write_bytes_per_second;
+				}
+			} else if stat.is_none() && secs > 0.0 {
+				let disk_usage = disk.usage();
+				read_bytes += disk_usage.read_bytes as f64 / secs;
+				write_bytes += disk_usage.written_bytes as f64 / secs;
+			}
+
+			let total_space = ByteSize::from_bytes(disk.total_space());
+			let free_space = ByteSize::from_bytes(disk.available_space());
+			let used_space = total_space.saturating_sub(free_space);
+
+			let mount = disk.mount_point().to_string_lossy().trim_end_matches('\\').to_string();
+			let (inodes_total, inodes_free) =
+				mounts.inodes.get(disk.mount_point()).copied().unzip();
+			let inodes_used = inodes_total.zip(inodes_free).map(|(total, free)| total - free);
+			let inode_usage = inodes_total
+				.zip(inodes_used)
+				.map(|(total, used)| crate::round(used as f64 / total as f64 * 100.0) as f32);
+
 			let disk_detail = DiskDetail {
-				name: disk.name().to_string_lossy().to_string(),
-				mount: disk.mount_point().to_string_lossy().trim_end_matches('\\').to_string(),
+				name,
+				mount_options: mounts.options.get(&mount).cloned().unwrap_or_default(),
+				mount,
 				total_space,
 				used_space,
 				free_space,
-				usage: usage.round() as f32,
+				usage: used_space.percent_of(total_space).round() as f32,
+				file_system: disk.file_system().to_string_lossy().to_string(),
+				kind,
+				removable: disk.is_removable(),
+				read_only: disk.is_read_only(),
+				inodes_total,
+				inodes_used,
+				inodes_free,
+				inode_usage,
+				io,
 			};
-			
-			total_disk_space += total_space;
-			total_used_space += used_space;
-			total_free_space += free_space;
 			disk_details.push(disk_detail);
 		}
-		
-		let total_usage = if total_disk_space > 0 {
-			(total_used_space / total_disk_space) as f64 * 100.0
-		} else {
-			0.0
-		};
-		
+
+		Self::from_details(disk_details, read_bytes, write_bytes)
+	}
+
+	/// 排除 tmpfs、overlay、squashfs 等伪文件系统，并重新计算总空间与使用率
+	///
+	/// 读写速度来自块设备统计，伪文件系统不计入其中，因此保持不变
+	pub fn without_pseudo_filesystems(self) -> Self {
+		let disks = self.disks.into_iter().filter(|disk| !disk.is_pseudo()).collect();
+		Self::from_details(disks, self.read_bytes_per_second, self.write_bytes_per_second)
+	}
+
+	fn from_details(disks: Vec<DiskDetail>, read_bytes: f64, write_bytes: f64) -> Self {
+		let total_space = disks.iter().map(|disk| disk.total_space).sum::<ByteSize>();
+		let total_used_space = disks.iter().map(|disk| disk.used_space).sum::<ByteSize>();
+		let total_free_space = disks.iter().map(|disk| disk.free_space).sum::<ByteSize>();
+
 		Self {
-			total_space: total_disk_space,
+			total_space,
 			total_used_space,
 			total_free_space,
-			total_usage,
-			read_speed: read_speed.round(),
-			write_speed: write_speed.round(),
-			disks: disk_details,
+			total_usage: total_used_space.percent_of(total_space),
+			read_speed: (read_bytes / 1024.0).round() as f32,
+			write_speed: (write_bytes / 1024.0).round() as f32,
+			read_bytes_per_second: read_bytes,
+			write_bytes_per_second: write_bytes,
+			disks,
 		}
 	}
 }
 
-
+/// 与旧版本单位字段数值相同的访问器，按 1024 进制换算并向下取整
 impl DiskInfo {
-	pub fn new() -> Self {
-		Self::default()
+	/// 总磁盘空间(单位: GB)
+	pub fn total_space_gb(&self) -> u64 {
+		self.total_space.whole_gib()
+	}
+
+	/// 总已用磁盘空间(单位: GB)
+	pub fn total_used_space_gb(&self) -> u64 {
+		self.total_used_space.whole_gib()
+	}
+
+	/// 总可用磁盘空间(单位: GB)
+	pub fn total_free_space_gb(&self) -> u64 {
+		self.total_free_space.whole_gib()
+	}
+}
+
+impl DiskDetail {
+	/// 是否为 tmpfs、overlay、squashfs 等不占用实际磁盘空间或只读打包的伪文件系统
+	pub fn is_pseudo(&self) -> bool {
+		const PSEUDO_FILESYSTEMS: &[&str] =
+			&["tmpfs", "devtmpfs", "ramfs", "overlay", "squashfs", "devfs", "autofs"];
+		self.kind == DeviceKind::Tmpfs || PSEUDO_FILESYSTEMS.contains(&self.file_system.as_str())
+	}
+
+	/// 总磁盘空间(单位: GB)，与旧版本字段相同，按 1024 进制换算并向下取整
+	pub fn total_space_gb(&self) -> u64 {
+		self.total_space.whole_gib()
+	}
+
+	/// 已用磁盘空间(单位: GB)，与旧版本字段相同，按 1024 进制换算并向下取整
+	pub fn used_space_gb(&self) -> u64 {
+		self.used_space.whole_gib()
+	}
+
+	/// 可用磁盘空间(单位: GB)，与旧版本字段相同，按 1024 进制换算并向下取整
+	pub fn free_space_gb(&self) -> u64 {
+		self.free_space.whole_gib()
+	}
+}
+
+impl DeviceKind {
+	fn detect(disk: &Disk, device: Option<&str>) -> Self {
+		const MEMORY_FILESYSTEMS: &[&str] = &["tmpfs", "devtmpfs", "ramfs"];
+
+		if is_network_filesystem(disk) {
+			return Self::Network;
+		}
+		let file_system = disk.file_system().to_string_lossy();
+		if MEMORY_FILESYSTEMS.contains(&file_system.as_ref()) {
+			return Self::Tmpfs;
+		}
+		if device.is_some_and(|device| device.starts_with("nvme")) {
+			return Self::Nvme;
+		}
+		match disk.kind() {
+			sysinfo::DiskKind::SSD => Self::Ssd,
+			sysinfo::DiskKind::HDD => Self::Hdd,
+			sysinfo::DiskKind::Unknown(_) => Self::Unknown,
+		}
+	}
+
+	/// 类型名称，如 `"ssd"`、`"nvme"`
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Ssd => "ssd",
+			Self::Hdd => "hdd",
+			Self::Nvme => "nvme",
+			Self::Network => "network",
+			Self::Tmpfs => "tmpfs",
+			Self::Unknown => "unknown",
+		}
+	}
+}
+
+impl fmt::Display for DeviceKind {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl DiskStat {
+	/// 计算自 `previous` 以来经过 `elapsed` 的IO统计
+	pub fn io_since(&self, previous: &DiskStat, elapsed: Duration) -> DiskIoStats {
+		const SECTOR_SIZE: f64 = 512.0;
+		let reads_completed = self.reads_completed.saturating_sub(previous.reads_completed);
+		let writes_completed = self.writes_completed.saturating_sub(previous.writes_completed);
+		let sectors_read = self.sectors_read.saturating_sub(previous.sectors_read);
+		let sectors_written = self.sectors_written.saturating_sub(previous.sectors_written);
+		let io_time = self.io_time.saturating_sub(previous.io_time);
+
+		let secs = elapsed.as_secs_f64();
+		let bytes_per_second =
+			|sectors: u64| if secs > 0.0 { sectors as f64 * SECTOR_SIZE / secs } else { 0.0 };
+		let read_bytes_per_second = bytes_per_second(sectors_read);
+		let write_bytes_per_second = bytes_per_second(sectors_written);
+		let latency = |time: u64, requests: u64| {
+			(requests > 0).then(|| crate::round(time as f64 / requests as f64) as f32)
+		};
+		let utilization = if secs > 0.0 {
+			crate::round((io_time as f64 / (secs * 1000.0) * 100.0).min(100.0)) as f32
+		} else {
+			0.0
+		};
+
+		DiskIoStats {
+			reads_completed,
+			writes_completed,
+			sectors_read,
+			sectors_written,
+			read_speed: crate::round(read_bytes_per_second / 1024.0) as f32,
+			write_speed: crate::round(write_bytes_per_second / 1024.0) as f32,
+			read_bytes_per_second,
+			write_bytes_per_second,
+			io_time,
+			in_flight: self.in_flight,
+			utilization,
+			read_latency: latency(
+				self.read_time.saturating_sub(previous.read_time),
+				reads_completed,
+			),
+			write_latency: latency(
+				self.write_time.saturating_sub(previous.write_time),
+				writes_completed,
+			),
+		}
+	}
+}
+
+impl DiskStats {
+	/// 解析 `/proc/diskstats` 格式的文本
+	///
+	/// 每行依次为主设备号、次设备号、设备名与至少 11 列计数
+	pub fn parse(text: &str) -> Result<Self, SystemInfoError> {
+		const SOURCE: &str = "/proc/diskstats";
+		let mut devices = BTreeMap::new();
+
+		for line in text.lines() {
+			let mut fields = line.split_whitespace().skip(2);
+			let Some(name) = fields.next() else {
+				continue;
+			};
+			let values = fields
+				.take(11)
+				.map(|value| value.parse::<u64>())
+				.collect::<Result<Vec<_>, _>>()
+				.map_err(|_| SystemInfoError::Parse(SOURCE))?;
+			if values.len() < 11 {
+				return Err(SystemInfoError::Parse(SOURCE));
+			}
+			let stat = DiskStat {
+				reads_completed: values[0],
+				sectors_read: values[2],
+				read_time: values[3],
+				writes_completed: values[4],
+				sectors_written: values[6],
+				write_time: values[7],
+				in_flight: values[8],
+				io_time: values[9],
+			};
+			devices.insert(name.to_string(), stat);
+		}
+
+		Ok(Self { devices })
+	}
+
+	/// 读取当前的 `/proc/diskstats`
+	#[cfg(target_os = "linux")]
+	pub fn read() -> Result<Self, SystemInfoError> {
+		Self::parse(&crate::error::read_file("/proc/diskstats")?)
+	}
+}
+
+/// 最近两次 `/proc/diskstats` 采样，非 Linux 平台下始终为空
+#[derive(Debug, Default)]
+pub(crate) struct DiskStatSample {
+	previous: Option<DiskStats>,
+	current: Option<DiskStats>,
+}
+
+impl DiskStatSample {
+	pub(crate) fn refresh(&mut self) {
+		#[cfg(target_os = "linux")]
+		{
+			self.previous = self.current.take();
+			self.current = DiskStats::read().ok();
+		}
+	}
+
+	pub(crate) fn pair(&self) -> Option<(&DiskStats, &DiskStats)> {
+		Some((self.previous.as_ref()?, self.current.as_ref()?))
+	}
+}
+
+/// 挂载选项与各挂载点的 inode 统计，随 [crate::SystemInfo::refresh_disk] 读取
+///
+/// `statvfs` 在失去响应的 NFS、CIFS 等网络挂载上会一直阻塞，因此网络文件系统不读取 inode 统计
+#[derive(Debug, Default)]
+pub(crate) struct MountSample {
+	options: HashMap<String, Vec<String>>,
+	inodes: HashMap<PathBuf, (u64, u64)>,
+}
+
+impl MountSample {
+	pub(crate) fn refresh(&mut self, disks: &Disks) {
+		self.options = mount_options();
+		self.inodes = disks
+			.list()
+			.iter()
+			.filter(|disk| !is_network_filesystem(disk))
+			.filter_map(|disk| {
+				Some((disk.mount_point().to_path_buf(), inodes(disk.mount_point())?))
+			})
+			.collect();
 	}
 }
 
+fn is_network_filesystem(disk: &Disk) -> bool {
+	const NETWORK_FILESYSTEMS: &[&str] =
+		&["nfs", "nfs4", "cifs", "smbfs", "smb3", "9p", "ceph", "glusterfs", "fuse.sshfs"];
+	NETWORK_FILESYSTEMS.contains(&disk.file_system().to_string_lossy().as_ref())
+}
+
+/// 将磁盘名(如 `/dev/sda1`、`/dev/mapper/root`)解析为 `/proc/diskstats` 中的设备名
+fn block_device_name(disk_name: &str) -> Option<String> {
+	let path = Path::new(disk_name);
+	if !path.starts_with("/dev") {
+		return None;
+	}
+	let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+	Some(path.file_name()?.to_string_lossy().into_owned())
+}
+
+/// 读取各挂载点的挂载选项，同一挂载点多次挂载时以最后一次为准
+fn mount_options() -> HashMap<String, Vec<String>> {
+	#[cfg(target_os = "linux")]
+	{
+		crate::error::read_file("/proc/mounts").map(|text| parse_mounts(&text)).unwrap_or_default()
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	{
+		HashMap::new()
+	}
+}
+
+/// 解析 `/proc/mounts` 格式的文本，挂载点中的空格等字符以八进制转义(如 `\040`)
+#[cfg(target_os = "linux")]
+fn parse_mounts(text: &str) -> HashMap<String, Vec<String>> {
+	let unescape = |field: &str| {
+		let mut result = String::with_capacity(field.len());
+		let mut rest = field;
+		while let Some(index) = rest.find('\\') {
+			result.push_str(&rest[..index]);
+			let escaped = rest.get(index + 1..index + 4);
+			match escaped.and_then(|octal| u8::from_str_radix(octal, 8).ok()) {
+				Some(byte) => {
+					result.push(byte as char);
+					rest = &rest[index + 4..];
+				}
+				None => {
+					result.push('\\');
+					rest = &rest[index + 1..];
+				}
+			}
+		}
+		result.push_str(rest);
+		result
+	};
+
+	text.lines()
+		.filter_map(|line| {
+			let mut fields = line.split_whitespace().skip(1);
+			let mount = unescape(fields.next()?);
+			let options = fields.nth(1)?.split(',').map(str::to_string).collect();
+			Some((mount, options))
+		})
+		.collect()
+}
+
+/// 通过 `statvfs` 读取 inode 总数与可用数，文件系统不支持 inode 统计时返回 None
+fn inodes(mount: &Path) -> Option<(u64, u64)> {
+	#[cfg(unix)]
+	{
+		use std::ffi::CString;
+		use std::os::unix::ffi::OsStrExt;
+
+		let path = CString::new(mount.as_os_str().as_bytes()).ok()?;
+		let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+		// SAFETY: `path` 为以 NUL 结尾的有效字符串，`stat` 指向足够大小的可写内存
+		if unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+			return None;
+		}
+		// SAFETY: statvfs 调用成功时已完整写入 `stat`
+		let stat = unsafe { stat.assume_init() };
+		// fsfilcnt_t 在 macOS 下为 u32
+		#[allow(clippy::unnecessary_cast)]
+		let (total, free) = (stat.f_files as u64, stat.f_ffree as u64);
+		(total > 0).then(|| (total, free.min(total)))
+	}
+
+	#[cfg(not(unix))]
+	{
+		let _ = mount;
+		None
+	}
+}
+
+const DEFAULT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 一次性采样磁盘数据，间隔 `interval` 刷新两次以计算读写速度与IO统计
+fn sample(interval: Duration) -> (Disks, DiskStatSample, MountSample) {
+	use std::thread::sleep;
+
+	let mut stat = DiskStatSample::default();
+	let mut mounts = MountSample::default();
+	let mut disks = Disks::new_with_refreshed_list();
+	stat.refresh();
+	sleep(interval);
+	disks.refresh(true);
+	stat.refresh();
+	mounts.refresh(&disks);
+	(disks, stat, mounts)
+}
@@ -0,0 +1,108 @@
+use std::path::{Path, PathBuf};
+use std::{error, fmt, io};
+
+/// 系统信息采集错误
+///
+/// 每个变体对应一个失败的探测项，便于调用方判断具体是哪一项信息无法获取
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SystemInfoError {
+	/// 无法获取主机名
+	HostName,
+	/// 无法获取操作系统名
+	OsName,
+	/// 无法获取操作系统版本
+	OsVersion,
+	/// 系统启动时间无效(单位: 秒)
+	BootTime(u64),
+	/// 无法获取时区
+	TimeZone(iana_time_zone::GetTimezoneError),
+	/// 未检测到CPU
+	CpuNotFound,
+	/// 无法获取内存信息
+	MemoryUnavailable,
+	/// 未检测到磁盘
+	DiskNotFound,
+	/// 未检测到活跃网卡
+	NetworkNotFound,
+	/// 未检测到GPU
+	GpuNotFound,
+	/// 读取系统文件失败
+	Io {
+		/// 文件路径
+		path: PathBuf,
+		/// 底层IO错误
+		source: io::Error,
+	},
+	/// 系统文件内容格式无法解析，值为数据来源
+	Parse(&'static str),
+	/// 未知或未启用对应 feature 的模块名称
+	UnknownSubsystem(String),
+	/// 刷新间隔不是正的秒数
+	InvalidInterval(String),
+	/// 查找进程时的正则表达式无效
+	#[cfg(feature = "process-regex")]
+	InvalidPattern(regex::Error),
+}
+
+impl fmt::Display for SystemInfoError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::HostName => write!(f, "failed to get host name"),
+			Self::OsName => write!(f, "failed to get os name"),
+			Self::OsVersion => write!(f, "failed to get os version"),
+			Self::BootTime(secs) => write!(f, "invalid boot time timestamp: {secs}"),
+			Self::TimeZone(err) => write!(f, "failed to get time zone: {err}"),
+			Self::CpuNotFound => write!(f, "no cpu detected"),
+			Self::MemoryUnavailable => write!(f, "memory information unavailable"),
+			Self::DiskNotFound => write!(f, "no disk detected"),
+			Self::NetworkNotFound => write!(f, "no active network interface detected"),
+			Self::GpuNotFound => write!(f, "no gpu detected"),
+			Self::Io { path, source } => write!(f, "failed to read {}: {source}", path.display()),
+			Self::Parse(source) => write!(f, "failed to parse {source}"),
+			Self::UnknownSubsystem(name) => write!(f, "unknown subsystem `{name}`"),
+			Self::InvalidInterval(value) => {
+				write!(f, "invalid interval `{value}`, expected a positive number of seconds")
+			}
+			#[cfg(feature = "process-regex")]
+			Self::InvalidPattern(err) => write!(f, "invalid process name pattern: {err}"),
+		}
+	}
+}
+
+impl error::Error for SystemInfoError {
+	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+		match self {
+			Self::TimeZone(err) => Some(err),
+			Self::Io { source, .. } => Some(source),
+			#[cfg(feature = "process-regex")]
+			Self::InvalidPattern(err) => Some(err),
+			_ => None,
+		}
+	}
+}
+
+impl From<iana_time_zone::GetTimezoneError> for SystemInfoError {
+	fn from(err: iana_time_zone::GetTimezoneError) -> Self {
+		Self::TimeZone(err)
+	}
+}
+
+/// 读取文件内容，失败时附带文件路径
+#[cfg_attr(
+	not(any(
+		feature = "host",
+		feature = "cpu",
+		feature = "memory",
+		feature = "network",
+		feature = "gpu",
+		feature = "pci-ids",
+		all(target_os = "linux", any(feature = "disk", feature = "load"))
+	)),
+	allow(dead_code)
+)]
+pub(crate) fn read_file(path: impl AsRef<Path>) -> Result<String, SystemInfoError> {
+	let path = path.as_ref();
+	std::fs::read_to_string(path)
+		.map_err(|source| SystemInfoError::Io { path: path.to_path_buf(), source })
+}
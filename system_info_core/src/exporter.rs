@@ -0,0 +1,3 @@
+//! 将采集到的系统信息导出为监控系统使用的格式
+
+pub mod prometheus;
@@ -0,0 +1,793 @@
+//! Prometheus 文本格式(exposition format 0.0.4)导出
+//!
+//! 指标均以 `system_info_` 为前缀，容量以字节、频率以赫兹、时间以秒为单位，
+//! 累计值为以 `_total` 结尾的 counter，其余为 gauge。无法获取的值不会输出对应样本。
+//!
+//! # 示例
+//!
+//! ```no_run
+//! use puniyu_system_info::{Snapshot, exporter::prometheus};
+//!
+//! let metrics = prometheus::render(&Snapshot::new());
+//! println!("{metrics}");
+//! ```
+
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use chrono::{DateTime, Utc};
+
+use crate::{PartialSnapshot, Snapshot};
+#[cfg(feature = "process")]
+use crate::{ProcessInfo, ProcessTree};
+
+/// 文本格式对应的 HTTP `Content-Type`
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// 将快照中所有已启用模块的数据渲染为 Prometheus 文本格式
+pub fn render(snapshot: &Snapshot) -> String {
+	let mut encoder = Encoder::default();
+	encode_timestamp(&mut encoder, snapshot.timestamp);
+	#[cfg(feature = "host")]
+	encode_host(&mut encoder, &snapshot.host);
+	#[cfg(feature = "cpu")]
+	encode_cpu(&mut encoder, &snapshot.cpu);
+	#[cfg(feature = "memory")]
+	encode_memory(&mut encoder, &snapshot.memory);
+	#[cfg(feature = "disk")]
+	encode_disk(&mut encoder, &snapshot.disk);
+	#[cfg(feature = "network")]
+	encode_network(&mut encoder, &snapshot.networks);
+	#[cfg(feature = "process")]
+	encode_process(&mut encoder, &snapshot.processes);
+	#[cfg(feature = "gpu")]
+	encode_gpu(&mut encoder, &snapshot.gpus);
+	#[cfg(feature = "load")]
+	encode_load(&mut encoder, &snapshot.load);
+	encoder.out
+}
+
+/// 将部分快照中已采集模块的数据渲染为 Prometheus 文本格式，未采集的模块不输出
+pub fn render_partial(snapshot: &PartialSnapshot) -> String {
+	let mut encoder = Encoder::default();
+	encode_timestamp(&mut encoder, snapshot.timestamp);
+	#[cfg(feature = "host")]
+	if let Some(host) = &snapshot.host {
+		encode_host(&mut encoder, host);
+	}
+	#[cfg(feature = "cpu")]
+	if let Some(cpu) = &snapshot.cpu {
+		encode_cpu(&mut encoder, cpu);
+	}
+	#[cfg(feature = "memory")]
+	if let Some(memory) = &snapshot.memory {
+		encode_memory(&mut encoder, memory);
+	}
+	#[cfg(feature = "disk")]
+	if let Some(disk) = &snapshot.disk {
+		encode_disk(&mut encoder, disk);
+	}
+	#[cfg(feature = "network")]
+	if let Some(networks) = &snapshot.networks {
+		encode_network(&mut encoder, networks);
+	}
+	#[cfg(feature = "process")]
+	if let Some(processes) = &snapshot.processes {
+		encode_process(&mut encoder, processes);
+	}
+	#[cfg(feature = "gpu")]
+	if let Some(gpus) = &snapshot.gpus {
+		encode_gpu(&mut encoder, gpus);
+	}
+	#[cfg(feature = "load")]
+	if let Some(load) = &snapshot.load {
+		encode_load(&mut encoder, load);
+	}
+	encoder.out
+}
+
+fn encode_timestamp(encoder: &mut Encoder, timestamp: DateTime<Utc>) {
+	encoder.family(
+		"system_info_snapshot_timestamp_seconds",
+		MetricType::Gauge,
+		"Unix time at which the snapshot was taken.",
+		[(labels([]), timestamp.timestamp_millis() as f64 / 1000.0)],
+	);
+}
+
+#[cfg(feature = "host")]
+fn encode_host(encoder: &mut Encoder, host: &crate::HostInfo) {
+	encoder.family(
+		"system_info_host_info",
+		MetricType::Gauge,
+		"Host metadata, the value is always 1.",
+		[(
+			labels([
+				("host_name", host.host_name.clone()),
+				("os_name", host.os_name.clone()),
+				("os_version", host.os_version.clone()),
+				("os_type", host.os_type.clone()),
+				("arch", host.arch.clone()),
+				("virtualization", host.virtualization.to_string()),
+			]),
+			1.0,
+		)],
+	);
+	encoder.family(
+		"system_info_boot_time_seconds",
+		MetricType::Gauge,
+		"Unix time at which the system booted.",
+		[(labels([]), host.boot_time.timestamp() as f64)],
+	);
+	encoder.family(
+		"system_info_uptime_seconds",
+		MetricType::Gauge,
+		"Time since the system booted.",
+		[(labels([]), host.uptime as f64)],
+	);
+}
+
+#[cfg(feature = "cpu")]
+fn encode_cpu(encoder: &mut Encoder, cpu: &crate::CpuInfo) {
+	const GHZ: f64 = 1e9;
+
+	encoder.family(
+		"system_info_cpu_info",
+		MetricType::Gauge,
+		"CPU model, the value is always 1.",
+		[(labels([("model", cpu.model_name.clone())]), 1.0)],
+	);
+	encoder.family(
+		"system_info_cpu_physical_cores",
+		MetricType::Gauge,
+		"Number of physical CPU cores.",
+		[(labels([]), cpu.physical_cores as f64)],
+	);
+	encoder.family(
+		"system_info_cpu_logical_cores",
+		MetricType::Gauge,
+		"Number of logical CPUs.",
+		[(labels([]), cpu.logical_cores as f64)],
+	);
+	encoder.family(
+		"system_info_cpu_frequency_hertz",
+		MetricType::Gauge,
+		"Base CPU frequency.",
+		[(labels([]), (float(cpu.frequency) * GHZ).round())],
+	);
+	encoder.family(
+		"system_info_cpu_usage_percent",
+		MetricType::Gauge,
+		"Global CPU usage.",
+		cpu.usage.map(|usage| (labels([]), float(usage))),
+	);
+	encoder.family(
+		"system_info_cpu_temperature_celsius",
+		MetricType::Gauge,
+		"CPU temperature.",
+		cpu.temperature.map(|temperature| (labels([]), float(temperature))),
+	);
+	encoder.family(
+		"system_info_cpu_time_percent",
+		MetricType::Gauge,
+		"Share of CPU time spent in each mode.",
+		cpu.times.iter().flat_map(|times| cpu_time_samples(times, Vec::new())),
+	);
+	encoder.family(
+		"system_info_cpu_core_usage_percent",
+		MetricType::Gauge,
+		"Usage of each logical CPU.",
+		cpu.cores.iter().map(|core| (labels([("core", core.id.to_string())]), float(core.usage))),
+	);
+	encoder.family(
+		"system_info_cpu_core_frequency_hertz",
+		MetricType::Gauge,
+		"Current frequency of each logical CPU.",
+		cpu.cores.iter().map(|core| {
+			(labels([("core", core.id.to_string())]), (float(core.frequency) * GHZ).round())
+		}),
+	);
+	encoder.family(
+		"system_info_cpu_core_time_percent",
+		MetricType::Gauge,
+		"Share of time each logical CPU spent in each mode.",
+		cpu.cores.iter().flat_map(|core| {
+			core.times
+				.iter()
+				.flat_map(|times| cpu_time_samples(times, vec![("core", core.id.to_string())]))
+		}),
+	);
+}
+
+#[cfg(feature = "cpu")]
+fn cpu_time_samples(times: &crate::CpuTimePercent, base: Labels) -> Vec<(Labels, f64)> {
+	[
+		("user", times.user),
+		("nice", times.nice),
+		("system", times.system),
+		("idle", times.idle),
+		("iowait", times.iowait),
+		("irq", times.irq),
+		("softirq", times.softirq),
+		("steal", times.steal),
+		("guest", times.guest),
+	]
+	.into_iter()
+	.map(|(mode, value)| {
+		let mut labels = base.clone();
+		labels.push(("mode", mode.to_string()));
+		(labels, float(value))
+	})
+	.collect()
+}
+
+#[cfg(feature = "memory")]
+fn encode_memory(encoder: &mut Encoder, memory: &crate::MemoryInfo) {
+	use crate::ByteSize;
+
+	let bytes = |size: Option<ByteSize>| size.map(|size| (labels([]), size.as_bytes() as f64));
+	encoder.family(
+		"system_info_memory_total_bytes",
+		MetricType::Gauge,
+		"Total memory.",
+		bytes(Some(memory.total)),
+	);
+	encoder.family(
+		"system_info_memory_used_bytes",
+		MetricType::Gauge,
+		"Used memory.",
+		bytes(Some(memory.used)),
+	);
+	encoder.family(
+		"system_info_memory_free_bytes",
+		MetricType::Gauge,
+		"Unused memory (MemFree on Linux), excluding reclaimable caches; prefer available.",
+		bytes(Some(memory.free)),
+	);
+	encoder.family(
+		"system_info_memory_available_bytes",
+		MetricType::Gauge,
+		"Memory available for allocation including reclaimable caches.",
+		bytes(memory.available),
+	);
+	encoder.family(
+		"system_info_memory_usage_percent",
+		MetricType::Gauge,
+		"Memory usage.",
+		[(labels([]), float(memory.usage))],
+	);
+	encoder.family(
+		"system_info_swap_total_bytes",
+		MetricType::Gauge,
+		"Total swap.",
+		bytes(memory.swap_total),
+	);
+	encoder.family(
+		"system_info_swap_used_bytes",
+		MetricType::Gauge,
+		"Used swap.",
+		bytes(memory.swap_used),
+	);
+	encoder.family(
+		"system_info_swap_free_bytes",
+		MetricType::Gauge,
+		"Free swap.",
+		bytes(memory.swap_free),
+	);
+	encoder.family(
+		"system_info_swap_usage_percent",
+		MetricType::Gauge,
+		"Swap usage.",
+		memory.swap_usage.map(|usage| (labels([]), float(usage))),
+	);
+}
+
+#[cfg(feature = "disk")]
+fn encode_disk(encoder: &mut Encoder, disk: &crate::DiskInfo) {
+	let disk_labels = |detail: &crate::DiskDetail| {
+		labels([
+			("device", detail.name.clone()),
+			("mount", detail.mount.clone()),
+			("fstype", detail.file_system.clone()),
+		])
+	};
+	let per_disk = |value: fn(&crate::DiskDetail) -> Option<f64>| {
+		disk.disks.iter().filter_map(move |detail| Some((disk_labels(detail), value(detail)?)))
+	};
+
+	encoder.family(
+		"system_info_disk_total_bytes",
+		MetricType::Gauge,
+		"Filesystem size.",
+		per_disk(|detail| Some(detail.total_space.as_bytes() as f64)),
+	);
+	encoder.family(
+		"system_info_disk_used_bytes",
+		MetricType::Gauge,
+		"Used filesystem space.",
+		per_disk(|detail| Some(detail.used_space.as_bytes() as f64)),
+	);
+	encoder.family(
+		"system_info_disk_free_bytes",
+		MetricType::Gauge,
+		"Free filesystem space.",
+		per_disk(|detail| Some(detail.free_space.as_bytes() as f64)),
+	);
+	encoder.family(
+		"system_info_disk_usage_percent",
+		MetricType::Gauge,
+		"Filesystem usage.",
+		per_disk(|detail| Some(float(detail.usage))),
+	);
+	encoder.family(
+		"system_info_disk_read_only",
+		MetricType::Gauge,
+		"Whether the filesystem is mounted read-only.",
+		per_disk(|detail| Some(if detail.read_only { 1.0 } else { 0.0 })),
+	);
+	encoder.family(
+		"system_info_disk_inodes",
+		MetricType::Gauge,
+		"Total inodes.",
+		per_disk(|detail| detail.inodes_total.map(|inodes| inodes as f64)),
+	);
+	encoder.family(
+		"system_info_disk_inodes_free",
+		MetricType::Gauge,
+		"Free inodes.",
+		per_disk(|detail| detail.inodes_free.map(|inodes| inodes as f64)),
+	);
+	encoder.family(
+		"system_info_disk_read_bytes_per_second",
+		MetricType::Gauge,
+		"Read throughput of the underlying block device over the last interval.",
+		per_disk(|detail| detail.io.as_ref().map(|io| io.read_bytes_per_second.round())),
+	);
+	encoder.family(
+		"system_info_disk_write_bytes_per_second",
+		MetricType::Gauge,
+		"Write throughput of the underlying block device over the last interval.",
+		per_disk(|detail| detail.io.as_ref().map(|io| io.write_bytes_per_second.round())),
+	);
+	encoder.family(
+		"system_info_disk_io_utilization_percent",
+		MetricType::Gauge,
+		"Share of the last interval the block device was busy.",
+		per_disk(|detail| detail.io.as_ref().map(|io| float(io.utilization))),
+	);
+	encoder.family(
+		"system_info_disk_io_in_flight",
+		MetricType::Gauge,
+		"Requests in flight on the block device.",
+		per_disk(|detail| detail.io.as_ref().map(|io| io.in_flight as f64)),
+	);
+	encoder.family(
+		"system_info_disk_total_read_bytes_per_second",
+		MetricType::Gauge,
+		"Combined read throughput of all disks.",
+		[(labels([]), disk.read_bytes_per_second.round())],
+	);
+	encoder.family(
+		"system_info_disk_total_write_bytes_per_second",
+		MetricType::Gauge,
+		"Combined write throughput of all disks.",
+		[(labels([]), disk.write_bytes_per_second.round())],
+	);
+}
+
+#[cfg(feature = "network")]
+fn encode_network(encoder: &mut Encoder, networks: &[crate::NetworkInfo]) {
+	let interface = |network: &crate::NetworkInfo| labels([("interface", network.name.clone())]);
+
+	encoder.family(
+		"system_info_network_info",
+		MetricType::Gauge,
+		"Interface metadata, the value is always 1.",
+		networks.iter().map(|network| {
+			let mut labels = interface(network);
+			labels.push(("mac_address", network.mac_addr.to_string()));
+			(labels, 1.0)
+		}),
+	);
+	encoder.family(
+		"system_info_network_address_info",
+		MetricType::Gauge,
+		"IP addresses assigned to each interface, the value is always 1.",
+		networks.iter().flat_map(|network| {
+			network.ip_info.iter().map(move |ip| {
+				let mut labels = interface(network);
+				labels.push(("address", ip.ip_address.to_string()));
+				labels.push(("prefix", ip.netmask.to_string()));
+				(labels, 1.0)
+			})
+		}),
+	);
+	encoder.family(
+		"system_info_network_receive_bytes_total",
+		MetricType::Counter,
+		"Bytes received since the interface came up.",
+		networks
+			.iter()
+			.map(|network| (interface(network), network.total_download.as_bytes() as f64)),
+	);
+	encoder.family(
+		"system_info_network_transmit_bytes_total",
+		MetricType::Counter,
+		"Bytes transmitted since the interface came up.",
+		networks.iter().map(|network| (interface(network), network.total_upload.as_bytes() as f64)),
+	);
+	encoder.family(
+		"system_info_network_receive_bytes_per_second",
+		MetricType::Gauge,
+		"Receive throughput over the last interval.",
+		networks.iter().map(|network| (interface(network), network.download_bytes_per_second)),
+	);
+	encoder.family(
+		"system_info_network_transmit_bytes_per_second",
+		MetricType::Gauge,
+		"Transmit throughput over the last interval.",
+		networks.iter().map(|network| (interface(network), network.upload_bytes_per_second)),
+	);
+}
+
+#[cfg(feature = "process")]
+fn encode_process(encoder: &mut Encoder, processes: &[ProcessInfo]) {
+	let tree = ProcessTree::from_processes(processes.to_vec());
+	let flat = tree.flatten();
+	let process_labels = |process: &ProcessInfo| {
+		labels([("pid", process.pid.to_string()), ("name", process.name.clone())])
+	};
+	let per_process = |value: fn(&ProcessInfo) -> Option<f64>| {
+		flat.iter().filter_map(move |process| Some((process_labels(process), value(process)?)))
+	};
+
+	encoder.family(
+		"system_info_process_cpu_usage_percent",
+		MetricType::Gauge,
+		"CPU usage of each process, 100 per fully used logical CPU.",
+		per_process(|process| Some(float(process.cpu_usage.unwrap_or(0.0)))),
+	);
+	encoder.family(
+		"system_info_process_memory_bytes",
+		MetricType::Gauge,
+		"Resident memory of each process.",
+		per_process(|process| Some(process.used_memory.as_bytes() as f64)),
+	);
+	encoder.family(
+		"system_info_process_memory_usage_percent",
+		MetricType::Gauge,
+		"Resident memory of each process relative to total memory.",
+		per_process(|process| process.memory_usage.map(float)),
+	);
+	encoder.family(
+		"system_info_process_start_time_seconds",
+		MetricType::Gauge,
+		"Unix time at which each process started.",
+		per_process(|process| Some(process.start_time as f64)),
+	);
+
+	#[cfg(feature = "gpu")]
+	{
+		let per_gpu = |value: fn(&crate::ProcessGpuUsage) -> Option<f64>| {
+			flat.iter().flat_map(move |process| {
+				process.gpus.iter().flatten().filter_map(move |gpu| {
+					let mut labels = process_labels(process);
+					labels.push(("bus_id", gpu.pci_bus_id.clone().unwrap_or_default()));
+					Some((labels, value(gpu)?))
+				})
+			})
+		};
+		encoder.family(
+			"system_info_process_gpu_memory_bytes",
+			MetricType::Gauge,
+			"GPU memory used by each process on each GPU.",
+			per_gpu(|gpu| Some(gpu.memory?.as_bytes() as f64)),
+		);
+		encoder.family(
+			"system_info_process_gpu_usage_percent",
+			MetricType::Gauge,
+			"GPU utilization of each process on each GPU.",
+			per_gpu(|gpu| gpu.usage.map(float)),
+		);
+	}
+}
+
+#[cfg(feature = "gpu")]
+fn encode_gpu(encoder: &mut Encoder, gpus: &[crate::GpuInfo]) {
+	use crate::ByteSize;
+
+	let gpu_labels = |gpu: &crate::GpuInfo| {
+		labels([
+			("gpu", gpu.index.to_string()),
+			("model", gpu.model.clone()),
+			("bus_id", gpu.pci_bus_id.clone().unwrap_or_default()),
+		])
+	};
+	let bytes = |size: fn(&crate::GpuInfo) -> Option<ByteSize>| {
+		gpus.iter().filter_map(move |gpu| Some((gpu_labels(gpu), size(gpu)?.as_bytes() as f64)))
+	};
+
+	encoder.family(
+		"system_info_gpu_memory_total_bytes",
+		MetricType::Gauge,
+		"Total GPU memory.",
+		bytes(|gpu| gpu.memory_total),
+	);
+	encoder.family(
+		"system_info_gpu_memory_used_bytes",
+		MetricType::Gauge,
+		"Used GPU memory.",
+		bytes(|gpu| gpu.memory_used),
+	);
+	encoder.family(
+		"system_info_gpu_memory_free_bytes",
+		MetricType::Gauge,
+		"Free GPU memory.",
+		bytes(|gpu| gpu.memory_free),
+	);
+	encoder.family(
+		"system_info_gpu_usage_percent",
+		MetricType::Gauge,
+		"GPU utilization.",
+		gpus.iter().filter_map(|gpu| Some((gpu_labels(gpu), gpu.usage? as f64))),
+	);
+	encoder.family(
+		"system_info_gpu_temperature_celsius",
+		MetricType::Gauge,
+		"GPU temperature.",
+		gpus.iter().filter_map(|gpu| Some((gpu_labels(gpu), gpu.temperature? as f64))),
+	);
+
+	let percent = |value: fn(&crate::GpuInfo) -> Option<u8>| {
+		gpus.iter().filter_map(move |gpu| Some((gpu_labels(gpu), value(gpu)? as f64)))
+	};
+	encoder.family(
+		"system_info_gpu_memory_controller_usage_percent",
+		MetricType::Gauge,
+		"GPU memory controller utilization.",
+		percent(|gpu| gpu.memory_controller_usage),
+	);
+	encoder.family(
+		"system_info_gpu_encoder_usage_percent",
+		MetricType::Gauge,
+		"GPU video encoder utilization.",
+		percent(|gpu| gpu.encoder_usage),
+	);
+	encoder.family(
+		"system_info_gpu_decoder_usage_percent",
+		MetricType::Gauge,
+		"GPU video decoder utilization.",
+		percent(|gpu| gpu.decoder_usage),
+	);
+	encoder.family(
+		"system_info_gpu_fan_speed_percent",
+		MetricType::Gauge,
+		"GPU fan speed relative to its maximum.",
+		percent(|gpu| gpu.fan_speed),
+	);
+	encoder.family(
+		"system_info_gpu_power_usage_watts",
+		MetricType::Gauge,
+		"GPU power draw.",
+		gpus.iter().filter_map(|gpu| Some((gpu_labels(gpu), float(gpu.power_usage?)))),
+	);
+	encoder.family(
+		"system_info_gpu_power_limit_watts",
+		MetricType::Gauge,
+		"Enforced GPU power limit.",
+		gpus.iter().filter_map(|gpu| Some((gpu_labels(gpu), float(gpu.power_limit?)))),
+	);
+	encoder.family(
+		"system_info_gpu_clock_hertz",
+		MetricType::Gauge,
+		"Current GPU clock frequency by clock domain.",
+		gpus.iter().flat_map(|gpu| {
+			[("graphics", gpu.graphics_clock), ("memory", gpu.memory_clock)].into_iter().filter_map(
+				move |(clock, mhz)| {
+					let mut labels = gpu_labels(gpu);
+					labels.push(("clock", clock.to_string()));
+					Some((labels, mhz? as f64 * 1_000_000.0))
+				},
+			)
+		}),
+	);
+	encoder.family(
+		"system_info_gpu_pcie_bytes_per_second",
+		MetricType::Gauge,
+		"PCIe throughput of the GPU by direction.",
+		gpus.iter().flat_map(|gpu| {
+			[("transmit", gpu.pcie_tx), ("receive", gpu.pcie_rx)].into_iter().filter_map(
+				move |(direction, kilobytes)| {
+					let mut labels = gpu_labels(gpu);
+					labels.push(("direction", direction.to_string()));
+					Some((labels, kilobytes? as f64 * 1024.0))
+				},
+			)
+		}),
+	);
+	encoder.family(
+		"system_info_gpu_process_memory_bytes",
+		MetricType::Gauge,
+		"GPU memory used by each process.",
+		gpus.iter().flat_map(|gpu| {
+			gpu.processes.iter().flatten().filter_map(move |process| {
+				let mut labels = gpu_labels(gpu);
+				labels.push(("pid", process.pid.to_string()));
+				Some((labels, process.used_memory?.as_bytes() as f64))
+			})
+		}),
+	);
+	encoder.family(
+		"system_info_gpu_driver_info",
+		MetricType::Gauge,
+		"GPU driver and CUDA versions, the value is always 1.",
+		gpus.iter().filter_map(|gpu| {
+			let mut labels = gpu_labels(gpu);
+			labels.push(("driver_version", gpu.driver_version.clone()?));
+			labels.push(("cuda_version", gpu.cuda_version.clone().unwrap_or_default()));
+			Some((labels, 1.0))
+		}),
+	);
+}
+
+#[cfg(feature = "load")]
+fn encode_load(encoder: &mut Encoder, load: &crate::LoadInfo) {
+	encoder.family(
+		"system_info_load_average",
+		MetricType::Gauge,
+		"System load average.",
+		[("1m", load.one), ("5m", load.five), ("15m", load.fifteen)]
+			.map(|(window, value)| (labels([("window", window.to_string())]), value)),
+	);
+	encoder.family(
+		"system_info_tasks_running",
+		MetricType::Gauge,
+		"Runnable tasks.",
+		load.running_tasks.map(|tasks| (labels([]), tasks as f64)),
+	);
+	encoder.family(
+		"system_info_tasks",
+		MetricType::Gauge,
+		"Existing tasks.",
+		load.total_tasks.map(|tasks| (labels([]), tasks as f64)),
+	);
+
+	let pressure = load.pressure.as_ref().map_or(Vec::new(), |pressure| {
+		[("cpu", pressure.cpu), ("memory", pressure.memory), ("io", pressure.io)]
+			.into_iter()
+			.filter_map(|(resource, pressure)| Some((resource, pressure?)))
+			.flat_map(|(resource, pressure)| {
+				[("some", Some(pressure.some)), ("full", pressure.full)]
+					.into_iter()
+					.filter_map(move |(kind, stat)| Some((resource, kind, stat?)))
+			})
+			.collect()
+	});
+	let pressure_labels = |resource: &str, kind: &str| {
+		labels([("resource", resource.to_string()), ("kind", kind.to_string())])
+	};
+	for window in [10, 60, 300] {
+		encoder.family(
+			&format!("system_info_pressure_avg{window}_percent"),
+			MetricType::Gauge,
+			&format!("Share of the last {window} seconds tasks were stalled on a resource."),
+			pressure.iter().map(|(resource, kind, stat)| {
+				let average = match window {
+					10 => stat.avg10,
+					60 => stat.avg60,
+					_ => stat.avg300,
+				};
+				(pressure_labels(resource, kind), float(average))
+			}),
+		);
+	}
+	encoder.family(
+		"system_info_pressure_stall_seconds_total",
+		MetricType::Counter,
+		"Total time tasks were stalled on a resource.",
+		pressure.iter().map(|(resource, kind, stat)| {
+			(pressure_labels(resource, kind), stat.total as f64 / 1_000_000.0)
+		}),
+	);
+}
+
+type Labels = Vec<(&'static str, String)>;
+
+fn labels<const N: usize>(labels: [(&'static str, String); N]) -> Labels {
+	labels.into()
+}
+
+#[derive(Debug, Clone, Copy)]
+enum MetricType {
+	Gauge,
+	#[cfg(any(feature = "network", feature = "load"))]
+	Counter,
+}
+
+impl MetricType {
+	fn as_str(self) -> &'static str {
+		match self {
+			Self::Gauge => "gauge",
+			#[cfg(any(feature = "network", feature = "load"))]
+			Self::Counter => "counter",
+		}
+	}
+}
+
+#[derive(Debug, Default)]
+struct Encoder {
+	out: String,
+}
+
+impl Encoder {
+	/// 写入一个指标族，没有样本时不输出
+	fn family(
+		&mut self,
+		name: &str,
+		metric_type: MetricType,
+		help: &str,
+		samples: impl IntoIterator<Item = (Labels, f64)>,
+	) {
+		let mut samples = samples.into_iter().peekable();
+		if samples.peek().is_none() {
+			return;
+		}
+		let _ = writeln!(self.out, "# HELP {name} {}", escape(help, false));
+		let _ = writeln!(self.out, "# TYPE {name} {}", metric_type.as_str());
+		// 同一指标族中标签完全相同的样本只保留第一个，如重复挂载的分区
+		let mut seen = HashSet::new();
+		for (labels, value) in samples {
+			let mut series = String::new();
+			if !labels.is_empty() {
+				series.push('{');
+				for (index, (label, value)) in labels.iter().enumerate() {
+					if index > 0 {
+						series.push(',');
+					}
+					let _ = write!(series, "{label}=\"{}\"", escape(value, true));
+				}
+				series.push('}');
+			}
+			if seen.insert(series.clone()) {
+				let _ = writeln!(self.out, "{name}{series} {}", format_value(value));
+			}
+		}
+	}
+}
+
+/// 转义 HELP 文本或标签值中的反斜杠、换行符，标签值中还需转义双引号
+fn escape(text: &str, quote: bool) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'\\' => escaped.push_str("\\\\"),
+			'\n' => escaped.push_str("\\n"),
+			'"' if quote => escaped.push_str("\\\""),
+			c => escaped.push(c),
+		}
+	}
+	escaped
+}
+
+fn format_value(value: f64) -> String {
+	if value.is_nan() {
+		"NaN".to_string()
+	} else if value.is_infinite() {
+		if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+	} else {
+		value.to_string()
+	}
+}
+
+/// 按 f32 的最短十进制表示转换，避免 `12.3f32 as f64` 输出为 12.300000190734863
+#[cfg(any(
+	feature = "cpu",
+	feature = "memory",
+	feature = "disk",
+	feature = "process",
+	feature = "gpu",
+	feature = "load"
+))]
+fn float(value: f32) -> f64 {
+	value.to_string().parse().unwrap_or(f64::NAN)
+}
@@ -1,30 +1,128 @@
-#[cfg(any(target_os = "windows", target_os = "linux"))]
-use crate::round;
+use crate::{ByteSize, SystemInfoError};
+
+mod nvml;
+mod sysfs;
+
+pub(crate) use nvml::NvmlHandle;
+pub use nvml::{GpuProcess, NvmlMemory, NvmlPciInfo, NvmlSource};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GpuInfo {
+	/// GPU序号，即在 [GpuInfo::all] 中的位置
+	pub index: u32,
 	/// GPU型号
 	pub model: String,
-	/// GPU总内存(单位: MB)
-	pub memory_total: Option<f32>,
-	/// GPU已用内存(单位: MB)
-	pub memory_used: Option<f32>,
-	/// GPU可用内存(单位: MB)
-	pub memory_free: Option<f32>,
+	/// 厂商名称，如 `NVIDIA`、`AMD`、`Intel`
+	pub vendor: Option<String>,
+	/// PCI厂商ID
+	pub vendor_id: Option<u16>,
+	/// PCI设备ID
+	pub device_id: Option<u16>,
+	/// 内核驱动，如 `amdgpu`、`i915`、`nvidia`
+	pub driver: Option<String>,
+	/// PCI总线地址，格式为 `domain:bus:device.function`，如 `0000:01:00.0`
+	pub pci_bus_id: Option<String>,
+	/// GPU总内存
+	pub memory_total: Option<ByteSize>,
+	/// GPU已用内存
+	pub memory_used: Option<ByteSize>,
+	/// GPU可用内存
+	pub memory_free: Option<ByteSize>,
 	/// GPU使用率
 	pub usage: Option<u8>,
+	/// GPU温度(单位: 摄氏度)
+	pub temperature: Option<f32>,
+	/// 显存控制器使用率，仅 NVML
+	pub memory_controller_usage: Option<u8>,
+	/// 视频编码器使用率，仅 NVML
+	pub encoder_usage: Option<u8>,
+	/// 视频解码器使用率，仅 NVML
+	pub decoder_usage: Option<u8>,
+	/// 风扇转速(%)，仅 NVML
+	pub fan_speed: Option<u8>,
+	/// 功耗(单位: 瓦)，仅 NVML
+	pub power_usage: Option<f32>,
+	/// 功耗上限(单位: 瓦)，仅 NVML
+	pub power_limit: Option<f32>,
+	/// 图形核心频率(单位: MHz)，仅 NVML
+	pub graphics_clock: Option<u32>,
+	/// 显存频率(单位: MHz)，仅 NVML
+	pub memory_clock: Option<u32>,
+	/// PCIe 发送速率(单位: KB/s)，仅 NVML
+	pub pcie_tx: Option<u32>,
+	/// PCIe 接收速率(单位: KB/s)，仅 NVML
+	pub pcie_rx: Option<u32>,
+	/// 驱动版本，仅 NVML
+	pub driver_version: Option<String>,
+	/// 驱动支持的 CUDA 版本，如 `12.2`，仅 NVML
+	pub cuda_version: Option<String>,
+	/// 使用该GPU的进程，仅 NVML
+	pub processes: Option<Vec<GpuProcess>>,
 }
 
 impl GpuInfo {
+	/// 获取第一个GPU的信息，无法检测到GPU时返回 `None`
 	pub fn new() -> Option<Self> {
+		Self::try_new().ok()
+	}
+
+	/// 获取第一个GPU的信息，无法检测到GPU时返回 [SystemInfoError::GpuNotFound]
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		Self::all().into_iter().next().ok_or(SystemInfoError::GpuNotFound)
+	}
+
+	/// 获取所有GPU的信息，无法检测到GPU时返回空列表
+	///
+	/// Windows / Linux 下 NVIDIA 显卡通过 NVML 获取显存、使用率、功耗、频率与进程等信息，
+	/// 其余显卡只包含型号等基本信息
+	pub fn all() -> Vec<Self> {
+		Self::all_with(&NvmlHandle::default())
+	}
+
+	/// 使用已初始化的 NVML 句柄获取所有GPU的信息
+	pub(crate) fn all_with(nvml: &NvmlHandle) -> Vec<Self> {
+		#[cfg_attr(not(feature = "pci-ids"), allow(unused_mut))]
+		let mut gpus = Self::detect(nvml);
+		#[cfg(feature = "pci-ids")]
+		if let Some(ids) = crate::PciIds::system() {
+			gpus.iter_mut().for_each(|gpu| gpu.resolve_names(ids));
+		}
+		with_indices(gpus)
+	}
+
+	/// 使用PCI ID数据库补全厂商名称，型号只有厂商与设备ID时替换为数据库中的设备名称
+	#[cfg(feature = "pci-ids")]
+	pub fn resolve_names(&mut self, ids: &crate::PciIds) {
+		let Some(vendor_id) = self.vendor_id else {
+			return;
+		};
+		if self.vendor.is_none() {
+			self.vendor = ids.vendor(vendor_id).map(str::to_string);
+		}
+		let Some(device_id) = self.device_id else {
+			return;
+		};
+		if (self.model == UNKNOWN_MODEL || self.model == generic_model(vendor_id, device_id))
+			&& let Some(device) = ids.device(vendor_id, device_id)
+		{
+			self.model = match &self.vendor {
+				Some(vendor) => format!("{vendor} {device}"),
+				None => device.to_string(),
+			};
+		}
+	}
+
+	#[cfg_attr(not(any(target_os = "windows", target_os = "linux")), allow(unused_variables))]
+	fn detect(nvml: &NvmlHandle) -> Vec<Self> {
 		#[cfg(target_os = "windows")]
 		{
-			Self::from_windows()
+			Self::from_windows(nvml)
 		}
 
 		#[cfg(target_os = "linux")]
 		{
-			Self::from_linux()
+			Self::from_linux(nvml)
 		}
 
 		#[cfg(target_os = "macos")]
@@ -34,39 +132,100 @@ impl GpuInfo {
 
 		#[cfg(not(any(target_os = "windows", target_os = "linux", target_os = "macos")))]
 		{
-			None
+			Vec::new()
 		}
 	}
 
-	#[cfg(target_os = "windows")]
-	fn from_windows() -> Option<Self> {
-		Self::from_nvml()
-			.or_else(Self::from_wmi)
-			.or_else(Self::from_dxgi)
+	/// 只包含型号的GPU信息，序号由 [GpuInfo::all] 统一分配
+	fn basic(model: String, vendor_id: Option<u16>) -> Self {
+		Self {
+			index: 0,
+			model,
+			vendor: vendor_id.and_then(vendor_name).map(str::to_string),
+			vendor_id,
+			device_id: None,
+			driver: None,
+			pci_bus_id: None,
+			memory_total: None,
+			memory_used: None,
+			memory_free: None,
+			usage: None,
+			temperature: None,
+			memory_controller_usage: None,
+			encoder_usage: None,
+			decoder_usage: None,
+			fan_speed: None,
+			power_usage: None,
+			power_limit: None,
+			graphics_clock: None,
+			memory_clock: None,
+			pcie_tx: None,
+			pcie_rx: None,
+			driver_version: None,
+			cuda_version: None,
+			processes: None,
+		}
 	}
 
-	#[cfg(target_os = "windows")]
-	fn from_nvml() -> Option<Self> {
-		use nvml_wrapper::Nvml;
-
-		let nvml = Nvml::init().ok()?;
-		let device = nvml.device_by_index(0).ok()?;
+	/// 以当前信息为准，缺失的字段使用 `other` 补全
+	#[cfg(target_os = "linux")]
+	fn merge(self, other: Self) -> Self {
+		Self {
+			index: self.index,
+			model: self.model,
+			vendor: self.vendor.or(other.vendor),
+			vendor_id: self.vendor_id.or(other.vendor_id),
+			device_id: self.device_id.or(other.device_id),
+			driver: self.driver.or(other.driver),
+			pci_bus_id: self.pci_bus_id.or(other.pci_bus_id),
+			memory_total: self.memory_total.or(other.memory_total),
+			memory_used: self.memory_used.or(other.memory_used),
+			memory_free: self.memory_free.or(other.memory_free),
+			usage: self.usage.or(other.usage),
+			temperature: self.temperature.or(other.temperature),
+			memory_controller_usage: self.memory_controller_usage.or(other.memory_controller_usage),
+			encoder_usage: self.encoder_usage.or(other.encoder_usage),
+			decoder_usage: self.decoder_usage.or(other.decoder_usage),
+			fan_speed: self.fan_speed.or(other.fan_speed),
+			power_usage: self.power_usage.or(other.power_usage),
+			power_limit: self.power_limit.or(other.power_limit),
+			graphics_clock: self.graphics_clock.or(other.graphics_clock),
+			memory_clock: self.memory_clock.or(other.memory_clock),
+			pcie_tx: self.pcie_tx.or(other.pcie_tx),
+			pcie_rx: self.pcie_rx.or(other.pcie_rx),
+			driver_version: self.driver_version.or(other.driver_version),
+			cuda_version: self.cuda_version.or(other.cuda_version),
+			processes: self.processes.or(other.processes),
+		}
+	}
 
-		let model = device.name().ok()?;
-		let memory_info = device.memory_info().ok()?;
-		let utilization = device.utilization_rates().ok();
+	#[cfg(target_os = "windows")]
+	fn from_windows(nvml: &NvmlHandle) -> Vec<Self> {
+		let mut gpus = Self::detect_nvml(nvml);
+		let mut adapters = Self::from_wmi();
+		if adapters.is_empty() {
+			adapters = Self::from_dxgi();
+		}
+		// NVIDIA 显卡已由 NVML 提供更完整的信息
+		let has_nvml = !gpus.is_empty();
+		gpus.extend(
+			adapters
+				.into_iter()
+				.filter(|gpu| !has_nvml || gpu.vendor.as_deref() != vendor_name(NVIDIA)),
+		);
+		gpus
+	}
 
-		Some(Self {
-			model,
-			memory_total: Some(round(memory_info.total as f64 / 1024.0 / 1024.0) as f32),
-			memory_used: Some(round(memory_info.used as f64 / 1024.0 / 1024.0) as f32),
-			memory_free: Some(round(memory_info.free as f64 / 1024.0 / 1024.0) as f32),
-			usage: utilization.map(|u| u.gpu as u8),
-		})
+	#[cfg(any(target_os = "windows", target_os = "linux"))]
+	pub(crate) fn detect_nvml(nvml: &NvmlHandle) -> Vec<Self> {
+		match nvml.get() {
+			Some(nvml) => Self::from_nvml(nvml),
+			None => Vec::new(),
+		}
 	}
 
 	#[cfg(target_os = "windows")]
-	fn from_wmi() -> Option<Self> {
+	fn from_wmi() -> Vec<Self> {
 		use serde::Deserialize;
 		use wmi::WMIConnection;
 
@@ -75,126 +234,98 @@ impl GpuInfo {
 		struct Win32VideoController {
 			name: Option<String>,
 			adapter_ram: Option<u64>,
+			#[serde(rename = "PNPDeviceID")]
+			pnp_device_id: Option<String>,
 		}
 
-		let wmi = WMIConnection::new().ok()?;
-
-		let results: Vec<Win32VideoController> = wmi.query().ok()?;
-		let gpu = results.into_iter().next()?;
-
-		let model = gpu.name.unwrap_or_else(|| "Unknown".to_string());
-		let memory_total = gpu
-			.adapter_ram
-			.map(|r| round(r as f64 / 1024.0 / 1024.0) as f32);
-
-		Some(Self {
-			model,
-			memory_total,
-			memory_used: None,
-			memory_free: None,
-			usage: None,
-		})
+		let Ok(wmi) = WMIConnection::new() else {
+			return Vec::new();
+		};
+		let results: Vec<Win32VideoController> = wmi.query().unwrap_or_default();
+
+		results
+			.into_iter()
+			.map(|gpu| {
+				let model = gpu.name.unwrap_or_else(|| UNKNOWN_MODEL.to_string());
+				// 形如 `PCI\VEN_10DE&DEV_2204&...`
+				let pnp_id = |key: &str| {
+					let id = gpu.pnp_device_id.as_deref()?;
+					let start = id.find(key)? + key.len();
+					u16::from_str_radix(id.get(start..start + 4)?, 16).ok()
+				};
+				let vendor_id = pnp_id("VEN_");
+
+				Self {
+					device_id: pnp_id("DEV_"),
+					memory_total: gpu.adapter_ram.map(ByteSize::from_bytes),
+					..Self::basic(model, vendor_id)
+				}
+			})
+			.collect()
 	}
 
 	#[cfg(target_os = "windows")]
-	fn from_dxgi() -> Option<Self> {
-		use windows::Win32::Graphics::Dxgi::{CreateDXGIFactory1, IDXGIAdapter1, IDXGIFactory1};
+	fn from_dxgi() -> Vec<Self> {
+		use windows::Win32::Graphics::Dxgi::{
+			CreateDXGIFactory1, DXGI_ADAPTER_FLAG_SOFTWARE, IDXGIAdapter1, IDXGIFactory1,
+		};
 
+		let mut gpus = Vec::new();
 		unsafe {
-			let factory: IDXGIFactory1 = CreateDXGIFactory1().ok()?;
-			let adapter: IDXGIAdapter1 = factory.EnumAdapters1(0).ok()?;
-			let desc = adapter.GetDesc1().ok()?;
-
-			let model = String::from_utf16_lossy(
-				&desc.Description[..desc
-					.Description
-					.iter()
-					.position(|&c| c == 0)
-					.unwrap_or(desc.Description.len())],
-			);
-
-			let memory_total = Some(round(desc.DedicatedVideoMemory as f64 / 1024.0 / 1024.0) as f32);
-
-			Some(Self {
-				model,
-				memory_total,
-				memory_used: None,
-				memory_free: None,
-				usage: None,
-			})
-		}
-	}
-
-	#[cfg(target_os = "linux")]
-	fn from_nvml() -> Option<Self> {
-		use nvml_wrapper::Nvml;
-
-		let nvml = Nvml::init().ok()?;
-		let device = nvml.device_by_index(0).ok()?;
-
-		let model = device.name().ok()?;
-		let memory_info = device.memory_info().ok()?;
-		let utilization = device.utilization_rates().ok();
-
-		Some(Self {
-			model,
-			memory_total: Some(round(memory_info.total as f64 / 1024.0 / 1024.0) as f32),
-			memory_used: Some(round(memory_info.used as f64 / 1024.0 / 1024.0) as f32),
-			memory_free: Some(round(memory_info.free as f64 / 1024.0 / 1024.0) as f32),
-			usage: utilization.map(|u| u.gpu as u8),
-		})
-	}
+			let Ok(factory) = CreateDXGIFactory1::<IDXGIFactory1>() else {
+				return gpus;
+			};
+
+			let mut index = 0;
+			while let Ok(adapter) = factory.EnumAdapters1(index) {
+				index += 1;
+				let adapter: IDXGIAdapter1 = adapter;
+				let Ok(desc) = adapter.GetDesc1() else {
+					continue;
+				};
+				// 跳过 Microsoft Basic Render Driver 等软件适配器
+				if desc.Flags & DXGI_ADAPTER_FLAG_SOFTWARE.0 as u32 != 0 {
+					continue;
+				}
 
-	#[cfg(target_os = "linux")]
-	fn from_linux() -> Option<Self> {
-		Self::from_nvml().or_else(Self::from_lspci)
+				let model = String::from_utf16_lossy(
+					&desc.Description[..desc
+						.Description
+						.iter()
+						.position(|&c| c == 0)
+						.unwrap_or(desc.Description.len())],
+				);
+
+				gpus.push(Self {
+					memory_total: Some(ByteSize::from_bytes(desc.DedicatedVideoMemory as u64)),
+					device_id: u16::try_from(desc.DeviceId).ok(),
+					..Self::basic(model, u16::try_from(desc.VendorId).ok())
+				});
+			}
+		}
+		gpus
 	}
 
 	#[cfg(target_os = "linux")]
-	fn from_lspci() -> Option<Self> {
-		use std::process::Command;
-
-		let output = Command::new("lspci")
-			.args(["-v"])
-			.output()
-			.ok()?;
-
-		if !output.status.success() {
-			return None;
-		}
-
-		let stdout = String::from_utf8_lossy(&output.stdout);
-
-		for section in stdout.split("\n\n") {
-			let lower = section.to_lowercase();
-			if lower.contains("vga") || lower.contains("3d controller") {
-				let first_line = section.lines().next()?;
-				let model = first_line.split(':').nth(2).unwrap_or("Unknown GPU").trim();
-
-				let memory_total = section
-					.lines()
-					.find(|l| l.to_lowercase().contains("memory") && l.contains("M"))
-					.and_then(|l| {
-						l.split_whitespace()
-							.find(|s| s.ends_with('M') || s.ends_with("MB"))
-							.and_then(|s| s.trim_end_matches(|c| !char::is_ascii_digit(&c)).parse::<f32>().ok())
-					});
-
-				return Some(Self {
-					model: model.to_string(),
-					memory_total,
-					memory_used: None,
-					memory_free: None,
-					usage: None,
-				});
+	fn from_linux(nvml: &NvmlHandle) -> Vec<Self> {
+		let mut gpus = Self::from_sysfs("/sys");
+		// 同一块显卡以 NVML 的信息为准，无法读取 sysfs 时(如部分容器)直接使用 NVML 的结果
+		for device in Self::detect_nvml(nvml) {
+			match gpus
+				.iter_mut()
+				.find(|gpu| gpu.pci_bus_id.is_some() && gpu.pci_bus_id == device.pci_bus_id)
+			{
+				Some(gpu) => *gpu = device.merge(gpu.clone()),
+				None => gpus.push(device),
 			}
 		}
-		None
+		gpus
 	}
 
 	#[cfg(target_os = "macos")]
-	fn from_iokit() -> Option<Self> {
+	fn from_iokit() -> Vec<Self> {
 		use core_foundation::base::{CFType, TCFType};
+		use core_foundation::data::CFData;
 		use core_foundation::dictionary::{CFDictionary, CFMutableDictionaryRef};
 		use core_foundation::number::CFNumber;
 		use core_foundation::string::CFString;
@@ -202,22 +333,20 @@ impl GpuInfo {
 		use io_kit_sys::types::*;
 		use mach2::kern_return::KERN_SUCCESS;
 
+		let mut gpus = Vec::new();
 		unsafe {
 			let matching = IOServiceMatching(b"IOPCIDevice\0".as_ptr() as *const i8);
 			if matching.is_null() {
-				return None;
+				return gpus;
 			}
 
 			let mut iterator: io_iterator_t = 0;
 			let result = IOServiceGetMatchingServices(kIOMasterPortDefault, matching, &mut iterator);
 
 			if result != KERN_SUCCESS as i32 {
-				return None;
+				return gpus;
 			}
 
-			let mut gpu_model = String::new();
-			let mut vram: f32 = 0.0;
-
 			loop {
 				let service = IOIteratorNext(iterator);
 				if service == 0 {
@@ -230,35 +359,42 @@ impl GpuInfo {
 
 				if result == KERN_SUCCESS as i32 && !properties.is_null() {
 					let dict =
-						CFDictionary::<CFString, CFType>::wrap_under_get_rule(properties as _);
-
-					if let Some(class_code) = dict.find(CFString::new("class-code")) {
-						if let Some(num) = class_code.downcast::<CFNumber>() {
-							let code: i32 = num.to_i32().unwrap_or(0);
-							if (code & 0xFF0000) == 0x030000 {
-								if let Some(model) = dict.find(CFString::new("model")) {
-									if let Some(data) =
-										model.downcast::<core_foundation::data::CFData>()
-									{
-										let bytes = data.bytes();
-										if let Ok(s) = std::str::from_utf8(bytes) {
-											gpu_model = s.trim_end_matches('\0').to_string();
-										}
-									}
-								}
-
-								if let Some(vram_size) = dict.find(CFString::new("VRAM,totalMB")) {
-									if let Some(num) = vram_size.downcast::<CFNumber>() {
-										vram = num.to_i64().unwrap_or(0) as f32;
-									}
-								}
-
-								if !gpu_model.is_empty() {
-									IOObjectRelease(service);
-									break;
-								}
-							}
-						}
+						CFDictionary::<CFString, CFType>::wrap_under_create_rule(properties as _);
+
+					let is_display = dict
+						.find(CFString::new("class-code"))
+						.and_then(|class_code| class_code.downcast::<CFNumber>())
+						.and_then(|num| num.to_i32())
+						.is_some_and(|code| (code & 0xFF0000) == 0x030000);
+
+					let model = dict
+						.find(CFString::new("model"))
+						.and_then(|model| model.downcast::<CFData>())
+						.and_then(|data| {
+							std::str::from_utf8(data.bytes())
+								.ok()
+								.map(|s| s.trim_end_matches('\0').to_string())
+						})
+						.filter(|model| !model.is_empty());
+
+					if let (true, Some(model)) = (is_display, model) {
+						// `vendor-id` 为小端序的 4 字节数据，PCI厂商ID为低 16 位
+						let vendor_id = dict
+							.find(CFString::new("vendor-id"))
+							.and_then(|vendor| vendor.downcast::<CFData>())
+							.and_then(|data| data.bytes().get(..2)?.try_into().ok())
+							.map(u16::from_le_bytes);
+						let vram = dict
+							.find(CFString::new("VRAM,totalMB"))
+							.and_then(|vram| vram.downcast::<CFNumber>())
+							.and_then(|num| num.to_i64())
+							.unwrap_or(0)
+							.max(0) as u64;
+
+						gpus.push(Self {
+							memory_total: (vram > 0).then(|| ByteSize::from_mib(vram)),
+							..Self::basic(model, vendor_id)
+						});
 					}
 				}
 
@@ -266,19 +402,78 @@ impl GpuInfo {
 			}
 
 			IOObjectRelease(iterator);
+		}
+		gpus
+	}
+}
 
-			if gpu_model.is_empty() {
-				return None;
-			}
+/// 与旧版本单位字段数值相同的访问器，按 1024 进制换算并保留两位小数
+impl GpuInfo {
+	/// GPU总内存(单位: MB)
+	pub fn memory_total_mb(&self) -> Option<f32> {
+		self.memory_total.map(|size| crate::round(size.as_mib()) as f32)
+	}
 
-			let memory_total = if vram > 0.0 { Some(vram) } else { None };
-			Some(Self {
-				model: gpu_model,
-				memory_total,
-				memory_used: None,
-				memory_free: None,
-				usage: None,
-			})
-		}
+	/// GPU已用内存(单位: MB)
+	pub fn memory_used_mb(&self) -> Option<f32> {
+		self.memory_used.map(|size| crate::round(size.as_mib()) as f32)
+	}
+
+	/// GPU可用内存(单位: MB)
+	pub fn memory_free_mb(&self) -> Option<f32> {
+		self.memory_free.map(|size| crate::round(size.as_mib()) as f32)
+	}
+}
+
+/// 无法获取型号时使用的名称
+#[cfg_attr(not(any(target_os = "windows", feature = "pci-ids")), allow(dead_code))]
+const UNKNOWN_MODEL: &str = "Unknown";
+
+/// 只有厂商与设备ID时使用的型号，如 `Intel Device 3e92`
+fn generic_model(vendor_id: u16, device_id: u16) -> String {
+	let vendor = vendor_name(vendor_id).map_or_else(|| format!("{vendor_id:04x}"), str::to_string);
+	format!("{vendor} Device {device_id:04x}")
+}
+
+/// NVIDIA 的 PCI 厂商ID
+const NVIDIA: u16 = 0x10de;
+
+/// 常见显卡厂商的名称
+fn vendor_name(vendor_id: u16) -> Option<&'static str> {
+	Some(match vendor_id {
+		0x10de => "NVIDIA",
+		0x1002 | 0x1022 => "AMD",
+		0x8086 => "Intel",
+		0x106b => "Apple",
+		0x1414 => "Microsoft",
+		0x15ad => "VMware",
+		0x80ee => "VirtualBox",
+		0x1af4 => "Red Hat",
+		0x1234 => "QEMU",
+		0x1a03 => "ASPEED",
+		0x102b => "Matrox",
+		0x5143 => "Qualcomm",
+		0x13b5 => "ARM",
+		_ => return None,
+	})
+}
+
+/// 统一PCI总线地址的格式，NVML 返回的 `00000000:01:00.0` 转为 `0000:01:00.0`
+fn normalize_bus_id(bus_id: &str) -> String {
+	let bus_id = bus_id.trim_end_matches('\0').to_lowercase();
+	match bus_id.split_once(':') {
+		Some((domain, rest)) => match u32::from_str_radix(domain, 16) {
+			Ok(domain) => format!("{domain:04x}:{rest}"),
+			Err(_) => bus_id,
+		},
+		None => bus_id,
 	}
-}
\ No newline at end of file
+}
+
+/// 按顺序为各GPU分配序号
+fn with_indices(mut gpus: Vec<GpuInfo>) -> Vec<GpuInfo> {
+	for (index, gpu) in gpus.iter_mut().enumerate() {
+		gpu.index = index as u32;
+	}
+	gpus
+}
@@ -0,0 +1,275 @@
+//! 通过 NVML 读取 NVIDIA 显卡的详细信息
+//!
+//! NVML 的访问经由 [NvmlSource] 进行，便于在没有 NVIDIA 显卡的机器上使用模拟实现测试
+
+use super::{GpuInfo, NVIDIA, normalize_bus_id, with_indices};
+use crate::ByteSize;
+
+/// NVML 报告的显存用量(单位: 字节)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NvmlMemory {
+	pub total: u64,
+	pub used: u64,
+	pub free: u64,
+}
+
+/// NVML 报告的 PCI 信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NvmlPciInfo {
+	/// 总线地址，NVML 的格式为 `00000000:01:00.0`
+	pub bus_id: String,
+	/// PCI厂商ID
+	pub vendor_id: u16,
+	/// PCI设备ID
+	pub device_id: u16,
+}
+
+/// 在GPU上运行的进程
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GpuProcess {
+	/// 进程ID
+	pub pid: u32,
+	/// 占用的显存，Windows WDDM 模式下无法获取
+	pub used_memory: Option<ByteSize>,
+	/// 进程的 SM(图形与计算)使用率(%)，取驱动缓存中最近的一次采样
+	pub usage: Option<u8>,
+}
+
+/// 采样器持有的 NVML 句柄，首次读取GPU信息时初始化，之后的读取共用同一个句柄
+///
+/// 初始化失败(如未安装 NVIDIA 驱动)时记为不可用，不会在每次读取时重试
+#[derive(Debug, Default)]
+pub(crate) struct NvmlHandle {
+	#[cfg(any(target_os = "windows", target_os = "linux"))]
+	nvml: std::sync::OnceLock<Option<nvml_wrapper::Nvml>>,
+}
+
+impl NvmlHandle {
+	#[cfg(any(target_os = "windows", target_os = "linux"))]
+	pub(crate) fn get(&self) -> Option<&nvml_wrapper::Nvml> {
+		self.nvml.get_or_init(|| nvml_wrapper::Nvml::init().ok()).as_ref()
+	}
+}
+
+/// NVML 的访问接口，各设备以序号区分
+///
+/// 各项读取失败或设备不支持时返回 `None`，只影响对应的字段，不会使整个探测失败
+pub trait NvmlSource {
+	/// 设备数量
+	fn device_count(&self) -> u32;
+	/// 驱动版本，如 `535.104.05`
+	fn driver_version(&self) -> Option<String>;
+	/// 驱动支持的 CUDA 版本，格式与 NVML 相同，如 `12020` 表示 12.2
+	fn cuda_version(&self) -> Option<i32>;
+	/// 设备名称，无法获取时跳过该设备
+	fn name(&self, index: u32) -> Option<String>;
+	fn pci_info(&self, index: u32) -> Option<NvmlPciInfo>;
+	fn memory(&self, index: u32) -> Option<NvmlMemory>;
+	/// GPU使用率(%)
+	fn gpu_utilization(&self, index: u32) -> Option<u32>;
+	/// 显存控制器使用率(%)
+	fn memory_utilization(&self, index: u32) -> Option<u32>;
+	/// 视频编码器使用率(%)
+	fn encoder_utilization(&self, index: u32) -> Option<u32>;
+	/// 视频解码器使用率(%)
+	fn decoder_utilization(&self, index: u32) -> Option<u32>;
+	/// 温度(单位: 摄氏度)
+	fn temperature(&self, index: u32) -> Option<u32>;
+	/// 风扇转速(%)
+	fn fan_speed(&self, index: u32) -> Option<u32>;
+	/// 功耗(单位: 毫瓦)
+	fn power_usage(&self, index: u32) -> Option<u32>;
+	/// 功耗上限(单位: 毫瓦)
+	fn power_limit(&self, index: u32) -> Option<u32>;
+	/// 图形核心频率(单位: MHz)
+	fn graphics_clock(&self, index: u32) -> Option<u32>;
+	/// 显存频率(单位: MHz)
+	fn memory_clock(&self, index: u32) -> Option<u32>;
+	/// PCIe 发送速率(单位: KB/s)
+	fn pcie_tx(&self, index: u32) -> Option<u32>;
+	/// PCIe 接收速率(单位: KB/s)
+	fn pcie_rx(&self, index: u32) -> Option<u32>;
+	/// 计算与图形进程
+	fn processes(&self, index: u32) -> Option<Vec<GpuProcess>>;
+}
+
+impl GpuInfo {
+	/// 从 NVML 读取所有 NVIDIA 显卡的信息
+	pub fn from_nvml(source: &dyn NvmlSource) -> Vec<Self> {
+		let driver_version = source.driver_version();
+		// 如 12020 -> 12.2
+		let cuda_version = source
+			.cuda_version()
+			.map(|version| format!("{}.{}", version / 1000, version % 1000 / 10));
+
+		let gpus = (0..source.device_count())
+			.filter_map(|index| {
+				let model = source.name(index)?;
+				let pci_info = source.pci_info(index);
+				let memory = source.memory(index);
+				let percent = |value: Option<u32>| value.map(|value| value.min(100) as u8);
+				let watts = |milliwatts: Option<u32>| milliwatts.map(|value| value as f32 / 1000.0);
+
+				Some(Self {
+					device_id: pci_info.as_ref().map(|pci| pci.device_id),
+					driver: Some("nvidia".to_string()),
+					driver_version: driver_version.clone(),
+					cuda_version: cuda_version.clone(),
+					pci_bus_id: pci_info.as_ref().map(|pci| normalize_bus_id(&pci.bus_id)),
+					memory_total: memory.map(|m| ByteSize::from_bytes(m.total)),
+					memory_used: memory.map(|m| ByteSize::from_bytes(m.used)),
+					memory_free: memory.map(|m| ByteSize::from_bytes(m.free)),
+					usage: percent(source.gpu_utilization(index)),
+					memory_controller_usage: percent(source.memory_utilization(index)),
+					encoder_usage: percent(source.encoder_utilization(index)),
+					decoder_usage: percent(source.decoder_utilization(index)),
+					temperature: source.temperature(index).map(|t| t as f32),
+					fan_speed: percent(source.fan_speed(index)),
+					power_usage: watts(source.power_usage(index)),
+					power_limit: watts(source.power_limit(index)),
+					graphics_clock: source.graphics_clock(index),
+					memory_clock: source.memory_clock(index),
+					pcie_tx: source.pcie_tx(index),
+					pcie_rx: source.pcie_rx(index),
+					processes: source.processes(index),
+					..Self::basic(
+						model,
+						Some(pci_info.as_ref().map_or(NVIDIA, |pci| pci.vendor_id)),
+					)
+				})
+			})
+			.collect();
+		with_indices(gpus)
+	}
+}
+
+#[cfg(any(target_os = "windows", target_os = "linux"))]
+impl NvmlSource for nvml_wrapper::Nvml {
+	fn device_count(&self) -> u32 {
+		nvml_wrapper::Nvml::device_count(self).unwrap_or(0)
+	}
+
+	fn driver_version(&self) -> Option<String> {
+		self.sys_driver_version().ok()
+	}
+
+	fn cuda_version(&self) -> Option<i32> {
+		self.sys_cuda_driver_version().ok()
+	}
+
+	fn name(&self, index: u32) -> Option<String> {
+		self.device_by_index(index).ok()?.name().ok()
+	}
+
+	fn pci_info(&self, index: u32) -> Option<NvmlPciInfo> {
+		let pci = self.device_by_index(index).ok()?.pci_info().ok()?;
+		// 低 16 位为厂商ID，高 16 位为设备ID
+		Some(NvmlPciInfo {
+			bus_id: pci.bus_id,
+			vendor_id: pci.pci_device_id as u16,
+			device_id: (pci.pci_device_id >> 16) as u16,
+		})
+	}
+
+	fn memory(&self, index: u32) -> Option<NvmlMemory> {
+		let memory = self.device_by_index(index).ok()?.memory_info().ok()?;
+		Some(NvmlMemory { total: memory.total, used: memory.used, free: memory.free })
+	}
+
+	fn gpu_utilization(&self, index: u32) -> Option<u32> {
+		Some(self.device_by_index(index).ok()?.utilization_rates().ok()?.gpu)
+	}
+
+	fn memory_utilization(&self, index: u32) -> Option<u32> {
+		Some(self.device_by_index(index).ok()?.utilization_rates().ok()?.memory)
+	}
+
+	fn encoder_utilization(&self, index: u32) -> Option<u32> {
+		Some(self.device_by_index(index).ok()?.encoder_utilization().ok()?.utilization)
+	}
+
+	fn decoder_utilization(&self, index: u32) -> Option<u32> {
+		Some(self.device_by_index(index).ok()?.decoder_utilization().ok()?.utilization)
+	}
+
+	fn temperature(&self, index: u32) -> Option<u32> {
+		use nvml_wrapper::enum_wrappers::device::TemperatureSensor;
+
+		self.device_by_index(index).ok()?.temperature(TemperatureSensor::Gpu).ok()
+	}
+
+	fn fan_speed(&self, index: u32) -> Option<u32> {
+		self.device_by_index(index).ok()?.fan_speed(0).ok()
+	}
+
+	fn power_usage(&self, index: u32) -> Option<u32> {
+		self.device_by_index(index).ok()?.power_usage().ok()
+	}
+
+	fn power_limit(&self, index: u32) -> Option<u32> {
+		self.device_by_index(index).ok()?.enforced_power_limit().ok()
+	}
+
+	fn graphics_clock(&self, index: u32) -> Option<u32> {
+		use nvml_wrapper::enum_wrappers::device::Clock;
+
+		self.device_by_index(index).ok()?.clock_info(Clock::Graphics).ok()
+	}
+
+	fn memory_clock(&self, index: u32) -> Option<u32> {
+		use nvml_wrapper::enum_wrappers::device::Clock;
+
+		self.device_by_index(index).ok()?.clock_info(Clock::Memory).ok()
+	}
+
+	fn pcie_tx(&self, index: u32) -> Option<u32> {
+		use nvml_wrapper::enum_wrappers::device::PcieUtilCounter;
+
+		self.device_by_index(index).ok()?.pcie_throughput(PcieUtilCounter::Send).ok()
+	}
+
+	fn pcie_rx(&self, index: u32) -> Option<u32> {
+		use nvml_wrapper::enum_wrappers::device::PcieUtilCounter;
+
+		self.device_by_index(index).ok()?.pcie_throughput(PcieUtilCounter::Receive).ok()
+	}
+
+	fn processes(&self, index: u32) -> Option<Vec<GpuProcess>> {
+		use std::collections::HashMap;
+
+		use nvml_wrapper::enums::device::UsedGpuMemory;
+
+		let device = self.device_by_index(index).ok()?;
+		let compute = device.running_compute_processes().ok();
+		let graphics = device.running_graphics_processes().ok();
+		if compute.is_none() && graphics.is_none() {
+			return None;
+		}
+		// 同一进程可能有多次采样，只保留最近的一次
+		let mut utilization: HashMap<u32, (u64, u32)> = HashMap::new();
+		for sample in device.process_utilization_stats(None).unwrap_or_default() {
+			let entry = utilization.entry(sample.pid).or_insert((sample.timestamp, sample.sm_util));
+			if sample.timestamp > entry.0 {
+				*entry = (sample.timestamp, sample.sm_util);
+			}
+		}
+
+		// 同时使用计算与图形的进程会出现在两个列表中，只保留一次
+		let mut processes: Vec<GpuProcess> = Vec::new();
+		for process in compute.into_iter().chain(graphics).flatten() {
+			if processes.iter().any(|existing| existing.pid == process.pid) {
+				continue;
+			}
+			processes.push(GpuProcess {
+				pid: process.pid,
+				used_memory: match process.used_gpu_memory {
+					UsedGpuMemory::Used(bytes) => Some(ByteSize::from_bytes(bytes)),
+					UsedGpuMemory::Unavailable => None,
+				},
+				usage: utilization.get(&process.pid).map(|(_, usage)| (*usage).min(100) as u8),
+			});
+		}
+		Some(processes)
+	}
+}
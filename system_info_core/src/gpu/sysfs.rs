@@ -0,0 +1,73 @@
+//! 通过 sysfs 中的 DRM 设备探测GPU，无需依赖 `lspci` 等外部命令
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{GpuInfo, generic_model, with_indices};
+use crate::ByteSize;
+use crate::error::read_file;
+use crate::pci::PciAttributes;
+
+impl GpuInfo {
+	/// 从指定的 sysfs 根目录(通常为 `/sys`)读取 `class/drm/card*` 对应的 PCI 显卡
+	///
+	/// 型号由厂商与设备ID组成；amdgpu 额外提供显存与使用率，驱动注册了 hwmon 时读取温度。
+	/// 目录不存在时返回空列表，也便于使用伪造的目录结构进行测试
+	pub fn from_sysfs(root: impl AsRef<Path>) -> Vec<Self> {
+		let Ok(entries) = fs::read_dir(root.as_ref().join("class/drm")) else {
+			return Vec::new();
+		};
+		// 只保留 `card0` 这样的设备，跳过 `card0-DP-1` 等显示接口与 `renderD128`
+		let mut cards: Vec<(u32, PathBuf)> = entries
+			.filter_map(|entry| {
+				let entry = entry.ok()?;
+				let number = entry.file_name().to_str()?.strip_prefix("card")?.parse().ok()?;
+				Some((number, entry.path().join("device")))
+			})
+			.collect();
+		cards.sort_by_key(|(number, _)| *number);
+
+		with_indices(cards.iter().filter_map(|(_, device)| read_card(device)).collect())
+	}
+}
+
+/// 读取 `card*/device` 目录，非 PCI 设备(如 simpledrm)或非显示控制器时返回 None
+fn read_card(device: &Path) -> Option<GpuInfo> {
+	let pci = PciAttributes::read(device)?;
+	if pci.class.is_some_and(|class| class >> 16 != 0x03) {
+		return None;
+	}
+
+	// amdgpu 提供显存用量(单位: 字节)与使用率
+	let memory_total = read_u64(device.join("mem_info_vram_total")).map(ByteSize::from_bytes);
+	let memory_used = read_u64(device.join("mem_info_vram_used")).map(ByteSize::from_bytes);
+
+	Some(GpuInfo {
+		device_id: Some(pci.device_id),
+		driver: pci.driver,
+		pci_bus_id: pci.pci_bus_id,
+		memory_total,
+		memory_used,
+		memory_free: memory_total.zip(memory_used).map(|(total, used)| total.saturating_sub(used)),
+		usage: read_u64(device.join("gpu_busy_percent")).map(|usage| usage.min(100) as u8),
+		temperature: read_temperature(device),
+		..GpuInfo::basic(generic_model(pci.vendor_id, pci.device_id), Some(pci.vendor_id))
+	})
+}
+
+fn read_u64(path: PathBuf) -> Option<u64> {
+	read_file(path).ok()?.trim().parse().ok()
+}
+
+/// 读取第一个 hwmon 的 `temp1_input`(单位: 毫摄氏度)
+fn read_temperature(device: &Path) -> Option<f32> {
+	let mut hwmons: Vec<PathBuf> = fs::read_dir(device.join("hwmon"))
+		.ok()?
+		.filter_map(|entry| Some(entry.ok()?.path()))
+		.collect();
+	hwmons.sort();
+	hwmons
+		.into_iter()
+		.find_map(|hwmon| read_u64(hwmon.join("temp1_input")))
+		.map(|millidegrees| millidegrees as f32 / 1000.0)
+}
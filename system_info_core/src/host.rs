@@ -1,6 +1,10 @@
 use chrono::{DateTime, TimeZone, Utc};
+use sysinfo::System;
+
+use crate::{SystemInfoError, Virtualization};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HostInfo {
 	/// 主机名
 	pub host_name: String,
@@ -18,33 +22,28 @@ pub struct HostInfo {
 	pub boot_time: DateTime<Utc>,
 	/// 系统运行时间， 单位：秒
 	pub uptime: u64,
+	/// 容器或虚拟化类型，仅 Linux 下可检测
+	pub virtualization: Virtualization,
 }
 
 impl Default for HostInfo {
+	/// 获取主机信息，无法获取的字段使用以下回退值：
+	///
+	/// * 主机名、操作系统名、操作系统版本 - `"Unknown"`
+	/// * 系统启动时间 - Unix 纪元(1970-01-01T00:00:00Z)
+	/// * 时区 - `"UTC"`
 	fn default() -> Self {
-		use std::env;
-		use sysinfo::System;
-		let hostname = System::host_name().unwrap();
-		let os_name = System::name().unwrap();
-		let arch = System::cpu_arch();
-		let os_version = System::os_version().unwrap();
-		let os_type = env::consts::OS.to_string();
-		let boot_time_secs = System::boot_time();
-		let boot_time = Utc
-			.timestamp_opt(boot_time_secs as i64, 0)
-			.single()
-			.expect("Invalid boot time timestamp");
-		let tz_str = iana_time_zone::get_timezone().expect("Invalid timezone");
-		let uptime = System::uptime();
+		const UNKNOWN: &str = "Unknown";
 		Self {
-			host_name: hostname,
-			os_name,
-			arch,
-			os_version,
-			os_type,
-			boot_time,
-			uptime,
-			time_zone: tz_str,
+			host_name: host_name().unwrap_or_else(|_| UNKNOWN.to_string()),
+			os_name: os_name().unwrap_or_else(|_| UNKNOWN.to_string()),
+			os_version: os_version().unwrap_or_else(|_| UNKNOWN.to_string()),
+			os_type: std::env::consts::OS.to_string(),
+			arch: System::cpu_arch(),
+			time_zone: time_zone().unwrap_or_else(|_| "UTC".to_string()),
+			boot_time: boot_time().unwrap_or(DateTime::UNIX_EPOCH),
+			uptime: System::uptime(),
+			virtualization: Virtualization::detect(),
 		}
 	}
 }
@@ -53,4 +52,42 @@ impl HostInfo {
 	pub fn new() -> Self {
 		Self::default()
 	}
+
+	/// 获取主机信息，任一探测项失败时返回对应的 [SystemInfoError]
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		Ok(Self {
+			host_name: host_name()?,
+			os_name: os_name()?,
+			os_version: os_version()?,
+			os_type: std::env::consts::OS.to_string(),
+			arch: System::cpu_arch(),
+			time_zone: time_zone()?,
+			boot_time: boot_time()?,
+			uptime: System::uptime(),
+			virtualization: Virtualization::detect(),
+		})
+	}
+}
+
+fn host_name() -> Result<String, SystemInfoError> {
+	System::host_name().ok_or(SystemInfoError::HostName)
+}
+
+fn os_name() -> Result<String, SystemInfoError> {
+	System::name().ok_or(SystemInfoError::OsName)
+}
+
+fn os_version() -> Result<String, SystemInfoError> {
+	System::os_version().ok_or(SystemInfoError::OsVersion)
+}
+
+fn time_zone() -> Result<String, SystemInfoError> {
+	Ok(iana_time_zone::get_timezone()?)
+}
+
+fn boot_time() -> Result<DateTime<Utc>, SystemInfoError> {
+	let boot_time_secs = System::boot_time();
+	Utc.timestamp_opt(boot_time_secs as i64, 0)
+		.single()
+		.ok_or(SystemInfoError::BootTime(boot_time_secs))
 }
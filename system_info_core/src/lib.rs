@@ -1,45 +1,334 @@
+mod error;
+pub use error::SystemInfoError;
+
+mod byte_size;
+pub use byte_size::{ByteSize, ByteSizeDisplay, RateDisplay, UnitSystem, format_duration};
+
+#[cfg(feature = "serde")]
+mod serde_ext;
+
 #[cfg(feature = "host")]
 mod host;
 #[cfg(feature = "host")]
 pub use host::HostInfo;
+#[cfg(feature = "host")]
+mod virtualization;
+#[cfg(feature = "host")]
+pub use virtualization::Virtualization;
 
 #[cfg(feature = "cpu")]
 mod cpu;
 
 #[cfg(feature = "cpu")]
-pub use cpu::CpuInfo;
+pub use cpu::{CoreInfo, CpuInfo, CpuStat, CpuTimePercent, CpuTimes};
 
 #[cfg(feature = "memory")]
 mod memory;
 
-pub use memory::MemoryInfo;
+#[cfg(feature = "memory")]
+pub use memory::{MemInfo, MemoryInfo};
 
 #[cfg(feature = "disk")]
 mod disk;
 
 #[cfg(feature = "disk")]
-pub use disk::{DiskDetail, DiskInfo};
+pub use disk::{DeviceKind, DiskDetail, DiskInfo, DiskIoStats, DiskStat, DiskStats};
 
 #[cfg(feature = "network")]
 mod network;
 #[cfg(feature = "network")]
-pub use network::{IpInfo, NetworkInfo};
+pub use network::{IpInfo, NetworkDevice, NetworkInfo};
 
 #[cfg(feature = "process")]
 mod process;
 
 #[cfg(feature = "process")]
-pub use process::ProcessInfo;
+pub use process::{ProcessInfo, ProcessSort, ProcessTotals, ProcessTree};
+#[cfg(all(feature = "process", feature = "gpu"))]
+pub use process::{DrmClient, ProcessGpuUsage};
 
 #[cfg(feature = "gpu")]
 mod gpu;
 #[cfg(feature = "gpu")]
-pub use gpu::GpuInfo;
+pub use gpu::{GpuInfo, GpuProcess, NvmlMemory, NvmlPciInfo, NvmlSource};
+
+#[cfg(any(feature = "gpu", feature = "network"))]
+mod pci;
+#[cfg(feature = "pci-ids")]
+mod pci_ids;
+#[cfg(feature = "pci-ids")]
+pub use pci_ids::PciIds;
+
+#[cfg(any(feature = "cpu", feature = "memory"))]
+mod container;
+#[cfg(any(feature = "cpu", feature = "memory"))]
+pub use container::{CgroupVersion, ContainerInfo};
+
+#[cfg(feature = "load")]
+mod load;
+#[cfg(feature = "load")]
+pub use load::{LoadInfo, Pressure, PressureInfo, PressureStat};
+
+#[cfg(feature = "prometheus")]
+pub mod exporter;
+
+mod snapshot;
+#[cfg(feature = "memory")]
+pub use snapshot::MemoryDelta;
+#[cfg(feature = "network")]
+pub use snapshot::IpChange;
+pub use snapshot::{
+	PartialSnapshot, SAMPLE_INTERVAL, Snapshot, SnapshotDiff, Subsystem, parse_interval,
+};
+
+#[cfg(any(feature = "disk", feature = "network"))]
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "cpu")]
+use sysinfo::Components;
+#[cfg(feature = "disk")]
+use sysinfo::Disks;
+#[cfg(feature = "network")]
+use sysinfo::Networks;
+#[cfg(any(feature = "cpu", feature = "memory", feature = "process"))]
+use sysinfo::System;
+
+/// 系统信息采样器
+///
+/// 持有 `sysinfo` 的采样对象，通过 `refresh_*` 方法刷新数据，
+/// 各访问方法基于最近两次刷新之间的差值计算使用率与速率，不会在内部等待。
+///
+/// 创建后会立即完成一次刷新，此时使用率与速率尚无可比较的上一次采样，
+/// 需要在间隔一段时间后再次刷新才能得到有效数值。
+///
+/// # 示例
+///
+/// ```no_run
+/// use std::{thread::sleep, time::Duration};
+/// use puniyu_system_info::SystemInfo;
+///
+/// let mut system_info = SystemInfo::new();
+/// loop {
+///     sleep(Duration::from_secs(1));
+///     system_info.refresh_all();
+///     println!("{:?}", system_info.cpu());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct SystemInfo {
+	#[cfg(any(feature = "cpu", feature = "memory", feature = "process"))]
+	system: System,
+	#[cfg(feature = "cpu")]
+	components: Components,
+	#[cfg(feature = "cpu")]
+	cpu_stat: cpu::CpuStatSample,
+	#[cfg(feature = "memory")]
+	meminfo: memory::MemInfoSample,
+	#[cfg(feature = "disk")]
+	disks: Disks,
+	#[cfg(feature = "disk")]
+	disk_sample: Sample,
+	#[cfg(feature = "disk")]
+	disk_stat: disk::DiskStatSample,
+	#[cfg(feature = "disk")]
+	mounts: disk::MountSample,
+	#[cfg(feature = "network")]
+	networks: Networks,
+	#[cfg(feature = "network")]
+	network_sample: Sample,
+	#[cfg(all(feature = "process", feature = "gpu"))]
+	gpu_usage: process::GpuUsageSample,
+	#[cfg(feature = "gpu")]
+	nvml: gpu::NvmlHandle,
+	#[cfg(any(feature = "cpu", feature = "memory"))]
+	container: Option<container::ContainerSample>,
+}
 
-#[derive(Debug, Clone)]
-pub struct SystemInfo;
+/// 记录最近两次刷新的时间，用于把增量换算为速率
+#[cfg(any(feature = "disk", feature = "network"))]
+#[derive(Debug, Clone, Copy, Default)]
+struct Sample {
+	refreshed_at: Option<Instant>,
+	elapsed: Duration,
+}
+
+#[cfg(any(feature = "disk", feature = "network"))]
+impl Sample {
+	fn tick(&mut self) {
+		let now = Instant::now();
+		self.elapsed = self.refreshed_at.map_or(Duration::ZERO, |last| now.duration_since(last));
+		self.refreshed_at = Some(now);
+	}
+}
+
+impl Default for SystemInfo {
+	fn default() -> Self {
+		let mut system_info = Self {
+			#[cfg(any(feature = "cpu", feature = "memory", feature = "process"))]
+			system: System::new(),
+			#[cfg(feature = "cpu")]
+			components: Components::new(),
+			#[cfg(feature = "cpu")]
+			cpu_stat: cpu::CpuStatSample::default(),
+			#[cfg(feature = "memory")]
+			meminfo: memory::MemInfoSample::default(),
+			#[cfg(feature = "disk")]
+			disks: Disks::new(),
+			#[cfg(feature = "disk")]
+			disk_sample: Sample::default(),
+			#[cfg(feature = "disk")]
+			disk_stat: disk::DiskStatSample::default(),
+			#[cfg(feature = "disk")]
+			mounts: disk::MountSample::default(),
+			#[cfg(feature = "network")]
+			networks: Networks::new(),
+			#[cfg(feature = "network")]
+			network_sample: Sample::default(),
+			#[cfg(all(feature = "process", feature = "gpu"))]
+			gpu_usage: process::GpuUsageSample::default(),
+			#[cfg(feature = "gpu")]
+			nvml: gpu::NvmlHandle::default(),
+			#[cfg(any(feature = "cpu", feature = "memory"))]
+			container: None,
+		};
+		system_info.refresh_all();
+		system_info
+	}
+}
 
 impl SystemInfo {
+	/// 创建采样器并完成首次刷新
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 创建采样器，指定模块中包含CPU、磁盘、网络或进程时等待 [SAMPLE_INTERVAL] 后再刷新一次这些模块，
+	/// 使返回的采样器可以直接读取有效的使用率与速率
+	pub fn sampled(subsystems: &[Subsystem]) -> Self {
+		let mut system_info = Self::new();
+		if subsystems.iter().any(Subsystem::is_sampled) {
+			std::thread::sleep(SAMPLE_INTERVAL);
+			system_info.refresh(subsystems);
+		}
+		system_info
+	}
+
+	/// 设置是否按容器(cgroup)的资源限制报告CPU与内存信息，默认关闭
+	///
+	/// 开启后会立即读取一次 cgroup 数据，之后随 `refresh_cpu` 刷新(未启用 `cpu` 特性时随
+	/// `refresh_memory` 刷新)；非 Linux 或未检测到 cgroup 时仍报告主机数据
+	#[cfg(any(feature = "cpu", feature = "memory"))]
+	pub fn set_container_aware(&mut self, enabled: bool) {
+		self.container = enabled.then(|| {
+			let mut sample = container::ContainerSample::default();
+			sample.refresh();
+			sample
+		});
+	}
+
+	/// 刷新所有已启用模块的数据
+	pub fn refresh_all(&mut self) {
+		#[cfg(feature = "cpu")]
+		self.refresh_cpu();
+		#[cfg(feature = "memory")]
+		self.refresh_memory();
+		#[cfg(feature = "disk")]
+		self.refresh_disk();
+		#[cfg(feature = "network")]
+		self.refresh_network();
+		#[cfg(feature = "process")]
+		self.refresh_process();
+	}
+
+	/// 只刷新指定模块的数据，主机、GPU与负载信息在读取时获取，无需刷新
+	///
+	/// 同时指定进程与GPU时还会刷新各进程的GPU占用，参见 [SystemInfo::refresh_process_gpu]
+	pub fn refresh(&mut self, subsystems: &[Subsystem]) {
+		for subsystem in subsystems {
+			match *subsystem {
+				#[cfg(feature = "cpu")]
+				Subsystem::Cpu => self.refresh_cpu(),
+				#[cfg(feature = "memory")]
+				Subsystem::Memory => self.refresh_memory(),
+				#[cfg(feature = "disk")]
+				Subsystem::Disk => self.refresh_disk(),
+				#[cfg(feature = "network")]
+				Subsystem::Network => self.refresh_network(),
+				#[cfg(feature = "process")]
+				Subsystem::Process => self.refresh_process(),
+				#[allow(unreachable_patterns)]
+				_ => {}
+			}
+		}
+		#[cfg(all(feature = "process", feature = "gpu"))]
+		if subsystems.contains(&Subsystem::Process) && subsystems.contains(&Subsystem::Gpu) {
+			self.refresh_process_gpu();
+		}
+	}
+
+	/// 刷新CPU使用率、频率、温度与 `/proc/stat` 时间统计
+	///
+	/// 两次刷新的间隔不应小于 [sysinfo::MINIMUM_CPU_UPDATE_INTERVAL]，否则使用率可能不准确
+	#[cfg(feature = "cpu")]
+	pub fn refresh_cpu(&mut self) {
+		self.system.refresh_cpu_all();
+		self.components.refresh(true);
+		self.cpu_stat.refresh();
+		self.refresh_container();
+	}
+
+	/// 刷新内存、交换内存与 `/proc/meminfo` 统计
+	#[cfg(feature = "memory")]
+	pub fn refresh_memory(&mut self) {
+		self.system.refresh_memory();
+		self.meminfo.refresh();
+		#[cfg(not(feature = "cpu"))]
+		self.refresh_container();
+	}
+
+	#[cfg(any(feature = "cpu", feature = "memory"))]
+	fn refresh_container(&mut self) {
+		if let Some(container) = &mut self.container {
+			container.refresh();
+		}
+	}
+
+	/// 刷新磁盘列表、空间、读写量、`/proc/diskstats` 统计、挂载选项与 inode 统计
+	///
+	/// 网络文件系统不读取 inode 统计，避免在失去响应的挂载上阻塞
+	#[cfg(feature = "disk")]
+	pub fn refresh_disk(&mut self) {
+		self.disks.refresh(true);
+		self.disk_sample.tick();
+		self.disk_stat.refresh();
+		self.mounts.refresh(&self.disks);
+	}
+
+	/// 刷新网卡列表与流量
+	#[cfg(feature = "network")]
+	pub fn refresh_network(&mut self) {
+		self.networks.refresh(true);
+		self.network_sample.tick();
+	}
+
+	/// 刷新进程列表及其CPU、内存占用，不包含GPU占用
+	#[cfg(feature = "process")]
+	pub fn refresh_process(&mut self) {
+		use sysinfo::{MemoryRefreshKind, ProcessesToUpdate};
+		self.system.refresh_processes(ProcessesToUpdate::All, true);
+		self.system.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+	}
+
+	/// 刷新各进程的GPU显存占用与使用率
+	///
+	/// 需要查询 NVML 并读取所有进程的 `/proc/<pid>/fdinfo`，开销较大，因此不随
+	/// [SystemInfo::refresh_process] 与 [SystemInfo::refresh_all] 刷新。
+	/// DRM 客户端的使用率需要两次刷新才能计算
+	#[cfg(all(feature = "process", feature = "gpu"))]
+	pub fn refresh_process_gpu(&mut self) {
+		self.gpu_usage.refresh(&self.nvml);
+	}
+
 	/// 获取主机信息
 	///
 	/// 此函数可以获取主机信息，包括主机名、操作系统名、操作系统版本、操作系统类型、系统启动时间等
@@ -48,20 +337,43 @@ impl SystemInfo {
 	/// * [HostInfo] - 主机信息
 	///
 	#[cfg(feature = "host")]
-	pub fn host() -> HostInfo {
+	pub fn host(&self) -> HostInfo {
 		HostInfo::default()
 	}
 
+	/// 获取主机信息，任一探测项失败时返回对应的 [SystemInfoError]
+	#[cfg(feature = "host")]
+	pub fn try_host(&self) -> Result<HostInfo, SystemInfoError> {
+		HostInfo::try_new()
+	}
+
 	/// 获取CPU信息
 	///
-	/// 此函数可以获取CPU信息，包括型号、核心数、频率、使用率等
+	/// 此函数可以获取CPU信息，包括型号、核心数、频率、使用率等，
+	/// 使用率为最近两次 [SystemInfo::refresh_cpu] 之间的平均值
 	/// # 返回值
 	///
 	/// * [CpuInfo] - CPU信息
 	///
 	#[cfg(feature = "cpu")]
-	pub fn cpu() -> CpuInfo {
-		CpuInfo::default()
+	pub fn cpu(&self) -> CpuInfo {
+		let cpu_info = CpuInfo::from_system(&self.system, &self.components, self.cpu_stat.pair());
+		self.apply_container_cpu(cpu_info)
+	}
+
+	/// 获取CPU信息，未检测到CPU时返回 [SystemInfoError::CpuNotFound]
+	#[cfg(feature = "cpu")]
+	pub fn try_cpu(&self) -> Result<CpuInfo, SystemInfoError> {
+		CpuInfo::try_from_system(&self.system, &self.components, self.cpu_stat.pair())
+			.map(|cpu_info| self.apply_container_cpu(cpu_info))
+	}
+
+	#[cfg(feature = "cpu")]
+	fn apply_container_cpu(&self, cpu_info: CpuInfo) -> CpuInfo {
+		match self.container.as_ref().and_then(|sample| Some((sample.current()?, sample))) {
+			Some((current, sample)) => cpu_info.with_container(current, sample.previous()),
+			None => cpu_info,
+		}
 	}
 
 	/// 获取内存信息
@@ -72,32 +384,65 @@ impl SystemInfo {
 	/// * [MemoryInfo] - 内存信息
 	///
 	#[cfg(feature = "memory")]
-	pub fn memory() -> MemoryInfo {
-		MemoryInfo::default()
+	pub fn memory(&self) -> MemoryInfo {
+		self.apply_container_memory(MemoryInfo::from_system(&self.system, self.meminfo.current()))
+	}
+
+	/// 获取内存信息，无法读取内存总量时返回 [SystemInfoError::MemoryUnavailable]
+	#[cfg(feature = "memory")]
+	pub fn try_memory(&self) -> Result<MemoryInfo, SystemInfoError> {
+		MemoryInfo::try_from_system(&self.system, self.meminfo.current())
+			.map(|memory_info| self.apply_container_memory(memory_info))
+	}
+
+	#[cfg(feature = "memory")]
+	fn apply_container_memory(&self, memory_info: MemoryInfo) -> MemoryInfo {
+		match self.container.as_ref().and_then(|sample| sample.current()) {
+			Some(current) => memory_info.with_container(current),
+			None => memory_info,
+		}
 	}
 
 	/// 获取磁盘信息
 	///
-	/// 此函数可以获取磁盘信息，包括总磁盘空间、已用磁盘空间、可用磁盘空间、磁盘使用率等
+	/// 此函数可以获取磁盘信息，包括总磁盘空间、已用磁盘空间、可用磁盘空间、磁盘使用率等，
+	/// 读写速度为最近两次 [SystemInfo::refresh_disk] 之间的平均值
 	/// # 返回值
 	///
 	/// * [DiskInfo] - 磁盘信息
 	///
 	#[cfg(feature = "disk")]
-	pub fn disk() -> DiskInfo {
-		DiskInfo::default()
+	pub fn disk(&self) -> DiskInfo {
+		DiskInfo::from_disks(
+			&self.disks,
+			self.disk_stat.pair(),
+			&self.mounts,
+			self.disk_sample.elapsed,
+		)
+	}
+
+	/// 获取磁盘信息，未检测到磁盘时返回 [SystemInfoError::DiskNotFound]
+	#[cfg(feature = "disk")]
+	pub fn try_disk(&self) -> Result<DiskInfo, SystemInfoError> {
+		DiskInfo::try_from_disks(
+			&self.disks,
+			self.disk_stat.pair(),
+			&self.mounts,
+			self.disk_sample.elapsed,
+		)
 	}
 
 	/// 获取网卡信息
 	///
-	/// 此函数可以获取网络信息，包括网络名称、MAC地址、上传速度、下载速度、IP地址等
+	/// 此函数可以获取网络信息，包括网络名称、MAC地址、上传速度、下载速度、IP地址等，
+	/// 速率为最近两次 [SystemInfo::refresh_network] 之间的平均值
 	/// # 返回值
 	///
 	/// * [NetworkInfo] - 网络信息
 	///
 	#[cfg(feature = "network")]
-	pub fn network() -> Vec<NetworkInfo> {
-		NetworkInfo::all()
+	pub fn network(&self) -> Vec<NetworkInfo> {
+		NetworkInfo::all_from_networks(&self.networks, self.network_sample.elapsed)
 	}
 
 	/// 获取当前网络信息
@@ -108,8 +453,14 @@ impl SystemInfo {
 	/// * [NetworkInfo] - 当前网络信息
 	///
 	#[cfg(feature = "network")]
-	pub fn current_network() -> NetworkInfo {
-		NetworkInfo::default()
+	pub fn current_network(&self) -> NetworkInfo {
+		NetworkInfo::current_from_networks(&self.networks, self.network_sample.elapsed)
+	}
+
+	/// 获取当前网络信息，未检测到活跃网卡时返回 [SystemInfoError::NetworkNotFound]
+	#[cfg(feature = "network")]
+	pub fn try_current_network(&self) -> Result<NetworkInfo, SystemInfoError> {
+		NetworkInfo::try_current_from_networks(&self.networks, self.network_sample.elapsed)
 	}
 
 	/// 获取进程信息
@@ -118,9 +469,9 @@ impl SystemInfo {
 	///
 	/// * [ProcessInfo] - 进程信息
 	#[cfg(feature = "process")]
-	pub fn process() -> ProcessInfo {
+	pub fn process(&self) -> ProcessInfo {
 		use std::process;
-		Self::process_with_pid(process::id())
+		self.process_with_pid(process::id())
 	}
 
 	/// 获取指定 PID 的进程信息及其子进程
@@ -136,29 +487,170 @@ impl SystemInfo {
 	///
 	/// * [ProcessInfo] - 进程信息，包含子进程列表
 	#[cfg(feature = "process")]
-	pub fn process_with_pid(pid: u32) -> ProcessInfo {
-		ProcessInfo::new(pid)
+	pub fn process_with_pid(&self, pid: u32) -> ProcessInfo {
+		#[cfg_attr(not(feature = "gpu"), allow(unused_mut))]
+		let mut process = self
+			.process_tree()
+			.subtree(pid)
+			.unwrap_or_else(|| ProcessInfo::unknown(sysinfo::Pid::from_u32(pid)));
+		// 未刷新过各进程的GPU占用时只读取这些进程的 DRM 客户端
+		#[cfg(feature = "gpu")]
+		if !self.gpu_usage.is_refreshed() {
+			process.attach_drm_usage();
+		}
+		process
 	}
 
+	/// 获取所有顶层进程
+	///
+	/// 此函数可以获取系统中所有进程的信息，父进程不存在的进程位于顶层，子进程位于 `sub_list`
+	/// # 返回值
+	///
+	/// * `Vec<ProcessInfo>` - 顶层进程列表
 	#[cfg(feature = "process")]
-	pub fn process_all() -> Vec<ProcessInfo> {
-		ProcessInfo::all()
+	pub fn process_all(&self) -> Vec<ProcessInfo> {
+		self.process_tree().to_nested()
 	}
 
-	/// 获取GPU信息
+	/// 获取进程树，可按名称或PID查找、排序、展开并统计子树的资源占用
 	///
-	/// 此函数可以获取GPU信息，包括型号、已用内存、总内存、可用内存、使用率等
+	/// 各进程的GPU占用来自最近一次 [SystemInfo::refresh_process_gpu]，未刷新时为 None
+	#[cfg(feature = "process")]
+	pub fn process_tree(&self) -> ProcessTree {
+		#[cfg_attr(not(feature = "gpu"), allow(unused_mut))]
+		let mut tree = ProcessTree::from_system(&self.system);
+		#[cfg(feature = "gpu")]
+		tree.attach_gpu_usage(&self.gpu_usage.usage());
+		tree
+	}
+
+	/// 获取系统负载信息
+	///
+	/// 此函数可以获取1/5/15分钟平均负载、运行中与总任务数，以及 Linux 下的压力阻塞信息(PSI)
+	/// # 返回值
+	///
+	/// * [LoadInfo] - 负载信息
+	///
+	#[cfg(feature = "load")]
+	pub fn load(&self) -> LoadInfo {
+		LoadInfo::default()
+	}
+
+	/// 获取系统负载信息，Linux 下无法读取 `/proc/loadavg` 时返回错误
+	#[cfg(feature = "load")]
+	pub fn try_load(&self) -> Result<LoadInfo, SystemInfoError> {
+		LoadInfo::try_new()
+	}
+
+	/// 获取第一个GPU的信息
+	///
+	/// 此函数可以获取GPU信息，包括型号、已用内存、总内存、可用内存、使用率等，
+	/// 多GPU时使用 [SystemInfo::gpus]
 	///
 	/// # 返回值
 	///
 	/// * `Option<GpuInfo>` - GPU信息，如果无法检测到GPU则返回None
 	///
 	#[cfg(feature = "gpu")]
-	pub fn gpu() -> Option<GpuInfo> {
-		GpuInfo::new()
+	pub fn gpu(&self) -> Option<GpuInfo> {
+		self.try_gpu().ok()
+	}
+
+	/// 获取GPU信息，无法检测到GPU时返回 [SystemInfoError::GpuNotFound]
+	#[cfg(feature = "gpu")]
+	pub fn try_gpu(&self) -> Result<GpuInfo, SystemInfoError> {
+		self.gpus().into_iter().next().ok_or(SystemInfoError::GpuNotFound)
+	}
+
+	/// 获取所有GPU的信息，按 [GpuInfo::index] 排列，无法检测到GPU时返回空列表
+	///
+	/// NVML 句柄在首次调用时初始化，之后的调用与进程GPU占用的刷新共用同一个句柄
+	#[cfg(feature = "gpu")]
+	pub fn gpus(&self) -> Vec<GpuInfo> {
+		GpuInfo::all_with(&self.nvml)
+	}
+
+	/// 获取所有已启用模块的快照
+	///
+	/// 基于最近一次刷新的数据构建，不会在内部等待
+	/// # 返回值
+	///
+	/// * [Snapshot] - 带采集时间的系统信息快照
+	///
+	pub fn snapshot(&self) -> Snapshot {
+		Snapshot {
+			timestamp: chrono::Utc::now(),
+			#[cfg(feature = "host")]
+			host: self.host(),
+			#[cfg(feature = "cpu")]
+			cpu: self.cpu(),
+			#[cfg(feature = "memory")]
+			memory: self.memory(),
+			#[cfg(feature = "disk")]
+			disk: self.disk(),
+			#[cfg(feature = "network")]
+			networks: self.network(),
+			#[cfg(feature = "process")]
+			processes: self.process_all(),
+			#[cfg(feature = "gpu")]
+			gpus: self.gpus(),
+			#[cfg(feature = "load")]
+			load: self.load(),
+		}
+	}
+
+	/// 获取指定模块的快照，未指定的模块为 `None`
+	///
+	/// 基于最近一次刷新的数据构建，不会在内部等待
+	/// # 返回值
+	///
+	/// * [PartialSnapshot] - 只包含指定模块的系统信息快照
+	///
+	pub fn snapshot_of(&self, subsystems: &[Subsystem]) -> PartialSnapshot {
+		#[cfg_attr(
+			not(any(
+				feature = "host",
+				feature = "cpu",
+				feature = "memory",
+				feature = "disk",
+				feature = "network",
+				feature = "process",
+				feature = "gpu",
+				feature = "load"
+			)),
+			allow(unused_variables)
+		)]
+		let enabled = |subsystem: Subsystem| subsystems.contains(&subsystem);
+		PartialSnapshot {
+			timestamp: chrono::Utc::now(),
+			#[cfg(feature = "host")]
+			host: enabled(Subsystem::Host).then(|| self.host()),
+			#[cfg(feature = "cpu")]
+			cpu: enabled(Subsystem::Cpu).then(|| self.cpu()),
+			#[cfg(feature = "memory")]
+			memory: enabled(Subsystem::Memory).then(|| self.memory()),
+			#[cfg(feature = "disk")]
+			disk: enabled(Subsystem::Disk).then(|| self.disk()),
+			#[cfg(feature = "network")]
+			networks: enabled(Subsystem::Network).then(|| self.network()),
+			#[cfg(feature = "process")]
+			processes: enabled(Subsystem::Process).then(|| self.process_all()),
+			#[cfg(feature = "gpu")]
+			gpus: enabled(Subsystem::Gpu).then(|| self.gpus()),
+			#[cfg(feature = "load")]
+			load: enabled(Subsystem::Load).then(|| self.load()),
+		}
 	}
 }
 
+#[cfg(any(
+	feature = "cpu",
+	feature = "memory",
+	feature = "disk",
+	feature = "process",
+	feature = "network",
+	feature = "gpu"
+))]
 fn round(value: f64) -> f64 {
 	(value * 100.0).round() / 100.0
 }
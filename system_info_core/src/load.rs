@@ -0,0 +1,184 @@
+use crate::SystemInfoError;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LoadInfo {
+	/// 1分钟平均负载
+	pub one: f64,
+	/// 5分钟平均负载
+	pub five: f64,
+	/// 15分钟平均负载
+	pub fifteen: f64,
+	/// 正在运行的任务数，仅 Linux 下可用
+	pub running_tasks: Option<u32>,
+	/// 任务总数，仅 Linux 下可用
+	pub total_tasks: Option<u32>,
+	/// 压力阻塞信息(PSI)，仅 Linux 且内核启用 PSI 时可用
+	pub pressure: Option<PressureInfo>,
+}
+
+/// `/proc/pressure` 下的压力阻塞信息
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressureInfo {
+	/// CPU压力
+	pub cpu: Option<Pressure>,
+	/// 内存压力
+	pub memory: Option<Pressure>,
+	/// IO压力
+	pub io: Option<Pressure>,
+}
+
+/// 单项资源的压力阻塞信息
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pressure {
+	/// 至少一个任务因该资源阻塞的时间占比
+	pub some: PressureStat,
+	/// 所有非空闲任务同时因该资源阻塞的时间占比，旧内核的 CPU 压力无此项
+	pub full: Option<PressureStat>,
+}
+
+/// 压力阻塞统计
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PressureStat {
+	/// 最近10秒的阻塞时间占比(单位: %)
+	pub avg10: f32,
+	/// 最近60秒的阻塞时间占比(单位: %)
+	pub avg60: f32,
+	/// 最近300秒的阻塞时间占比(单位: %)
+	pub avg300: f32,
+	/// 累计阻塞时间(单位: 微秒)
+	pub total: u64,
+}
+
+impl Default for LoadInfo {
+	/// 获取负载信息，Linux 下无法读取 `/proc/loadavg` 时回退到 `sysinfo` 的平均负载，任务数为 `None`
+	fn default() -> Self {
+		Self::try_new().unwrap_or_else(|_| Self::from_sysinfo())
+	}
+}
+
+impl LoadInfo {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 获取负载信息，Linux 下无法读取或解析 `/proc/loadavg` 时返回错误
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		#[cfg(target_os = "linux")]
+		{
+			let mut load_info = Self::parse(&crate::error::read_file("/proc/loadavg")?)?;
+			load_info.pressure = PressureInfo::read();
+			Ok(load_info)
+		}
+
+		#[cfg(not(target_os = "linux"))]
+		{
+			Ok(Self::from_sysinfo())
+		}
+	}
+
+	/// 解析 `/proc/loadavg` 格式的文本，如 `0.52 0.58 0.59 2/1234 56789`
+	pub fn parse(text: &str) -> Result<Self, SystemInfoError> {
+		const SOURCE: &str = "/proc/loadavg";
+		let mut fields = text.split_whitespace();
+		let mut next_load = || {
+			fields
+				.next()
+				.and_then(|value| value.parse::<f64>().ok())
+				.ok_or(SystemInfoError::Parse(SOURCE))
+		};
+		let (one, five, fifteen) = (next_load()?, next_load()?, next_load()?);
+		let (running_tasks, total_tasks) = fields
+			.next()
+			.and_then(|tasks| tasks.split_once('/'))
+			.and_then(|(running, total)| Some((running.parse().ok()?, total.parse().ok()?)))
+			.ok_or(SystemInfoError::Parse(SOURCE))?;
+
+		Ok(Self {
+			one,
+			five,
+			fifteen,
+			running_tasks: Some(running_tasks),
+			total_tasks: Some(total_tasks),
+			pressure: None,
+		})
+	}
+
+	fn from_sysinfo() -> Self {
+		let load_avg = sysinfo::System::load_average();
+		Self {
+			one: load_avg.one,
+			five: load_avg.five,
+			fifteen: load_avg.fifteen,
+			running_tasks: None,
+			total_tasks: None,
+			pressure: None,
+		}
+	}
+}
+
+impl PressureInfo {
+	/// 读取 `/proc/pressure/{cpu,memory,io}`，均不可用时返回 None
+	#[cfg(target_os = "linux")]
+	pub fn read() -> Option<Self> {
+		let read = |name: &str| {
+			crate::error::read_file(format!("/proc/pressure/{name}"))
+				.and_then(|text| Pressure::parse(&text))
+				.ok()
+		};
+		let pressure_info = Self { cpu: read("cpu"), memory: read("memory"), io: read("io") };
+		if pressure_info.cpu.is_none()
+			&& pressure_info.memory.is_none()
+			&& pressure_info.io.is_none()
+		{
+			return None;
+		}
+		Some(pressure_info)
+	}
+}
+
+impl Pressure {
+	/// 解析 `/proc/pressure/*` 格式的文本
+	///
+	/// ```text
+	/// some avg10=0.12 avg60=0.08 avg300=0.02 total=123456
+	/// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+	/// ```
+	pub fn parse(text: &str) -> Result<Self, SystemInfoError> {
+		let mut some = None;
+		let mut full = None;
+		for line in text.lines() {
+			let mut fields = line.split_whitespace();
+			match fields.next() {
+				Some("some") => some = Some(PressureStat::parse(fields)?),
+				Some("full") => full = Some(PressureStat::parse(fields)?),
+				_ => continue,
+			}
+		}
+		Ok(Self { some: some.ok_or(SystemInfoError::Parse("/proc/pressure"))?, full })
+	}
+}
+
+impl PressureStat {
+	fn parse<'a>(fields: impl Iterator<Item = &'a str>) -> Result<Self, SystemInfoError> {
+		const SOURCE: &str = "/proc/pressure";
+		let mut stat = Self::default();
+		for field in fields {
+			let (key, value) = field.split_once('=').ok_or(SystemInfoError::Parse(SOURCE))?;
+			let parse_avg = || value.parse::<f32>().map_err(|_| SystemInfoError::Parse(SOURCE));
+			match key {
+				"avg10" => stat.avg10 = parse_avg()?,
+				"avg60" => stat.avg60 = parse_avg()?,
+				"avg300" => stat.avg300 = parse_avg()?,
+				"total" => {
+					stat.total = value.parse().map_err(|_| SystemInfoError::Parse(SOURCE))?
+				}
+				_ => {}
+			}
+		}
+		Ok(stat)
+	}
+}
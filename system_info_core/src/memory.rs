@@ -1,63 +1,378 @@
+use sysinfo::System;
+
+use crate::{ByteSize, ContainerInfo, SystemInfoError};
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryInfo {
-	/// 总内存(单位: MB)
-	pub total: u64,
+	/// 总内存
+	pub total: ByteSize,
 	/// 内存使用率
 	pub usage: f32,
-	/// 已用内存(单位: MB)
-	pub used: u64,
-	/// 可用内存(单位: MB)
-	pub free: u64,
-	/// 交换内存(单位: MB)
-	pub swap_total: Option<u64>,
-	/// 交换内存已用(单位: MB)
-	pub swap_used: Option<u64>,
-	/// 交换内存可用(单位: MB)
-	pub swap_free: Option<u64>,
+	/// 已用内存
+	pub used: ByteSize,
+	/// 空闲内存
+	///
+	/// Linux 下为 `/proc/meminfo` 的 `MemFree`，不含可回收的页缓存，通常远小于实际可分配的内存；
+	/// 其余平台为总内存减去已用内存。判断还能分配多少内存请使用 [MemoryInfo::available]
+	pub free: ByteSize,
+	/// 交换内存
+	pub swap_total: Option<ByteSize>,
+	/// 交换内存已用
+	pub swap_used: Option<ByteSize>,
+	/// 交换内存可用
+	pub swap_free: Option<ByteSize>,
 	/// 交换内存使用率
 	pub swap_usage: Option<f32>,
+	/// 可分配内存，含可回收的页缓存，是衡量剩余内存的首选指标，仅 Linux 下可用
+	pub available: Option<ByteSize>,
+	/// 块设备缓冲区，仅 Linux 下可用
+	pub buffers: Option<ByteSize>,
+	/// 页缓存，仅 Linux 下可用
+	pub cached: Option<ByteSize>,
+	/// 共享内存，仅 Linux 下可用
+	pub shared: Option<ByteSize>,
+	/// 可回收的内核 slab，仅 Linux 下可用
+	pub slab_reclaimable: Option<ByteSize>,
+	/// 不可回收的内核 slab，仅 Linux 下可用
+	pub slab_unreclaimable: Option<ByteSize>,
+	/// 等待写回磁盘的脏页，仅 Linux 下可用
+	pub dirty: Option<ByteSize>,
+	/// 正在写回磁盘的页，仅 Linux 下可用
+	pub writeback: Option<ByteSize>,
+	/// 大页总数，仅 Linux 下可用
+	pub hugepages_total: Option<u64>,
+	/// 空闲大页数，仅 Linux 下可用
+	pub hugepages_free: Option<u64>,
+	/// 大页大小，仅 Linux 下可用
+	pub hugepage_size: Option<ByteSize>,
+	/// 已承诺分配的内存，仅 Linux 下可用
+	pub committed_as: Option<ByteSize>,
+	/// 可承诺分配的内存上限，仅 Linux 下可用
+	pub commit_limit: Option<ByteSize>,
 }
 
+/// `/proc/meminfo` 中的内存统计，容量单位均为字节，缺失的项为 None
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemInfo {
+	/// `MemTotal`
+	pub total: Option<u64>,
+	/// `MemFree`
+	pub free: Option<u64>,
+	/// `MemAvailable`
+	pub available: Option<u64>,
+	/// `Buffers`
+	pub buffers: Option<u64>,
+	/// `Cached`
+	pub cached: Option<u64>,
+	/// `Shmem`
+	pub shared: Option<u64>,
+	/// `SwapTotal`
+	pub swap_total: Option<u64>,
+	/// `SwapFree`
+	pub swap_free: Option<u64>,
+	/// `SReclaimable`
+	pub slab_reclaimable: Option<u64>,
+	/// `SUnreclaim`
+	pub slab_unreclaimable: Option<u64>,
+	/// `Dirty`
+	pub dirty: Option<u64>,
+	/// `Writeback`
+	pub writeback: Option<u64>,
+	/// `HugePages_Total`，单位为页
+	pub hugepages_total: Option<u64>,
+	/// `HugePages_Free`，单位为页
+	pub hugepages_free: Option<u64>,
+	/// `Hugepagesize`
+	pub hugepage_size: Option<u64>,
+	/// `Committed_AS`
+	pub committed_as: Option<u64>,
+	/// `CommitLimit`
+	pub commit_limit: Option<u64>,
+}
 
 impl Default for MemoryInfo {
+	/// 获取内存信息，无法读取内存数据时各项均为 0，交换内存各项为 `None`
 	fn default() -> Self {
-		use sysinfo::System;
-		let mut system = System::new();
-		system.refresh_memory();
-		
-		let total_memory = system.total_memory() / 1024 / 1024;
-		let used_memory = system.used_memory() / 1024 / 1024;
-		let swap_memory_total = system.total_swap() / 1024 / 1024;
-		let swap_memory_used = system.used_swap() / 1024 / 1024;
-		
-		let usage =
-			if total_memory > 0 { (used_memory as f32 / total_memory as f32) * 100.0 } else { 0.0 };
-		
-		let swap_memory_usage = if swap_memory_total > 0 {
-			Some((swap_memory_used as f32 / swap_memory_total as f32) * 100.0)
-		} else {
-			None
+		let (system, meminfo) = sample();
+		Self::from_system(&system, meminfo.as_ref())
+	}
+}
+
+impl MemoryInfo {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 获取内存信息，无法读取内存总量时返回 [SystemInfoError::MemoryUnavailable]
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		let (system, meminfo) = sample();
+		Self::try_from_system(&system, meminfo.as_ref())
+	}
+
+	pub(crate) fn try_from_system(
+		system: &System,
+		meminfo: Option<&MemInfo>,
+	) -> Result<Self, SystemInfoError> {
+		if system.total_memory() == 0 {
+			return Err(SystemInfoError::MemoryUnavailable);
+		}
+		Ok(Self::from_system(system, meminfo))
+	}
+
+	/// 从已刷新内存数据的 [System] 与最近一次读取的 `/proc/meminfo` 中构建内存信息，不会读取文件
+	pub(crate) fn from_system(system: &System, meminfo: Option<&MemInfo>) -> Self {
+		let total = ByteSize::from_bytes(system.total_memory());
+		let used = ByteSize::from_bytes(system.used_memory());
+		let swap_total = ByteSize::from_bytes(system.total_swap());
+		let swap_used = ByteSize::from_bytes(system.used_swap());
+		let has_swap = swap_total > ByteSize::ZERO;
+
+		let memory_info = Self {
+			total,
+			usage: used.percent_of(total) as f32,
+			used,
+			free: total.saturating_sub(used),
+			swap_total: has_swap.then_some(swap_total),
+			swap_used: has_swap.then_some(swap_used),
+			swap_free: has_swap.then(|| swap_total.saturating_sub(swap_used)),
+			swap_usage: has_swap.then(|| swap_used.percent_of(swap_total) as f32),
+			available: None,
+			buffers: None,
+			cached: None,
+			shared: None,
+			slab_reclaimable: None,
+			slab_unreclaimable: None,
+			dirty: None,
+			writeback: None,
+			hugepages_total: None,
+			hugepages_free: None,
+			hugepage_size: None,
+			committed_as: None,
+			commit_limit: None,
+		};
+		match meminfo {
+			Some(meminfo) => memory_info.with_meminfo(meminfo),
+			None => memory_info,
+		}
+	}
+
+	/// 使用 `/proc/meminfo` 中的统计填充详细内存字段，存在 `MemFree` 时以其作为空闲内存
+	pub fn with_meminfo(self, meminfo: &MemInfo) -> Self {
+		let bytes = |bytes: Option<u64>| bytes.map(ByteSize::from_bytes);
+		Self {
+			free: bytes(meminfo.free).unwrap_or(self.free),
+			available: bytes(meminfo.available),
+			buffers: bytes(meminfo.buffers),
+			cached: bytes(meminfo.cached),
+			shared: bytes(meminfo.shared),
+			slab_reclaimable: bytes(meminfo.slab_reclaimable),
+			slab_unreclaimable: bytes(meminfo.slab_unreclaimable),
+			dirty: bytes(meminfo.dirty),
+			writeback: bytes(meminfo.writeback),
+			hugepages_total: meminfo.hugepages_total,
+			hugepages_free: meminfo.hugepages_free,
+			hugepage_size: bytes(meminfo.hugepage_size),
+			committed_as: bytes(meminfo.committed_as),
+			commit_limit: bytes(meminfo.commit_limit),
+			..self
+		}
+	}
+
+	/// 按容器的内存限制调整内存信息
+	///
+	/// 容器设置了低于主机内存的上限时，总内存改为该上限，已用内存改为容器的内存工作集；
+	/// 交换内存与 `/proc/meminfo` 详细字段保持不变
+	pub fn with_container(self, container: &ContainerInfo) -> Self {
+		let Some(limit) = container.memory_limit.map(ByteSize::from_bytes) else {
+			return self;
 		};
-		
+		if limit == ByteSize::ZERO || limit >= self.total {
+			return self;
+		}
+		let used =
+			container.memory_working_set().map_or(self.used, ByteSize::from_bytes).min(limit);
 		Self {
-			total: total_memory,
-			usage,
-			used: used_memory,
-			free: total_memory - used_memory,
-			swap_total: if swap_memory_total > 0 { Some(swap_memory_total) } else { None },
-			swap_used: if swap_memory_total > 0 { Some(swap_memory_used) } else { None },
-			swap_free: if swap_memory_total > 0 {
-				Some(swap_memory_total - swap_memory_used)
-			} else {
-				None
-			},
-			swap_usage: swap_memory_usage,
+			total: limit,
+			used,
+			free: limit - used,
+			usage: used.percent_of(limit) as f32,
+			..self
 		}
 	}
 }
 
+/// 与旧版本单位字段数值相同的访问器，按 1024 进制换算并向下取整
 impl MemoryInfo {
-	pub fn new() -> Self {
-		Self::default()
+	/// 总内存(单位: MB)
+	pub fn total_mb(&self) -> u64 {
+		self.total.whole_mib()
+	}
+
+	/// 已用内存(单位: MB)
+	pub fn used_mb(&self) -> u64 {
+		self.used.whole_mib()
+	}
+
+	/// 空闲内存(单位: MB)
+	pub fn free_mb(&self) -> u64 {
+		self.free.whole_mib()
+	}
+
+	/// 交换内存(单位: MB)
+	pub fn swap_total_mb(&self) -> Option<u64> {
+		self.swap_total.map(ByteSize::whole_mib)
 	}
-}
\ No newline at end of file
+
+	/// 交换内存已用(单位: MB)
+	pub fn swap_used_mb(&self) -> Option<u64> {
+		self.swap_used.map(ByteSize::whole_mib)
+	}
+
+	/// 交换内存可用(单位: MB)
+	pub fn swap_free_mb(&self) -> Option<u64> {
+		self.swap_free.map(ByteSize::whole_mib)
+	}
+
+	/// 可分配内存(单位: MB)
+	pub fn available_mb(&self) -> Option<u64> {
+		self.available.map(ByteSize::whole_mib)
+	}
+
+	/// 块设备缓冲区(单位: MB)
+	pub fn buffers_mb(&self) -> Option<u64> {
+		self.buffers.map(ByteSize::whole_mib)
+	}
+
+	/// 页缓存(单位: MB)
+	pub fn cached_mb(&self) -> Option<u64> {
+		self.cached.map(ByteSize::whole_mib)
+	}
+
+	/// 共享内存(单位: MB)
+	pub fn shared_mb(&self) -> Option<u64> {
+		self.shared.map(ByteSize::whole_mib)
+	}
+
+	/// 可回收的内核 slab(单位: MB)
+	pub fn slab_reclaimable_mb(&self) -> Option<u64> {
+		self.slab_reclaimable.map(ByteSize::whole_mib)
+	}
+
+	/// 不可回收的内核 slab(单位: MB)
+	pub fn slab_unreclaimable_mb(&self) -> Option<u64> {
+		self.slab_unreclaimable.map(ByteSize::whole_mib)
+	}
+
+	/// 等待写回磁盘的脏页(单位: MB)
+	pub fn dirty_mb(&self) -> Option<u64> {
+		self.dirty.map(ByteSize::whole_mib)
+	}
+
+	/// 正在写回磁盘的页(单位: MB)
+	pub fn writeback_mb(&self) -> Option<u64> {
+		self.writeback.map(ByteSize::whole_mib)
+	}
+
+	/// 已承诺分配的内存(单位: MB)
+	pub fn committed_as_mb(&self) -> Option<u64> {
+		self.committed_as.map(ByteSize::whole_mib)
+	}
+
+	/// 可承诺分配的内存上限(单位: MB)
+	pub fn commit_limit_mb(&self) -> Option<u64> {
+		self.commit_limit.map(ByteSize::whole_mib)
+	}
+
+	/// 大页大小(单位: KB)
+	pub fn hugepage_size_kb(&self) -> Option<u64> {
+		self.hugepage_size.map(|size| size.as_bytes() / 1024)
+	}
+}
+
+impl MemInfo {
+	/// 解析 `/proc/meminfo` 格式的文本，忽略未识别的项
+	pub fn parse(text: &str) -> Result<Self, SystemInfoError> {
+		const SOURCE: &str = "/proc/meminfo";
+		let mut meminfo = Self::default();
+		for line in text.lines() {
+			let Some((key, value)) = line.split_once(':') else {
+				continue;
+			};
+			let mut fields = value.split_whitespace();
+			let value = fields
+				.next()
+				.and_then(|value| value.parse::<u64>().ok())
+				.ok_or(SystemInfoError::Parse(SOURCE))?;
+			let value = match fields.next() {
+				Some("kB") => value.checked_mul(1024).ok_or(SystemInfoError::Parse(SOURCE))?,
+				Some(_) => return Err(SystemInfoError::Parse(SOURCE)),
+				None => value,
+			};
+			let field = match key {
+				"MemTotal" => &mut meminfo.total,
+				"MemFree" => &mut meminfo.free,
+				"MemAvailable" => &mut meminfo.available,
+				"Buffers" => &mut meminfo.buffers,
+				"Cached" => &mut meminfo.cached,
+				"Shmem" => &mut meminfo.shared,
+				"SwapTotal" => &mut meminfo.swap_total,
+				"SwapFree" => &mut meminfo.swap_free,
+				"SReclaimable" => &mut meminfo.slab_reclaimable,
+				"SUnreclaim" => &mut meminfo.slab_unreclaimable,
+				"Dirty" => &mut meminfo.dirty,
+				"Writeback" => &mut meminfo.writeback,
+				"HugePages_Total" => &mut meminfo.hugepages_total,
+				"HugePages_Free" => &mut meminfo.hugepages_free,
+				"Hugepagesize" => &mut meminfo.hugepage_size,
+				"Committed_AS" => &mut meminfo.committed_as,
+				"CommitLimit" => &mut meminfo.commit_limit,
+				_ => continue,
+			};
+			*field = Some(value);
+		}
+		if meminfo.total.is_none() {
+			return Err(SystemInfoError::Parse(SOURCE));
+		}
+		Ok(meminfo)
+	}
+
+	/// 读取当前的 `/proc/meminfo`
+	#[cfg(target_os = "linux")]
+	pub fn read() -> Result<Self, SystemInfoError> {
+		Self::parse(&crate::error::read_file("/proc/meminfo")?)
+	}
+}
+
+fn sample() -> (System, Option<MemInfo>) {
+	let mut system = System::new();
+	system.refresh_memory();
+	(system, MemInfoSample::read())
+}
+
+/// 最近一次读取的 `/proc/meminfo`，非 Linux 平台下始终为空
+#[derive(Debug, Default)]
+pub(crate) struct MemInfoSample {
+	current: Option<MemInfo>,
+}
+
+impl MemInfoSample {
+	pub(crate) fn refresh(&mut self) {
+		self.current = Self::read();
+	}
+
+	pub(crate) fn current(&self) -> Option<&MemInfo> {
+		self.current.as_ref()
+	}
+
+	#[cfg(target_os = "linux")]
+	fn read() -> Option<MemInfo> {
+		MemInfo::read().ok()
+	}
+
+	#[cfg(not(target_os = "linux"))]
+	fn read() -> Option<MemInfo> {
+		None
+	}
+}
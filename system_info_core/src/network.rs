@@ -1,18 +1,24 @@
 use std::net::IpAddr;
+use std::path::Path;
+use std::time::Duration;
 
 use sysinfo::{MacAddr, NetworkData, Networks};
 
-use crate::round;
+use crate::pci::PciAttributes;
+use crate::{ByteSize, SystemInfoError, round};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IpInfo {
 	/// ip地址
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_ext::ip_addr"))]
 	pub ip_address: IpAddr,
 	/// 子网掩码
 	pub netmask: u8,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkInfo {
 	/// 网卡名称
 	pub name: String,
@@ -22,29 +28,105 @@ pub struct NetworkInfo {
 	pub upload: f64,
 	/// 下载速度(单位: KB/S)
 	pub download: f64,
-	/// 总上传流量(单位: MB)
-	pub total_upload: f64,
-	/// 总下载流量(单位: MB)
-	pub total_download: f64,
+	/// 上传速度(单位: 字节/秒)，未经取整
+	pub upload_bytes_per_second: f64,
+	/// 下载速度(单位: 字节/秒)，未经取整
+	pub download_bytes_per_second: f64,
+	/// 总上传流量
+	pub total_upload: ByteSize,
+	/// 总下载流量
+	pub total_download: ByteSize,
 	/// 网卡mac地址
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_ext::mac_addr"))]
 	pub mac_addr: MacAddr,
+	/// 网卡的PCI设备信息，虚拟网卡、非 PCI 设备或非 Linux 系统为 None
+	pub device: Option<NetworkDevice>,
 }
 
-impl Default for NetworkInfo {
-	fn default() -> Self {
-		use std::thread::sleep;
-		use std::time::Duration;
-
-		let mut networks = Networks::new_with_refreshed_list();
-		sleep(Duration::from_millis(100));
-		networks.refresh(true);
-		if let Some(info) = Self::find_active_network(&networks, true) {
-			return info;
+/// 网卡对应的PCI设备
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NetworkDevice {
+	/// PCI厂商ID
+	pub vendor_id: u16,
+	/// PCI设备ID
+	pub device_id: u16,
+	/// PCI子系统厂商ID
+	pub subsystem_vendor_id: Option<u16>,
+	/// PCI子系统设备ID
+	pub subsystem_device_id: Option<u16>,
+	/// 厂商名称，需启用 `pci-ids` feature
+	pub vendor: Option<String>,
+	/// 设备名称，需启用 `pci-ids` feature
+	pub model: Option<String>,
+	/// 子系统名称，即板卡厂商对该设备的命名，需启用 `pci-ids` feature
+	pub subsystem: Option<String>,
+	/// 内核驱动，如 `igb`、`r8169`
+	pub driver: Option<String>,
+	/// PCI总线地址，如 `0000:02:00.0`
+	pub pci_bus_id: Option<String>,
+}
+
+impl NetworkDevice {
+	/// 从指定的 sysfs 根目录(通常为 `/sys`)读取 `class/net/<interface>/device`
+	///
+	/// 虚拟网卡或非 PCI 设备返回 `None`，名称字段均为 `None`，可通过 `resolve_names` 补全
+	pub fn from_sysfs(root: impl AsRef<Path>, interface: &str) -> Option<Self> {
+		let pci =
+			PciAttributes::read(&root.as_ref().join("class/net").join(interface).join("device"))?;
+		Some(Self {
+			vendor_id: pci.vendor_id,
+			device_id: pci.device_id,
+			subsystem_vendor_id: pci.subsystem_vendor_id,
+			subsystem_device_id: pci.subsystem_device_id,
+			vendor: None,
+			model: None,
+			subsystem: None,
+			driver: pci.driver,
+			pci_bus_id: pci.pci_bus_id,
+		})
+	}
+
+	/// 使用PCI ID数据库补全厂商、设备与子系统名称
+	#[cfg(feature = "pci-ids")]
+	pub fn resolve_names(&mut self, ids: &crate::PciIds) {
+		self.vendor = ids.vendor(self.vendor_id).map(str::to_string);
+		self.model = ids.device(self.vendor_id, self.device_id).map(str::to_string);
+		self.subsystem = self
+			.subsystem_vendor_id
+			.zip(self.subsystem_device_id)
+			.and_then(|(vendor, device)| {
+				ids.subsystem(self.vendor_id, self.device_id, vendor, device)
+			})
+			.map(str::to_string);
+	}
+
+	/// 读取当前系统中指定网卡的设备信息
+	fn detect(interface: &str) -> Option<Self> {
+		#[cfg(target_os = "linux")]
+		{
+			#[cfg_attr(not(feature = "pci-ids"), allow(unused_mut))]
+			let mut device = Self::from_sysfs("/sys", interface)?;
+			#[cfg(feature = "pci-ids")]
+			if let Some(ids) = crate::PciIds::system() {
+				device.resolve_names(ids);
+			}
+			Some(device)
 		}
-		if let Some(info) = Self::find_active_network(&networks, false) {
-			return info;
+
+		#[cfg(not(target_os = "linux"))]
+		{
+			let _ = interface;
+			None
 		}
-		Self::unknown()
+	}
+}
+
+impl Default for NetworkInfo {
+	/// 获取当前活跃网卡信息，未检测到活跃网卡时名称为 `"Unknown"`，其余各项为空或 0
+	fn default() -> Self {
+		let (networks, interval) = sample();
+		Self::current_from_networks(&networks, interval)
 	}
 }
 
@@ -53,24 +135,43 @@ impl NetworkInfo {
 		Self::default()
 	}
 
+	/// 获取当前活跃网卡信息，未检测到活跃网卡时返回 [SystemInfoError::NetworkNotFound]
+	pub fn try_new() -> Result<Self, SystemInfoError> {
+		let (networks, interval) = sample();
+		Self::try_current_from_networks(&networks, interval)
+	}
+
 	pub fn all() -> Vec<Self> {
-		use std::thread::sleep;
-		use std::time::Duration;
-		let mut networks = Networks::new_with_refreshed_list();
-		sleep(Duration::from_millis(100));
-		networks.refresh(true);
-		networks
-			.list()
-			.iter()
-			.map(|(name, data)| {
-				let upload = round(data.transmitted() as f64 / 1024.0 / 0.1);
-				let download = round(data.received() as f64 / 1024.0 / 0.1);
-				Self::from_data(name, data, upload, download)
-			})
-			.collect()
+		let (networks, interval) = sample();
+		Self::all_from_networks(&networks, interval)
 	}
 
-	fn find_active_network(networks: &Networks, require_traffic: bool) -> Option<Self> {
+	/// 从已刷新的 [Networks] 中构建所有网卡信息
+	///
+	/// 速率由最近一次刷新的增量除以 `elapsed` 得到，`elapsed` 为零时速率为 0
+	pub(crate) fn all_from_networks(networks: &Networks, elapsed: Duration) -> Vec<Self> {
+		networks.list().iter().map(|(name, data)| Self::from_data(name, data, elapsed)).collect()
+	}
+
+	/// 从已刷新的 [Networks] 中选出当前活跃网卡
+	pub(crate) fn current_from_networks(networks: &Networks, elapsed: Duration) -> Self {
+		Self::try_current_from_networks(networks, elapsed).unwrap_or_else(|_| Self::unknown())
+	}
+
+	pub(crate) fn try_current_from_networks(
+		networks: &Networks,
+		elapsed: Duration,
+	) -> Result<Self, SystemInfoError> {
+		Self::find_active_network(networks, elapsed, true)
+			.or_else(|| Self::find_active_network(networks, elapsed, false))
+			.ok_or(SystemInfoError::NetworkNotFound)
+	}
+
+	fn find_active_network(
+		networks: &Networks,
+		elapsed: Duration,
+		require_traffic: bool,
+	) -> Option<Self> {
 		for (name, data) in networks.list() {
 			if Self::is_loopback(name) {
 				continue;
@@ -88,37 +189,34 @@ impl NetworkInfo {
 				continue;
 			}
 
-			let (upload, download) = if has_traffic {
-				(
-					round(data.transmitted() as f64 / 1024.0 / 0.1),
-					round(data.received() as f64 / 1024.0 / 0.1),
-				)
-			} else {
-				(0.0, 0.0)
-			};
-
-			return Some(Self {
-				name: name.to_string(),
-				ip_info,
-				upload,
-				download,
-				total_upload: Self::bytes_to_mb(data.total_transmitted()),
-				total_download: Self::bytes_to_mb(data.total_received()),
-				mac_addr: data.mac_address(),
-			});
+			return Some(Self::from_data(name, data, elapsed));
 		}
 		None
 	}
 
-	fn from_data(name: &str, data: &NetworkData, upload: f64, download: f64) -> Self {
+	/// 上传与下载速度(单位: 字节/秒)
+	fn bytes_per_second(data: &NetworkData, elapsed: Duration) -> (f64, f64) {
+		let secs = elapsed.as_secs_f64();
+		if secs > 0.0 {
+			(data.transmitted() as f64 / secs, data.received() as f64 / secs)
+		} else {
+			(0.0, 0.0)
+		}
+	}
+
+	fn from_data(name: &str, data: &NetworkData, elapsed: Duration) -> Self {
+		let (upload, download) = Self::bytes_per_second(data, elapsed);
 		Self {
 			name: name.to_string(),
 			ip_info: Self::parse_ip_info(data),
-			upload,
-			download,
-			total_upload: Self::bytes_to_mb(data.total_transmitted()),
-			total_download: Self::bytes_to_mb(data.total_received()),
+			upload: round(upload / 1024.0),
+			download: round(download / 1024.0),
+			upload_bytes_per_second: upload,
+			download_bytes_per_second: download,
+			total_upload: ByteSize::from_bytes(data.total_transmitted()),
+			total_download: ByteSize::from_bytes(data.total_received()),
 			mac_addr: data.mac_address(),
+			device: NetworkDevice::detect(name),
 		}
 	}
 
@@ -128,9 +226,12 @@ impl NetworkInfo {
 			ip_info: Vec::new(),
 			upload: 0.0,
 			download: 0.0,
-			total_upload: 0.0,
-			total_download: 0.0,
+			upload_bytes_per_second: 0.0,
+			download_bytes_per_second: 0.0,
+			total_upload: ByteSize::ZERO,
+			total_download: ByteSize::ZERO,
 			mac_addr: MacAddr([0u8; 6]),
+			device: None,
 		}
 	}
 
@@ -144,8 +245,28 @@ impl NetworkInfo {
 	fn is_loopback(name: &str) -> bool {
 		name.starts_with("lo") || name.starts_with("Loopback") || name.contains("loopback")
 	}
+}
+
+/// 与旧版本单位字段数值相同的访问器，按 1024 进制换算并保留两位小数
+impl NetworkInfo {
+	/// 总上传流量(单位: MB)
+	pub fn total_upload_mb(&self) -> f64 {
+		round(self.total_upload.as_mib())
+	}
 
-	fn bytes_to_mb(bytes: u64) -> f64 {
-		round(bytes as f64 / 1024.0 / 1024.0)
+	/// 总下载流量(单位: MB)
+	pub fn total_download_mb(&self) -> f64 {
+		round(self.total_download.as_mib())
 	}
 }
+
+/// 一次性采样网卡数据，间隔 100ms 刷新两次以计算速率
+fn sample() -> (Networks, Duration) {
+	use std::thread::sleep;
+
+	let interval = Duration::from_millis(100);
+	let mut networks = Networks::new_with_refreshed_list();
+	sleep(interval);
+	networks.refresh(true);
+	(networks, interval)
+}
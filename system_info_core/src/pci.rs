@@ -0,0 +1,56 @@
+//! 读取 sysfs 中 PCI 设备目录的通用属性
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::read_file;
+
+/// PCI 设备的ID、驱动与总线地址
+#[derive(Debug, Clone)]
+#[cfg_attr(not(all(feature = "gpu", feature = "network")), allow(dead_code))]
+pub(crate) struct PciAttributes {
+	pub(crate) vendor_id: u16,
+	pub(crate) device_id: u16,
+	pub(crate) subsystem_vendor_id: Option<u16>,
+	pub(crate) subsystem_device_id: Option<u16>,
+	/// 设备类别，如 `0x030000`
+	pub(crate) class: Option<u32>,
+	pub(crate) driver: Option<String>,
+	/// 小写的总线地址，如 `0000:01:00.0`
+	pub(crate) pci_bus_id: Option<String>,
+}
+
+impl PciAttributes {
+	/// 读取 `device` 目录(如 `/sys/class/drm/card0/device`)，没有 `vendor` 与 `device` 文件的非 PCI 设备返回 None
+	pub(crate) fn read(device: &Path) -> Option<Self> {
+		let uevent = read_file(device.join("uevent")).unwrap_or_default();
+		let uevent_value = |key: &str| {
+			uevent
+				.lines()
+				.find_map(|line| Some(line.strip_prefix(key)?.strip_prefix('=')?.to_string()))
+		};
+
+		Some(Self {
+			vendor_id: read_hex(device.join("vendor"))? as u16,
+			device_id: read_hex(device.join("device"))? as u16,
+			subsystem_vendor_id: read_hex(device.join("subsystem_vendor")).map(|id| id as u16),
+			subsystem_device_id: read_hex(device.join("subsystem_device")).map(|id| id as u16),
+			class: read_hex(device.join("class")),
+			driver: uevent_value("DRIVER").or_else(|| link_name(&device.join("driver"))),
+			pci_bus_id: uevent_value("PCI_SLOT_NAME")
+				.or_else(|| link_name(device))
+				.map(|id| id.to_lowercase()),
+		})
+	}
+}
+
+/// 读取 `0x10de` 格式的十六进制数
+fn read_hex(path: PathBuf) -> Option<u32> {
+	let text = read_file(path).ok()?;
+	u32::from_str_radix(text.trim().trim_start_matches("0x"), 16).ok()
+}
+
+/// 符号链接指向的文件名，如 `device/driver` -> `.../drivers/amdgpu`
+fn link_name(path: &Path) -> Option<String> {
+	Some(fs::read_link(path).ok()?.file_name()?.to_str()?.to_string())
+}
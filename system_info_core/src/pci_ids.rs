@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use crate::SystemInfoError;
+use crate::error::read_file;
+
+/// 系统中 `pci.ids` 的常见位置，按顺序查找
+const SEARCH_PATHS: &[&str] = &[
+	"/usr/share/hwdata/pci.ids",
+	"/usr/share/misc/pci.ids",
+	"/usr/share/pci.ids",
+	"/usr/local/share/hwdata/pci.ids",
+	"/usr/local/share/pci.ids",
+	"/var/lib/pciutils/pci.ids",
+	"/opt/homebrew/share/pci.ids",
+];
+
+/// PCI ID 数据库，由 [pci.ids](https://pci-ids.ucw.cz/) 解析而来
+///
+/// 将厂商ID、设备ID与子系统ID解析为名称，可单独使用，
+/// 启用 `pci-ids` feature 后 `GpuInfo` 与网卡的 `NetworkDevice` 也会使用系统中的数据库补全名称
+///
+/// # 示例
+///
+/// ```no_run
+/// use puniyu_system_info::PciIds;
+///
+/// if let Some(ids) = PciIds::system() {
+///     println!("{:?}", ids.device(0x10de, 0x2204));
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PciIds {
+	vendors: HashMap<u16, Vendor>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Vendor {
+	name: String,
+	devices: HashMap<u16, Device>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Device {
+	name: String,
+	/// 以 (子系统厂商ID, 子系统设备ID) 为键
+	subsystems: HashMap<(u16, u16), String>,
+}
+
+impl PciIds {
+	/// 解析 `pci.ids` 格式的文本，无法解析的行会被忽略
+	///
+	/// 文件末尾以 `C` 开头的设备类别部分不会被解析
+	pub fn parse(text: &str) -> Self {
+		let mut ids = Self::default();
+		let mut vendor: Option<u16> = None;
+		let mut device: Option<u16> = None;
+
+		for line in text.lines() {
+			if line.trim().is_empty() || line.starts_with('#') {
+				continue;
+			}
+			if let Some(line) = line.strip_prefix("\t\t") {
+				// 子系统：`\t\tsubvendor subdevice  name`
+				let (Some(vendor), Some(device)) = (vendor, device) else {
+					continue;
+				};
+				let Some((subvendor, rest)) = split_id(line) else {
+					continue;
+				};
+				let Some((subdevice, name)) = split_id(rest) else {
+					continue;
+				};
+				if let Some(entry) =
+					ids.vendors.get_mut(&vendor).and_then(|entry| entry.devices.get_mut(&device))
+				{
+					entry.subsystems.insert((subvendor, subdevice), name.to_string());
+				}
+			} else if let Some(line) = line.strip_prefix('\t') {
+				// 设备：`\tdevice  name`
+				let Some(vendor) = vendor else {
+					continue;
+				};
+				device = split_id(line).map(|(id, name)| {
+					if let Some(entry) = ids.vendors.get_mut(&vendor) {
+						entry
+							.devices
+							.insert(id, Device { name: name.to_string(), ..Default::default() });
+					}
+					id
+				});
+			} else {
+				// 设备类别部分不再包含厂商
+				if line.starts_with("C ") {
+					break;
+				}
+				device = None;
+				vendor = split_id(line).map(|(id, name)| {
+					ids.vendors.insert(id, Vendor { name: name.to_string(), ..Default::default() });
+					id
+				});
+			}
+		}
+		ids
+	}
+
+	/// 读取并解析指定的 `pci.ids` 文件
+	pub fn load(path: impl AsRef<Path>) -> Result<Self, SystemInfoError> {
+		read_file(path).map(|text| Self::parse(&text))
+	}
+
+	/// 系统中的 `pci.ids`，首次调用时在 `/usr/share/hwdata` 等常见位置查找并解析，
+	/// 找不到时返回 `None`
+	pub fn system() -> Option<&'static Self> {
+		static SYSTEM: OnceLock<Option<PciIds>> = OnceLock::new();
+		SYSTEM.get_or_init(|| SEARCH_PATHS.iter().find_map(|path| Self::load(path).ok())).as_ref()
+	}
+
+	/// 数据库中的厂商数
+	pub fn len(&self) -> usize {
+		self.vendors.len()
+	}
+
+	/// 数据库是否为空
+	pub fn is_empty(&self) -> bool {
+		self.vendors.is_empty()
+	}
+
+	/// 厂商名称，如 `0x10de` 为 `NVIDIA Corporation`
+	pub fn vendor(&self, vendor_id: u16) -> Option<&str> {
+		self.vendors.get(&vendor_id).map(|vendor| vendor.name.as_str())
+	}
+
+	/// 设备名称，如 `0x10de:0x2204` 为 `GA102 [GeForce RTX 3090]`
+	pub fn device(&self, vendor_id: u16, device_id: u16) -> Option<&str> {
+		self.find_device(vendor_id, device_id).map(|device| device.name.as_str())
+	}
+
+	/// 子系统名称，即板卡厂商对该设备的命名
+	pub fn subsystem(
+		&self,
+		vendor_id: u16,
+		device_id: u16,
+		subsystem_vendor_id: u16,
+		subsystem_device_id: u16,
+	) -> Option<&str> {
+		self.find_device(vendor_id, device_id)?
+			.subsystems
+			.get(&(subsystem_vendor_id, subsystem_device_id))
+			.map(String::as_str)
+	}
+
+	fn find_device(&self, vendor_id: u16, device_id: u16) -> Option<&Device> {
+		self.vendors.get(&vendor_id)?.devices.get(&device_id)
+	}
+}
+
+/// 拆分 `10de  NVIDIA Corporation` 为ID与其余部分
+fn split_id(line: &str) -> Option<(u16, &str)> {
+	let (id, rest) = line.split_once(' ')?;
+	let id = u16::from_str_radix(id, 16).ok()?;
+	Some((id, rest.trim()))
+}
@@ -1,11 +1,23 @@
 use std::time::{SystemTime, UNIX_EPOCH};
-use sysinfo::{Pid, Process, System};
+use sysinfo::{Pid, Process};
 
-use crate::round;
+use crate::{ByteSize, round};
+
+mod tree;
+pub use tree::{ProcessSort, ProcessTotals, ProcessTree};
+
+#[cfg(feature = "gpu")]
+mod gpu_usage;
+#[cfg(feature = "gpu")]
+pub(crate) use gpu_usage::GpuUsageSample;
+#[cfg(feature = "gpu")]
+pub use gpu_usage::{DrmClient, ProcessGpuUsage};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessInfo {
 	/// 进程ID
+	#[cfg_attr(feature = "serde", serde(with = "crate::serde_ext::pid"))]
 	pub pid: Pid,
 	/// 进程名称
 	pub name: String,
@@ -19,8 +31,11 @@ pub struct ProcessInfo {
 	pub cpu_usage: Option<f32>,
 	/// 进程内存使用率
 	pub memory_usage: Option<f32>,
-	/// 进程已用内存(单位: MB)
-	pub used_memory: f64,
+	/// 进程已用内存
+	pub used_memory: ByteSize,
+	/// 进程在各GPU上的显存占用与使用率，未使用GPU时为 None
+	#[cfg(feature = "gpu")]
+	pub gpus: Option<Vec<ProcessGpuUsage>>,
 }
 
 impl Default for ProcessInfo {
@@ -30,44 +45,26 @@ impl Default for ProcessInfo {
 }
 
 impl ProcessInfo {
+	/// 获取指定进程及其子进程的信息，进程不存在时名称为 `Unknown`
+	///
+	/// 启用 `gpu` 特性时只读取这些进程的 DRM 客户端显存占用，不包含 NVML 数据
 	pub fn new(pid: u32) -> Self {
-		use sysinfo::ProcessesToUpdate;
-		let mut system = System::new();
-		let pid = Pid::from_u32(pid);
-		system.refresh_processes(ProcessesToUpdate::All, true);
-		system.refresh_memory();
-
-		let total_memory = system.total_memory();
-
-		system
-			.process(pid)
-			.map_or_else(|| Self::unknown(pid), |p| Self::from_process(p, &system, total_memory))
+		#[cfg_attr(not(feature = "gpu"), allow(unused_mut))]
+		let mut process =
+			ProcessTree::new().subtree(pid).unwrap_or_else(|| Self::unknown(Pid::from_u32(pid)));
+		#[cfg(feature = "gpu")]
+		process.attach_drm_usage();
+		process
 	}
 
+	/// 获取所有顶层进程，子进程嵌套在 [ProcessInfo::sub_list] 中
+	///
+	/// 不包含GPU占用，需要时使用 [ProcessTree::with_gpu_usage]
 	pub fn all() -> Vec<ProcessInfo> {
-		use std::collections::HashSet;
-		use sysinfo::ProcessesToUpdate;
-
-		let mut system = System::new();
-		system.refresh_processes(ProcessesToUpdate::All, true);
-		system.refresh_memory();
-		let total_memory = system.total_memory();
-
-		let all_pids: HashSet<Pid> = system.processes().keys().copied().collect();
-
-		all_pids
-			.iter()
-			.filter_map(|&pid| {
-				let process = system.process(pid)?;
-				if process.parent().is_some_and(|parent_pid| all_pids.contains(&parent_pid)) {
-					return None;
-				}
-				Some(Self::from_process(process, &system, total_memory))
-			})
-			.collect()
+		ProcessTree::new().to_nested()
 	}
 
-	fn unknown(pid: Pid) -> Self {
+	pub(crate) fn unknown(pid: Pid) -> Self {
 		Self {
 			pid,
 			name: "Unknown".to_string(),
@@ -76,37 +73,30 @@ impl ProcessInfo {
 			run_time: 0,
 			cpu_usage: None,
 			memory_usage: None,
-			used_memory: 0.0,
+			used_memory: ByteSize::ZERO,
+			#[cfg(feature = "gpu")]
+			gpus: None,
 		}
 	}
 
-	fn from_process(process: &Process, system: &System, total_memory: u64) -> Self {
-		let pid = process.pid();
+	/// 不含子进程的进程信息，子进程由 [ProcessTree] 建立
+	fn from_process(process: &Process, total_memory: u64) -> Self {
 		let current_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
 
 		Self {
-			pid,
+			pid: process.pid(),
 			name: process.name().to_string_lossy().to_string(),
-			sub_list: Self::build_children(system, pid, total_memory),
+			sub_list: None,
 			start_time: process.start_time(),
 			run_time: current_time.saturating_sub(process.start_time()),
 			cpu_usage: Self::calc_cpu_usage(process),
 			memory_usage: Self::calc_memory_usage(process, total_memory),
-			used_memory: process.memory() as f64 / 1024.0 / 1024.0,
+			used_memory: ByteSize::from_bytes(process.memory()),
+			#[cfg(feature = "gpu")]
+			gpus: None,
 		}
 	}
 
-	fn build_children(system: &System, parent_pid: Pid, total_memory: u64) -> Option<Vec<Self>> {
-		let children: Vec<Self> = system
-			.processes()
-			.values()
-			.filter(|p| p.parent() == Some(parent_pid))
-			.map(|p| Self::from_process(p, system, total_memory))
-			.collect();
-
-		if children.is_empty() { None } else { Some(children) }
-	}
-
 	fn calc_cpu_usage(process: &Process) -> Option<f32> {
 		let usage = process.cpu_usage();
 		if usage > 0.0 { Some(round(usage as f64) as f32) } else { None }
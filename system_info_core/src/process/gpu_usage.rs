@@ -0,0 +1,363 @@
+//! 进程的GPU显存占用与使用率
+//!
+//! NVIDIA 显卡的数据来自 NVML，Linux 下其余显卡(amdgpu、i915、xe 等)读取
+//! `/proc/<pid>/fdinfo` 中的 DRM 客户端统计，格式参见内核文档 `drm-usage-stats`
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use super::ProcessInfo;
+use crate::error::read_file;
+use crate::gpu::NvmlHandle;
+use crate::{ByteSize, GpuInfo};
+
+/// 进程在一块GPU上的显存占用与使用率
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessGpuUsage {
+	/// GPU的PCI总线地址，与 [GpuInfo::pci_bus_id] 一致
+	pub pci_bus_id: Option<String>,
+	/// 内核驱动，如 `nvidia`、`amdgpu`、`i915`
+	pub driver: Option<String>,
+	/// 占用的显存
+	pub memory: Option<ByteSize>,
+	/// GPU使用率(%)，DRM 客户端需要两次采样才能计算
+	pub usage: Option<f32>,
+}
+
+impl ProcessGpuUsage {
+	/// 按进程ID整理 [GpuInfo::processes] 中 NVML 报告的进程
+	pub fn from_gpus(gpus: &[GpuInfo]) -> HashMap<u32, Vec<Self>> {
+		let mut usage: HashMap<u32, Vec<Self>> = HashMap::new();
+		for gpu in gpus {
+			for process in gpu.processes.iter().flatten() {
+				usage.entry(process.pid).or_default().push(Self {
+					pci_bus_id: gpu.pci_bus_id.clone(),
+					driver: gpu.driver.clone(),
+					memory: process.used_memory,
+					usage: process.usage.map(f32::from),
+				});
+			}
+		}
+		usage
+	}
+
+	/// 按GPU汇总一个进程的 DRM 客户端，同一GPU上的多个客户端累加
+	///
+	/// 使用率由 `previous` 中同一客户端间隔 `elapsed` 的忙碌时间增量计算，
+	/// 没有上一次采样或 `elapsed` 为零时为 `None`
+	pub fn from_drm_clients(
+		current: &[DrmClient],
+		previous: &[DrmClient],
+		elapsed: Duration,
+	) -> Vec<Self> {
+		let mut usage: Vec<Self> = Vec::new();
+		for client in current.iter().filter(|client| client.has_stats()) {
+			let client_usage = previous
+				.iter()
+				.find(|previous| previous.key() == client.key())
+				.and_then(|previous| client.usage_since(previous, elapsed));
+			let memory = ByteSize::from_bytes(client.used_memory());
+
+			match usage.iter_mut().find(|entry| {
+				entry.pci_bus_id == client.pci_bus_id
+					&& entry.driver.as_deref() == Some(client.driver.as_str())
+			}) {
+				Some(entry) => {
+					entry.memory = Some(entry.memory.unwrap_or(ByteSize::ZERO) + memory);
+					entry.usage = match (entry.usage, client_usage) {
+						(Some(a), Some(b)) => Some((a + b).min(100.0)),
+						(a, b) => a.or(b),
+					};
+				}
+				None => usage.push(Self {
+					pci_bus_id: client.pci_bus_id.clone(),
+					driver: Some(client.driver.clone()),
+					memory: Some(memory),
+					usage: client_usage,
+				}),
+			}
+		}
+		usage
+	}
+}
+
+/// `/proc/<pid>/fdinfo/<fd>` 中的 DRM 客户端统计
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DrmClient {
+	/// 驱动名称，即 `drm-driver`
+	pub driver: String,
+	/// 客户端ID，同一客户端可能对应多个文件描述符
+	pub client_id: Option<u64>,
+	/// PCI总线地址，即 `drm-pdev`
+	pub pci_bus_id: Option<String>,
+	/// 各内存区域的驻留内存(单位: 字节)，如 `vram`、`gtt`、`system0`
+	pub memory: HashMap<String, u64>,
+	/// 各引擎的累计忙碌时间(单位: 纳秒)，如 `gfx`、`render`
+	pub engines: HashMap<String, u64>,
+	/// 各引擎类别包含的引擎数，未列出的类别为 1
+	pub capacities: HashMap<String, u64>,
+	/// 各引擎的忙碌周期与GPU总周期，xe 以此代替忙碌时间
+	pub cycles: HashMap<String, (u64, u64)>,
+}
+
+impl DrmClient {
+	/// 解析一个 fdinfo 文件的内容，不是 DRM 设备(没有 `drm-driver`)时返回 None
+	///
+	/// 内存优先使用 `drm-resident-*`，其次为旧版的 `drm-memory-*`，最后为 `drm-total-*`
+	pub fn parse(text: &str) -> Option<Self> {
+		let mut client = Self::default();
+		let mut driver = None;
+		let (mut resident, mut legacy, mut total) =
+			(HashMap::new(), HashMap::new(), HashMap::new());
+
+		for line in text.lines() {
+			let Some((key, value)) = line.split_once(':') else {
+				continue;
+			};
+			let value = value.trim();
+			let Some(key) = key.trim().strip_prefix("drm-") else {
+				continue;
+			};
+
+			if key == "driver" {
+				driver = Some(value.to_string());
+			} else if key == "client-id" {
+				client.client_id = value.parse().ok();
+			} else if key == "pdev" {
+				client.pci_bus_id = Some(value.to_lowercase());
+			} else if let Some(engine) = key.strip_prefix("engine-capacity-") {
+				if let Ok(capacity) = value.parse() {
+					client.capacities.insert(engine.to_string(), capacity);
+				}
+			} else if let Some(engine) = key.strip_prefix("engine-") {
+				if let Some(busy) = value.strip_suffix("ns").and_then(|ns| ns.trim().parse().ok()) {
+					client.engines.insert(engine.to_string(), busy);
+				}
+			} else if let Some(engine) = key.strip_prefix("total-cycles-") {
+				if let Ok(cycles) = value.parse() {
+					client.cycles.entry(engine.to_string()).or_default().1 = cycles;
+				}
+			} else if let Some(engine) = key.strip_prefix("cycles-") {
+				if let Ok(cycles) = value.parse() {
+					client.cycles.entry(engine.to_string()).or_default().0 = cycles;
+				}
+			} else if let Some((map, region)) = key
+				.strip_prefix("resident-")
+				.map(|region| (&mut resident, region))
+				.or_else(|| key.strip_prefix("memory-").map(|region| (&mut legacy, region)))
+				.or_else(|| key.strip_prefix("total-").map(|region| (&mut total, region)))
+				&& let Some(bytes) = parse_size(value)
+			{
+				map.insert(region.to_string(), bytes);
+			}
+		}
+
+		client.driver = driver?;
+		client.memory = total;
+		client.memory.extend(legacy);
+		client.memory.extend(resident);
+		Some(client)
+	}
+
+	/// 读取 `proc_root/<pid>/fdinfo` 中的所有 DRM 客户端，重复的客户端只保留一个
+	///
+	/// `proc_root` 通常为 `/proc`；`fd/<n>` 可读且不指向 `/dev/dri` 的文件描述符会被跳过，
+	/// 以免读取每个文件描述符的 fdinfo
+	pub fn read_process(proc_root: impl AsRef<Path>, pid: u32) -> Vec<Self> {
+		let process = proc_root.as_ref().join(pid.to_string());
+		let Ok(entries) = fs::read_dir(process.join("fdinfo")) else {
+			return Vec::new();
+		};
+		let mut fds: Vec<(u32, String)> = entries
+			.filter_map(|entry| {
+				let name = entry.ok()?.file_name().into_string().ok()?;
+				Some((name.parse().ok()?, name))
+			})
+			.collect();
+		fds.sort();
+
+		let mut clients: Vec<Self> = Vec::new();
+		for (_, fd) in fds {
+			if fs::read_link(process.join("fd").join(&fd))
+				.is_ok_and(|target| !target.starts_with("/dev/dri"))
+			{
+				continue;
+			}
+			let Some(client) = read_file(process.join("fdinfo").join(&fd))
+				.ok()
+				.and_then(|text| Self::parse(&text))
+			else {
+				continue;
+			};
+			if client.client_id.is_none()
+				|| !clients.iter().any(|other| other.key() == client.key())
+			{
+				clients.push(client);
+			}
+		}
+		clients
+	}
+
+	/// 读取 `proc_root` 中所有进程的 DRM 客户端，以进程ID为键，没有 DRM 客户端的进程不包含在内
+	pub fn read_all(proc_root: impl AsRef<Path>) -> HashMap<u32, Vec<Self>> {
+		let proc_root = proc_root.as_ref();
+		let Ok(entries) = fs::read_dir(proc_root) else {
+			return HashMap::new();
+		};
+		entries
+			.filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+			.map(|pid| (pid, Self::read_process(proc_root, pid)))
+			.filter(|(_, clients)| !clients.is_empty())
+			.collect()
+	}
+
+	/// 占用的显存(单位: 字节)
+	///
+	/// 独立显卡只统计 `vram`、`local` 等显存区域；没有显存区域的集成显卡统计所有区域
+	pub fn used_memory(&self) -> u64 {
+		let is_device_memory =
+			|region: &str| region.starts_with("vram") || region.starts_with("local");
+		if self.memory.keys().any(|region| is_device_memory(region)) {
+			self.memory
+				.iter()
+				.filter(|(region, _)| is_device_memory(region))
+				.map(|(_, size)| size)
+				.sum()
+		} else {
+			self.memory.values().sum()
+		}
+	}
+
+	/// 相对 `previous` 的使用率(%)，取各引擎中最高的一个
+	///
+	/// 忙碌时间以 `elapsed` 为基准，忙碌周期以GPU总周期为基准，并除以该类别的引擎数
+	pub fn usage_since(&self, previous: &Self, elapsed: Duration) -> Option<f32> {
+		let capacity =
+			|engine: &str| self.capacities.get(engine).copied().unwrap_or(1).max(1) as f64;
+		let elapsed = elapsed.as_nanos() as f64;
+
+		let by_time = self.engines.iter().filter(|_| elapsed > 0.0).filter_map(|(engine, busy)| {
+			let delta = busy.checked_sub(*previous.engines.get(engine)?)?;
+			Some(delta as f64 / (elapsed * capacity(engine)))
+		});
+		let by_cycles = self.cycles.iter().filter_map(|(engine, (busy, total))| {
+			let (previous_busy, previous_total) = previous.cycles.get(engine)?;
+			let busy = busy.checked_sub(*previous_busy)?;
+			let total = total.checked_sub(*previous_total).filter(|total| *total > 0)?;
+			Some(busy as f64 / (total as f64 * capacity(engine)))
+		});
+
+		by_time
+			.chain(by_cycles)
+			.reduce(f64::max)
+			.map(|usage| ((usage * 100.0).min(100.0) * 100.0).round() as f32 / 100.0)
+	}
+
+	/// 同一进程中标识同一客户端的键
+	fn key(&self) -> (&str, Option<&str>, Option<u64>) {
+		(&self.driver, self.pci_bus_id.as_deref(), self.client_id)
+	}
+
+	/// 是否报告了内存或引擎统计，只打开设备而不提供统计的驱动(如 nvidia-drm)会被忽略
+	fn has_stats(&self) -> bool {
+		!self.memory.is_empty() || !self.engines.is_empty() || !self.cycles.is_empty()
+	}
+}
+
+/// 记录最近两次刷新的GPU进程数据，用于计算 DRM 客户端的使用率
+#[derive(Debug, Default)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+pub(crate) struct GpuUsageSample {
+	nvml: HashMap<u32, Vec<ProcessGpuUsage>>,
+	previous: HashMap<u32, Vec<DrmClient>>,
+	current: HashMap<u32, Vec<DrmClient>>,
+	refreshed_at: Option<Instant>,
+	elapsed: Duration,
+}
+
+impl GpuUsageSample {
+	#[cfg_attr(not(any(target_os = "windows", target_os = "linux")), allow(unused_variables))]
+	pub(crate) fn refresh(&mut self, nvml: &NvmlHandle) {
+		#[cfg(any(target_os = "windows", target_os = "linux"))]
+		{
+			self.nvml = ProcessGpuUsage::from_gpus(&GpuInfo::detect_nvml(nvml));
+		}
+
+		let now = Instant::now();
+		self.elapsed = self.refreshed_at.map_or(Duration::ZERO, |last| now.duration_since(last));
+		self.refreshed_at = Some(now);
+		#[cfg(target_os = "linux")]
+		{
+			self.previous = std::mem::replace(&mut self.current, DrmClient::read_all("/proc"));
+		}
+	}
+
+	/// 是否已刷新过
+	pub(crate) fn is_refreshed(&self) -> bool {
+		self.refreshed_at.is_some()
+	}
+
+	/// 单次采样的数据，DRM 客户端没有上一次采样，只包含显存
+	pub(crate) fn once() -> HashMap<u32, Vec<ProcessGpuUsage>> {
+		let mut sample = Self::default();
+		sample.refresh(&NvmlHandle::default());
+		sample.usage()
+	}
+
+	/// 按进程ID汇总 NVML 与 DRM 客户端的数据，同一GPU以 NVML 为准
+	pub(crate) fn usage(&self) -> HashMap<u32, Vec<ProcessGpuUsage>> {
+		let mut usage = self.nvml.clone();
+		for (pid, clients) in &self.current {
+			let previous = self.previous.get(pid).map_or(&[][..], Vec::as_slice);
+			let entries = usage.entry(*pid).or_default();
+			for entry in ProcessGpuUsage::from_drm_clients(clients, previous, self.elapsed) {
+				if !entries.iter().any(|existing| {
+					existing.pci_bus_id.is_some() && existing.pci_bus_id == entry.pci_bus_id
+				}) {
+					entries.push(entry);
+				}
+			}
+		}
+		usage.retain(|_, entries| !entries.is_empty());
+		usage
+	}
+}
+
+impl ProcessInfo {
+	/// 进程在所有GPU上占用的显存之和，没有GPU数据时返回 None
+	pub fn gpu_memory(&self) -> Option<ByteSize> {
+		let gpus = self.gpus.as_ref()?;
+		gpus.iter().filter_map(|gpu| gpu.memory).reduce(|a, b| a + b)
+	}
+
+	/// 只读取该进程及其子进程的 DRM 客户端，不查询 NVML，也不遍历其他进程
+	///
+	/// 只有一次采样，因此只包含显存
+	pub(crate) fn attach_drm_usage(&mut self) {
+		#[cfg(target_os = "linux")]
+		{
+			let clients = DrmClient::read_process("/proc", self.pid.as_u32());
+			let usage = ProcessGpuUsage::from_drm_clients(&clients, &[], Duration::ZERO);
+			self.gpus = (!usage.is_empty()).then_some(usage);
+		}
+		for child in self.sub_list.iter_mut().flatten() {
+			child.attach_drm_usage();
+		}
+	}
+}
+
+/// 解析 `1024 KiB` 格式的大小，没有单位时为字节
+fn parse_size(value: &str) -> Option<u64> {
+	let (number, unit) = value.split_once(' ').unwrap_or((value, ""));
+	let multiplier = match unit.trim() {
+		"" => 1,
+		"KiB" => 1 << 10,
+		"MiB" => 1 << 20,
+		"GiB" => 1 << 30,
+		_ => return None,
+	};
+	number.parse::<u64>().ok()?.checked_mul(multiplier)
+}
@@ -0,0 +1,322 @@
+//! 以父子索引组织的进程树
+
+use std::collections::HashMap;
+
+#[cfg(feature = "process-regex")]
+use regex::Regex;
+use sysinfo::{Pid, System};
+
+use super::ProcessInfo;
+use crate::ByteSize;
+#[cfg(feature = "process-regex")]
+use crate::SystemInfoError;
+
+/// 进程树中同级进程的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessSort {
+	/// 按进程ID升序，即构建时的顺序
+	Pid,
+	/// 按CPU使用率降序
+	Cpu,
+	/// 按已用内存降序
+	Memory,
+}
+
+/// 进程及其所有子孙进程的资源占用之和
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProcessTotals {
+	/// 进程数，包含进程本身
+	pub processes: usize,
+	/// CPU使用率之和
+	pub cpu_usage: f32,
+	/// 已用内存之和
+	pub used_memory: ByteSize,
+}
+
+#[derive(Debug, Clone)]
+struct Node {
+	/// `sub_list` 始终为 None，子进程由 `children` 索引
+	process: ProcessInfo,
+	parent: Option<usize>,
+	children: Vec<usize>,
+}
+
+/// 进程树
+///
+/// 一次遍历所有进程建立父子索引，父进程不存在的进程为根进程。
+/// 节点中的 [ProcessInfo::sub_list] 为 None，需要嵌套结构时使用 [ProcessTree::to_nested]
+///
+/// # 示例
+///
+/// ```no_run
+/// use puniyu_system_info::{ProcessSort, ProcessTree};
+///
+/// let mut tree = ProcessTree::new();
+/// tree.sort_by(ProcessSort::Memory);
+/// for process in tree.find_by_name("nginx") {
+///     println!("{} {:?}", process.pid, tree.totals(process.pid.as_u32()));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ProcessTree {
+	nodes: Vec<Node>,
+	roots: Vec<usize>,
+	index: HashMap<Pid, usize>,
+}
+
+impl Default for ProcessTree {
+	fn default() -> Self {
+		use sysinfo::ProcessesToUpdate;
+
+		let mut system = System::new();
+		system.refresh_processes(ProcessesToUpdate::All, true);
+		system.refresh_memory();
+		Self::from_system(&system)
+	}
+}
+
+impl ProcessTree {
+	/// 读取当前所有进程并建立进程树，不包含GPU占用
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 读取当前所有进程并建立进程树，同时读取各进程的GPU占用
+	///
+	/// 需要查询 NVML 并读取所有进程的 `fdinfo`；DRM 客户端只有一次采样，因此只包含显存
+	#[cfg(feature = "gpu")]
+	pub fn with_gpu_usage() -> Self {
+		let mut tree = Self::new();
+		tree.attach_gpu_usage(&super::GpuUsageSample::once());
+		tree
+	}
+
+	/// 从已刷新进程与内存数据的 [System] 中建立进程树，同级进程按进程ID排列
+	pub(crate) fn from_system(system: &System) -> Self {
+		let total_memory = system.total_memory();
+		let mut processes: Vec<_> = system.processes().values().collect();
+		processes.sort_by_key(|process| process.pid());
+
+		Self::from_parents(processes.into_iter().map(|process| {
+			let parent = process.parent().map(Pid::as_u32);
+			(ProcessInfo::from_process(process, total_memory), parent)
+		}))
+	}
+
+	/// 从进程及其父进程ID建立进程树，同级进程保持原有顺序
+	///
+	/// 父进程不在列表中的进程为根进程；父进程关系成环(如PID复用后读到过期的父进程ID)时，
+	/// 忽略闭合该环的父进程关系，使环中的一个进程成为根进程
+	pub fn from_parents(processes: impl IntoIterator<Item = (ProcessInfo, Option<u32>)>) -> Self {
+		let mut tree = Self::empty();
+		let parents: Vec<Option<u32>> = processes
+			.into_iter()
+			.map(|(mut process, parent)| {
+				process.sub_list = None;
+				tree.push(process);
+				parent
+			})
+			.collect();
+		for (child, parent) in parents.into_iter().enumerate() {
+			let parent = parent.and_then(|pid| tree.index.get(&Pid::from_u32(pid)).copied());
+			match parent.filter(|&parent| !tree.is_ancestor(child, parent)) {
+				Some(parent) => {
+					tree.nodes[child].parent = Some(parent);
+					tree.nodes[parent].children.push(child);
+				}
+				None => tree.roots.push(child),
+			}
+		}
+		tree
+	}
+
+	/// 从 [ProcessInfo::all] 或快照中的嵌套进程列表建立进程树，保持原有顺序
+	pub fn from_processes(processes: Vec<ProcessInfo>) -> Self {
+		fn insert(tree: &mut ProcessTree, mut process: ProcessInfo, parent: Option<usize>) {
+			let children = process.sub_list.take().unwrap_or_default();
+			let index = tree.push(process);
+			tree.nodes[index].parent = parent;
+			match parent {
+				Some(parent) => tree.nodes[parent].children.push(index),
+				None => tree.roots.push(index),
+			}
+			for child in children {
+				insert(tree, child, Some(index));
+			}
+		}
+
+		let mut tree = Self::empty();
+		for process in processes {
+			insert(&mut tree, process, None);
+		}
+		tree
+	}
+
+	/// 进程总数
+	pub fn len(&self) -> usize {
+		self.nodes.len()
+	}
+
+	/// 是否没有任何进程
+	pub fn is_empty(&self) -> bool {
+		self.nodes.is_empty()
+	}
+
+	/// 根进程，即父进程不在树中的进程
+	pub fn roots(&self) -> Vec<&ProcessInfo> {
+		self.roots.iter().map(|&index| &self.nodes[index].process).collect()
+	}
+
+	/// 指定进程的直接子进程，进程不存在时返回空列表
+	pub fn children(&self, pid: u32) -> Vec<&ProcessInfo> {
+		self.node(pid).map_or_else(Vec::new, |node| {
+			node.children.iter().map(|&index| &self.nodes[index].process).collect()
+		})
+	}
+
+	/// 指定进程的父进程，根进程或进程不存在时返回 None
+	pub fn parent(&self, pid: u32) -> Option<&ProcessInfo> {
+		self.node(pid)?.parent.map(|index| &self.nodes[index].process)
+	}
+
+	/// 按深度优先顺序展开所有进程，父进程排在子进程之前
+	pub fn flatten(&self) -> Vec<&ProcessInfo> {
+		let mut flat = Vec::with_capacity(self.nodes.len());
+		let mut stack: Vec<usize> = self.roots.iter().rev().copied().collect();
+		while let Some(index) = stack.pop() {
+			let node = &self.nodes[index];
+			flat.push(&node.process);
+			stack.extend(node.children.iter().rev());
+		}
+		flat
+	}
+
+	/// 查找指定PID的进程
+	pub fn find_by_pid(&self, pid: u32) -> Option<&ProcessInfo> {
+		self.node(pid).map(|node| &node.process)
+	}
+
+	/// 查找名称完全相同的进程，按 [ProcessTree::flatten] 的顺序返回
+	pub fn find_by_name(&self, name: &str) -> Vec<&ProcessInfo> {
+		self.flatten().into_iter().filter(|process| process.name == name).collect()
+	}
+
+	/// 查找名称匹配正则表达式的进程，表达式无效时返回 [SystemInfoError::InvalidPattern]
+	#[cfg(feature = "process-regex")]
+	pub fn find_by_regex(&self, pattern: &str) -> Result<Vec<&ProcessInfo>, SystemInfoError> {
+		let regex = Regex::new(pattern).map_err(SystemInfoError::InvalidPattern)?;
+		Ok(self.flatten().into_iter().filter(|process| regex.is_match(&process.name)).collect())
+	}
+
+	/// 对根进程与每个进程的子进程排序，CPU使用率或内存相同时按进程ID排列
+	pub fn sort_by(&mut self, sort: ProcessSort) {
+		let nodes = &self.nodes;
+		let compare = |a: &usize, b: &usize| {
+			let (a, b) = (&nodes[*a].process, &nodes[*b].process);
+			match sort {
+				ProcessSort::Pid => a.pid.cmp(&b.pid),
+				ProcessSort::Cpu => {
+					b.cpu_usage.unwrap_or(0.0).total_cmp(&a.cpu_usage.unwrap_or(0.0))
+				}
+				ProcessSort::Memory => b.used_memory.cmp(&a.used_memory),
+			}
+			.then(a.pid.cmp(&b.pid))
+		};
+
+		let mut roots = std::mem::take(&mut self.roots);
+		roots.sort_by(compare);
+		let mut children: Vec<Vec<usize>> =
+			nodes.iter().map(|node| node.children.clone()).collect();
+		for list in &mut children {
+			list.sort_by(compare);
+		}
+
+		self.roots = roots;
+		for (node, list) in self.nodes.iter_mut().zip(children) {
+			node.children = list;
+		}
+	}
+
+	/// 进程及其所有子孙进程的CPU使用率与内存之和，进程不存在时返回 None
+	pub fn totals(&self, pid: u32) -> Option<ProcessTotals> {
+		let mut totals =
+			ProcessTotals { processes: 0, cpu_usage: 0.0, used_memory: ByteSize::ZERO };
+		let mut stack = vec![*self.index.get(&Pid::from_u32(pid))?];
+		while let Some(index) = stack.pop() {
+			let node = &self.nodes[index];
+			totals.processes += 1;
+			totals.cpu_usage += node.process.cpu_usage.unwrap_or(0.0);
+			totals.used_memory += node.process.used_memory;
+			stack.extend(&node.children);
+		}
+		Some(totals)
+	}
+
+	/// 只保留深度不超过 `max_depth` 的进程，根进程的深度为 0
+	pub fn limit_depth(&self, max_depth: usize) -> Self {
+		Self::from_processes(self.nested(&self.roots, Some(max_depth)))
+	}
+
+	/// 转换为以 [ProcessInfo::sub_list] 嵌套的根进程列表，与 [ProcessInfo::all] 的结构相同
+	pub fn to_nested(&self) -> Vec<ProcessInfo> {
+		self.nested(&self.roots, None)
+	}
+
+	/// 指定进程及其嵌套的子进程，与 [ProcessInfo::new] 的结构相同
+	pub fn subtree(&self, pid: u32) -> Option<ProcessInfo> {
+		let index = *self.index.get(&Pid::from_u32(pid))?;
+		self.nested(&[index], None).pop()
+	}
+
+	fn empty() -> Self {
+		Self { nodes: Vec::new(), roots: Vec::new(), index: HashMap::new() }
+	}
+
+	fn push(&mut self, process: ProcessInfo) -> usize {
+		let index = self.nodes.len();
+		self.index.insert(process.pid, index);
+		self.nodes.push(Node { process, parent: None, children: Vec::new() });
+		index
+	}
+
+	/// `ancestor` 是否为 `index` 本身或其已建立的祖先进程
+	fn is_ancestor(&self, ancestor: usize, index: usize) -> bool {
+		let mut current = Some(index);
+		while let Some(index) = current {
+			if index == ancestor {
+				return true;
+			}
+			current = self.nodes[index].parent;
+		}
+		false
+	}
+
+	fn node(&self, pid: u32) -> Option<&Node> {
+		self.index.get(&Pid::from_u32(pid)).map(|&index| &self.nodes[index])
+	}
+
+	fn nested(&self, indices: &[usize], max_depth: Option<usize>) -> Vec<ProcessInfo> {
+		indices
+			.iter()
+			.map(|&index| {
+				let node = &self.nodes[index];
+				let children = match max_depth {
+					Some(0) => Vec::new(),
+					_ => self.nested(&node.children, max_depth.map(|depth| depth - 1)),
+				};
+				ProcessInfo {
+					sub_list: (!children.is_empty()).then_some(children),
+					..node.process.clone()
+				}
+			})
+			.collect()
+	}
+
+	/// 为所有进程填充GPU数据
+	#[cfg(feature = "gpu")]
+	pub(crate) fn attach_gpu_usage(&mut self, usage: &HashMap<u32, Vec<super::ProcessGpuUsage>>) {
+		for node in &mut self.nodes {
+			node.process.gpus = usage.get(&node.process.pid.as_u32()).cloned();
+		}
+	}
+}
@@ -0,0 +1,71 @@
+//! `sysinfo` 与标准库类型的序列化格式，供 `#[serde(with = "...")]` 使用
+
+/// MAC 地址序列化为 `aa:bb:cc:dd:ee:ff` 格式的字符串
+#[cfg(feature = "network")]
+pub(crate) mod mac_addr {
+	use serde::de::Error;
+	use serde::{Deserialize, Deserializer, Serializer};
+	use sysinfo::MacAddr;
+
+	pub(crate) fn serialize<S: Serializer>(
+		mac_addr: &MacAddr,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(mac_addr)
+	}
+
+	pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<MacAddr, D::Error> {
+		let text = String::deserialize(deserializer)?;
+		let mut bytes = [0u8; 6];
+		let mut parts = text.split([':', '-']);
+		for byte in &mut bytes {
+			let part = parts.next().ok_or_else(|| D::Error::custom("mac address too short"))?;
+			*byte = u8::from_str_radix(part, 16)
+				.map_err(|_| D::Error::custom(format!("invalid mac address byte `{part}`")))?;
+		}
+		if parts.next().is_some() {
+			return Err(D::Error::custom("mac address too long"));
+		}
+		Ok(MacAddr(bytes))
+	}
+}
+
+/// IP 地址序列化为字符串，如 `192.168.1.1`、`fe80::1`，与序列化格式是否为人类可读无关
+#[cfg(feature = "network")]
+pub(crate) mod ip_addr {
+	use std::net::IpAddr;
+
+	use serde::de::Error;
+	use serde::{Deserialize, Deserializer, Serializer};
+
+	pub(crate) fn serialize<S: Serializer>(
+		ip_addr: &IpAddr,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		serializer.collect_str(ip_addr)
+	}
+
+	pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<IpAddr, D::Error> {
+		let text = String::deserialize(deserializer)?;
+		text.parse().map_err(|_| D::Error::custom(format!("invalid ip address `{text}`")))
+	}
+}
+
+/// 进程ID序列化为无符号整数
+#[cfg(feature = "process")]
+pub(crate) mod pid {
+	use serde::{Deserialize, Deserializer, Serializer};
+	use sysinfo::Pid;
+
+	pub(crate) fn serialize<S: Serializer>(pid: &Pid, serializer: S) -> Result<S::Ok, S::Error> {
+		serializer.serialize_u32(pid.as_u32())
+	}
+
+	pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pid, D::Error> {
+		u32::deserialize(deserializer).map(Pid::from_u32)
+	}
+}
@@ -0,0 +1,480 @@
+#[cfg(any(feature = "process", feature = "disk", feature = "network"))]
+use std::collections::BTreeMap;
+#[cfg(feature = "network")]
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+#[cfg(feature = "cpu")]
+use crate::CpuInfo;
+#[cfg(feature = "gpu")]
+use crate::GpuInfo;
+#[cfg(feature = "host")]
+use crate::HostInfo;
+#[cfg(feature = "load")]
+use crate::LoadInfo;
+#[cfg(feature = "memory")]
+use crate::MemoryInfo;
+#[cfg(feature = "disk")]
+use crate::{DiskDetail, DiskInfo};
+#[cfg(feature = "network")]
+use crate::{IpInfo, NetworkInfo};
+#[cfg(feature = "process")]
+use crate::{ProcessInfo, ProcessTree};
+use crate::{SystemInfo, SystemInfoError};
+
+/// 某一时刻所有已启用模块的系统信息
+///
+/// 通过 [Snapshot::new] 一次性采集，或通过 [SystemInfo::snapshot] 从已刷新的采样器中构建，
+/// 两份快照可通过 [Snapshot::diff] 比较变化。只需要部分模块时使用 [PartialSnapshot]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+	/// 采集时间(UTC)
+	pub timestamp: DateTime<Utc>,
+	/// 主机信息
+	#[cfg(feature = "host")]
+	pub host: HostInfo,
+	/// CPU信息
+	#[cfg(feature = "cpu")]
+	pub cpu: CpuInfo,
+	/// 内存信息
+	#[cfg(feature = "memory")]
+	pub memory: MemoryInfo,
+	/// 磁盘信息
+	#[cfg(feature = "disk")]
+	pub disk: DiskInfo,
+	/// 所有网卡信息
+	#[cfg(feature = "network")]
+	pub networks: Vec<NetworkInfo>,
+	/// 所有顶层进程，子进程位于各进程的 `sub_list` 中
+	#[cfg(feature = "process")]
+	pub processes: Vec<ProcessInfo>,
+	/// 所有GPU信息，未检测到GPU时为空列表
+	#[cfg(feature = "gpu")]
+	pub gpus: Vec<GpuInfo>,
+	/// 负载信息
+	#[cfg(feature = "load")]
+	pub load: LoadInfo,
+}
+
+/// 某一时刻指定模块的系统信息
+///
+/// 通过 [PartialSnapshot::with_subsystems] 一次性采集，或通过 [SystemInfo::snapshot_of] 从已刷新的采样器中构建，
+/// 未采集的模块为 `None`，序列化时省略。完整的 [Snapshot] 可通过 `From` 转换为部分快照
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialSnapshot {
+	/// 采集时间(UTC)
+	pub timestamp: DateTime<Utc>,
+	/// 主机信息
+	#[cfg(feature = "host")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub host: Option<HostInfo>,
+	/// CPU信息
+	#[cfg(feature = "cpu")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub cpu: Option<CpuInfo>,
+	/// 内存信息
+	#[cfg(feature = "memory")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub memory: Option<MemoryInfo>,
+	/// 磁盘信息
+	#[cfg(feature = "disk")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub disk: Option<DiskInfo>,
+	/// 所有网卡信息
+	#[cfg(feature = "network")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub networks: Option<Vec<NetworkInfo>>,
+	/// 所有顶层进程，子进程位于各进程的 `sub_list` 中
+	#[cfg(feature = "process")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub processes: Option<Vec<ProcessInfo>>,
+	/// 所有GPU信息，未检测到GPU时为空列表
+	#[cfg(feature = "gpu")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub gpus: Option<Vec<GpuInfo>>,
+	/// 负载信息
+	#[cfg(feature = "load")]
+	#[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+	pub load: Option<LoadInfo>,
+}
+
+/// 快照可包含的模块，与 crate 的 feature 对应
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Subsystem {
+	#[cfg(feature = "host")]
+	Host,
+	#[cfg(feature = "cpu")]
+	Cpu,
+	#[cfg(feature = "memory")]
+	Memory,
+	#[cfg(feature = "disk")]
+	Disk,
+	#[cfg(feature = "network")]
+	Network,
+	#[cfg(feature = "process")]
+	Process,
+	#[cfg(feature = "gpu")]
+	Gpu,
+	#[cfg(feature = "load")]
+	Load,
+}
+
+/// 两份快照之间的变化，由 [Snapshot::diff] 生成
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnapshotDiff {
+	/// 较早快照的采集时间
+	pub from: DateTime<Utc>,
+	/// 较新快照的采集时间
+	pub to: DateTime<Utc>,
+	/// 新启动的进程，不含子进程列表
+	#[cfg(feature = "process")]
+	pub processes_started: Vec<ProcessInfo>,
+	/// 已退出的进程，不含子进程列表
+	#[cfg(feature = "process")]
+	pub processes_exited: Vec<ProcessInfo>,
+	/// 新挂载的分区
+	#[cfg(feature = "disk")]
+	pub disks_mounted: Vec<DiskDetail>,
+	/// 已卸载的分区
+	#[cfg(feature = "disk")]
+	pub disks_unmounted: Vec<DiskDetail>,
+	/// 新出现的网卡
+	#[cfg(feature = "network")]
+	pub interfaces_up: Vec<NetworkInfo>,
+	/// 已消失的网卡
+	#[cfg(feature = "network")]
+	pub interfaces_down: Vec<NetworkInfo>,
+	/// 两份快照中都存在、但IP地址发生变化的网卡
+	#[cfg(feature = "network")]
+	pub ip_changes: Vec<IpChange>,
+	/// 内存变化
+	#[cfg(feature = "memory")]
+	pub memory: MemoryDelta,
+}
+
+/// 网卡IP地址的变化
+#[cfg(feature = "network")]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IpChange {
+	/// 网卡名称
+	pub interface: String,
+	/// 新增的IP地址
+	pub added: Vec<IpInfo>,
+	/// 移除的IP地址
+	pub removed: Vec<IpInfo>,
+}
+
+/// 内存用量的变化，正数表示增加，负数表示减少
+#[cfg(feature = "memory")]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MemoryDelta {
+	/// 总内存变化(单位: 字节)，如容器限制调整
+	pub total: i64,
+	/// 已用内存变化(单位: 字节)
+	pub used: i64,
+	/// 可用内存变化(单位: 字节)
+	pub free: i64,
+	/// 内存使用率变化(单位: 百分点)
+	pub usage: f32,
+	/// 已用交换内存变化(单位: 字节)，任一快照无交换内存数据时为 `None`
+	pub swap_used: Option<i64>,
+}
+
+impl Default for Snapshot {
+	/// 采集所有已启用模块的信息，会在内部等待一个采样间隔以获得有效的使用率与速率
+	fn default() -> Self {
+		SystemInfo::sampled(Subsystem::ALL).snapshot()
+	}
+}
+
+/// 一次性采样时两次刷新的间隔，也是持续刷新时首次输出前应等待的时间
+pub const SAMPLE_INTERVAL: Duration = Duration::from_millis(200);
+
+impl Snapshot {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// 序列化为 JSON 字符串
+	#[cfg(feature = "serde")]
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string(self)
+	}
+
+	/// 序列化为带缩进的 JSON 字符串
+	#[cfg(feature = "serde")]
+	pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+		serde_json::to_string_pretty(self)
+	}
+
+	/// 比较从当前快照到 `other` 的变化，`other` 通常为较新的快照
+	///
+	/// 进程按 PID 与启动时间识别，分区按设备名称与挂载点识别，网卡按名称识别
+	pub fn diff(&self, other: &Snapshot) -> SnapshotDiff {
+		#[cfg(feature = "process")]
+		let (processes_started, processes_exited) = {
+			// 展开进程树，以 PID 与启动时间为键，避免 PID 复用时误判
+			let index = |processes: &[ProcessInfo]| -> BTreeMap<(u32, u64), ProcessInfo> {
+				ProcessTree::from_processes(processes.to_vec())
+					.flatten()
+					.into_iter()
+					.map(|process| ((process.pid.as_u32(), process.start_time), process.clone()))
+					.collect()
+			};
+			let before = index(&self.processes);
+			let after = index(&other.processes);
+			(added(&before, &after), added(&after, &before))
+		};
+		#[cfg(feature = "disk")]
+		let (disks_mounted, disks_unmounted) = {
+			let key = |disk: &DiskDetail| (disk.name.clone(), disk.mount.clone());
+			let before = index_by(&self.disk.disks, key);
+			let after = index_by(&other.disk.disks, key);
+			(added(&before, &after), added(&after, &before))
+		};
+		#[cfg(feature = "network")]
+		let (interfaces_up, interfaces_down, ip_changes) = {
+			let key = |network: &NetworkInfo| network.name.clone();
+			let before = index_by(&self.networks, key);
+			let after = index_by(&other.networks, key);
+			let ip_changes = before
+				.iter()
+				.filter_map(|(name, previous)| {
+					IpChange::between(name, &previous.ip_info, &after.get(name)?.ip_info)
+				})
+				.collect();
+			(added(&before, &after), added(&after, &before), ip_changes)
+		};
+
+		SnapshotDiff {
+			from: self.timestamp,
+			to: other.timestamp,
+			#[cfg(feature = "process")]
+			processes_started,
+			#[cfg(feature = "process")]
+			processes_exited,
+			#[cfg(feature = "disk")]
+			disks_mounted,
+			#[cfg(feature = "disk")]
+			disks_unmounted,
+			#[cfg(feature = "network")]
+			interfaces_up,
+			#[cfg(feature = "network")]
+			interfaces_down,
+			#[cfg(feature = "network")]
+			ip_changes,
+			#[cfg(feature = "memory")]
+			memory: MemoryDelta::between(&self.memory, &other.memory),
+		}
+	}
+}
+
+impl PartialSnapshot {
+	/// 只采集指定模块，包含CPU、磁盘、网络或进程时会在内部等待一个采样间隔
+	pub fn with_subsystems(subsystems: &[Subsystem]) -> Self {
+		SystemInfo::sampled(subsystems).snapshot_of(subsystems)
+	}
+
+	/// 序列化为 JSON 字符串
+	#[cfg(feature = "serde")]
+	pub fn to_json(&self) -> serde_json::Result<String> {
+		serde_json::to_string(self)
+	}
+
+	/// 序列化为带缩进的 JSON 字符串
+	#[cfg(feature = "serde")]
+	pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+		serde_json::to_string_pretty(self)
+	}
+}
+
+impl From<Snapshot> for PartialSnapshot {
+	fn from(snapshot: Snapshot) -> Self {
+		Self {
+			timestamp: snapshot.timestamp,
+			#[cfg(feature = "host")]
+			host: Some(snapshot.host),
+			#[cfg(feature = "cpu")]
+			cpu: Some(snapshot.cpu),
+			#[cfg(feature = "memory")]
+			memory: Some(snapshot.memory),
+			#[cfg(feature = "disk")]
+			disk: Some(snapshot.disk),
+			#[cfg(feature = "network")]
+			networks: Some(snapshot.networks),
+			#[cfg(feature = "process")]
+			processes: Some(snapshot.processes),
+			#[cfg(feature = "gpu")]
+			gpus: Some(snapshot.gpus),
+			#[cfg(feature = "load")]
+			load: Some(snapshot.load),
+		}
+	}
+}
+
+impl Subsystem {
+	/// 所有已启用 feature 对应的模块
+	pub const ALL: &[Subsystem] = &[
+		#[cfg(feature = "host")]
+		Self::Host,
+		#[cfg(feature = "cpu")]
+		Self::Cpu,
+		#[cfg(feature = "memory")]
+		Self::Memory,
+		#[cfg(feature = "disk")]
+		Self::Disk,
+		#[cfg(feature = "network")]
+		Self::Network,
+		#[cfg(feature = "process")]
+		Self::Process,
+		#[cfg(feature = "gpu")]
+		Self::Gpu,
+		#[cfg(feature = "load")]
+		Self::Load,
+	];
+
+	/// 模块名称，与 feature 名称相同
+	pub fn as_str(&self) -> &'static str {
+		match *self {
+			#[cfg(feature = "host")]
+			Self::Host => "host",
+			#[cfg(feature = "cpu")]
+			Self::Cpu => "cpu",
+			#[cfg(feature = "memory")]
+			Self::Memory => "memory",
+			#[cfg(feature = "disk")]
+			Self::Disk => "disk",
+			#[cfg(feature = "network")]
+			Self::Network => "network",
+			#[cfg(feature = "process")]
+			Self::Process => "process",
+			#[cfg(feature = "gpu")]
+			Self::Gpu => "gpu",
+			#[cfg(feature = "load")]
+			Self::Load => "load",
+		}
+	}
+
+	/// 使用率或速率需要两次刷新之间的差值计算，首次读取前需等待 [SAMPLE_INTERVAL] 后再刷新一次
+	pub fn is_sampled(&self) -> bool {
+		match *self {
+			#[cfg(feature = "cpu")]
+			Self::Cpu => true,
+			#[cfg(feature = "disk")]
+			Self::Disk => true,
+			#[cfg(feature = "network")]
+			Self::Network => true,
+			#[cfg(feature = "process")]
+			Self::Process => true,
+			#[allow(unreachable_patterns)]
+			_ => false,
+		}
+	}
+}
+
+impl fmt::Display for Subsystem {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl FromStr for Subsystem {
+	type Err = SystemInfoError;
+
+	/// 按名称解析模块，名称不区分大小写，未知或未启用对应 feature 时返回
+	/// [SystemInfoError::UnknownSubsystem]
+	fn from_str(name: &str) -> Result<Self, Self::Err> {
+		Self::ALL
+			.iter()
+			.find(|subsystem| subsystem.as_str().eq_ignore_ascii_case(name.trim()))
+			.copied()
+			.ok_or_else(|| SystemInfoError::UnknownSubsystem(name.to_string()))
+	}
+}
+
+/// 解析以秒为单位的刷新间隔，可以为小数，如 `0.5`
+///
+/// 不是正的有限数值时返回 [SystemInfoError::InvalidInterval]
+pub fn parse_interval(value: &str) -> Result<Duration, SystemInfoError> {
+	match value.trim().parse::<f64>() {
+		Ok(secs) if secs.is_finite() && secs > 0.0 => Ok(Duration::from_secs_f64(secs)),
+		_ => Err(SystemInfoError::InvalidInterval(value.to_string())),
+	}
+}
+
+impl SnapshotDiff {
+	/// 进程、分区、网卡与IP地址均无变化，不考虑内存用量的变化
+	pub fn is_empty(&self) -> bool {
+		let empty = true;
+		#[cfg(feature = "process")]
+		let empty = empty && self.processes_started.is_empty() && self.processes_exited.is_empty();
+		#[cfg(feature = "disk")]
+		let empty = empty && self.disks_mounted.is_empty() && self.disks_unmounted.is_empty();
+		#[cfg(feature = "network")]
+		let empty = empty
+			&& self.interfaces_up.is_empty()
+			&& self.interfaces_down.is_empty()
+			&& self.ip_changes.is_empty();
+		empty
+	}
+}
+
+#[cfg(feature = "network")]
+impl IpChange {
+	fn between(interface: &str, before: &[IpInfo], after: &[IpInfo]) -> Option<Self> {
+		let key = |ip: &IpInfo| (ip.ip_address, ip.netmask);
+		let before_keys: BTreeSet<_> = before.iter().map(key).collect();
+		let after_keys: BTreeSet<_> = after.iter().map(key).collect();
+		let added: Vec<IpInfo> =
+			after.iter().filter(|ip| !before_keys.contains(&key(ip))).cloned().collect();
+		let removed: Vec<IpInfo> =
+			before.iter().filter(|ip| !after_keys.contains(&key(ip))).cloned().collect();
+		if added.is_empty() && removed.is_empty() {
+			None
+		} else {
+			Some(Self { interface: interface.to_string(), added, removed })
+		}
+	}
+}
+
+#[cfg(feature = "memory")]
+impl MemoryDelta {
+	fn between(before: &MemoryInfo, after: &MemoryInfo) -> Self {
+		let delta = |before: crate::ByteSize, after: crate::ByteSize| {
+			after.as_bytes() as i64 - before.as_bytes() as i64
+		};
+		Self {
+			total: delta(before.total, after.total),
+			used: delta(before.used, after.used),
+			free: delta(before.free, after.free),
+			usage: crate::round((after.usage - before.usage) as f64) as f32,
+			swap_used: before.swap_used.zip(after.swap_used).map(|(b, a)| delta(b, a)),
+		}
+	}
+}
+
+#[cfg(any(feature = "disk", feature = "network"))]
+fn index_by<K: Ord, T: Clone>(items: &[T], key: impl Fn(&T) -> K) -> BTreeMap<K, T> {
+	items.iter().map(|item| (key(item), item.clone())).collect()
+}
+
+/// `after` 中存在而 `before` 中不存在的项
+#[cfg(any(feature = "process", feature = "disk", feature = "network"))]
+fn added<K: Ord, T: Clone>(before: &BTreeMap<K, T>, after: &BTreeMap<K, T>) -> Vec<T> {
+	after
+		.iter()
+		.filter(|(key, _)| !before.contains_key(key))
+		.map(|(_, item)| item.clone())
+		.collect()
+}
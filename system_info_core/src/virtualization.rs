@@ -0,0 +1,163 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::error::read_file;
+
+/// 运行环境的容器或虚拟化类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Virtualization {
+	/// 未检测到容器或虚拟化，非 Linux 下始终为该值
+	#[default]
+	None,
+	/// Docker 容器
+	Docker,
+	/// Podman 容器
+	Podman,
+	/// LXC 容器
+	Lxc,
+	/// 其他容器，如 Kubernetes 下的 containerd、CRI-O
+	Container,
+	/// Windows Subsystem for Linux
+	Wsl,
+	/// KVM / QEMU 虚拟机
+	Kvm,
+	/// Xen 虚拟机
+	Xen,
+	/// VMware 虚拟机
+	VMware,
+	/// Hyper-V 虚拟机
+	#[cfg_attr(feature = "serde", serde(rename = "hyper-v"))]
+	HyperV,
+	/// 其他虚拟机，CPU 报告了 hypervisor 标志但无法识别厂商
+	#[cfg_attr(feature = "serde", serde(rename = "vm"))]
+	VirtualMachine,
+}
+
+impl Virtualization {
+	/// 检测当前运行环境
+	pub fn detect() -> Self {
+		#[cfg(target_os = "linux")]
+		{
+			Self::detect_from_root("/")
+		}
+
+		#[cfg(not(target_os = "linux"))]
+		{
+			Self::None
+		}
+	}
+
+	/// 以 `root` 为文件系统根目录检测运行环境
+	///
+	/// 依次检查容器标记文件、`/proc/1/cgroup`、WSL 内核版本、DMI 厂商信息与 `/proc/cpuinfo` 的 hypervisor 标志，
+	/// 容器优先于虚拟机返回
+	pub fn detect_from_root(root: impl AsRef<Path>) -> Self {
+		let root = root.as_ref();
+		let read = |path: &str| read_file(root.join(path)).unwrap_or_default();
+
+		if root.join(".dockerenv").exists() {
+			return Self::Docker;
+		}
+		if root.join("run/.containerenv").exists() {
+			return Self::Podman;
+		}
+		if let Some(container) = Self::from_cgroup(&read("proc/1/cgroup")) {
+			return container;
+		}
+
+		let os_release = read("proc/sys/kernel/osrelease").to_lowercase();
+		if os_release.contains("microsoft") || os_release.contains("wsl") {
+			return Self::Wsl;
+		}
+
+		if let Some(hypervisor) = Self::from_dmi(
+			&read("sys/class/dmi/id/sys_vendor"),
+			&read("sys/class/dmi/id/product_name"),
+		) {
+			return hypervisor;
+		}
+		let has_hypervisor_flag = read("proc/cpuinfo")
+			.lines()
+			.filter(|line| line.starts_with("flags"))
+			.any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"));
+		if has_hypervisor_flag {
+			return Self::VirtualMachine;
+		}
+		Self::None
+	}
+
+	/// 根据 `/proc/1/cgroup` 的内容判断容器类型
+	fn from_cgroup(text: &str) -> Option<Self> {
+		let paths = text.lines().filter_map(|line| line.splitn(3, ':').nth(2));
+		let mut container = None;
+		for path in paths {
+			if path.contains("docker") {
+				return Some(Self::Docker);
+			}
+			if path.contains("libpod") {
+				return Some(Self::Podman);
+			}
+			if path.contains("lxc") {
+				return Some(Self::Lxc);
+			}
+			if path.contains("kubepods") || path.contains("containerd") || path.contains("crio") {
+				container = Some(Self::Container);
+			}
+		}
+		container
+	}
+
+	/// 根据 DMI 的 `sys_vendor` 与 `product_name` 判断虚拟机类型
+	fn from_dmi(sys_vendor: &str, product_name: &str) -> Option<Self> {
+		let (vendor, product) = (sys_vendor.trim(), product_name.trim());
+		if vendor == "QEMU" || product.starts_with("KVM") || product.contains("QEMU") {
+			Some(Self::Kvm)
+		} else if vendor == "Xen" || product.starts_with("HVM domU") {
+			Some(Self::Xen)
+		} else if vendor.starts_with("VMware") || product.starts_with("VMware") {
+			Some(Self::VMware)
+		} else if vendor == "Microsoft Corporation" && product == "Virtual Machine" {
+			Some(Self::HyperV)
+		} else {
+			None
+		}
+	}
+
+	/// 是否运行在容器中
+	pub fn is_container(&self) -> bool {
+		matches!(self, Self::Docker | Self::Podman | Self::Lxc | Self::Container)
+	}
+
+	/// 是否运行在虚拟机中(含 WSL)
+	pub fn is_virtual_machine(&self) -> bool {
+		matches!(
+			self,
+			Self::Wsl | Self::Kvm | Self::Xen | Self::VMware | Self::HyperV | Self::VirtualMachine
+		)
+	}
+
+	/// 类型名称，如 `"docker"`、`"kvm"`，未检测到时为 `"none"`
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::None => "none",
+			Self::Docker => "docker",
+			Self::Podman => "podman",
+			Self::Lxc => "lxc",
+			Self::Container => "container",
+			Self::Wsl => "wsl",
+			Self::Kvm => "kvm",
+			Self::Xen => "xen",
+			Self::VMware => "vmware",
+			Self::HyperV => "hyper-v",
+			Self::VirtualMachine => "vm",
+		}
+	}
+}
+
+impl fmt::Display for Virtualization {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
@@ -0,0 +1,60 @@
+use puniyu_system_info::{ByteSize, UnitSystem};
+
+#[test]
+fn test_byte_size_conversion() {
+	let size = ByteSize::from_mib(1536);
+
+	assert_eq!(size.as_bytes(), 1536 * 1024 * 1024);
+	assert_eq!(size.as_mib(), 1536.0);
+	assert_eq!(size.as_gib(), 1.5);
+	assert_eq!(size.as_kb(), 1_610_612.736);
+	assert_eq!(ByteSize::from_bytes(500_000_000).as_mb(), 500.0);
+	assert_eq!(ByteSize::from_bytes(2_000_000_000).as_gb(), 2.0);
+	assert_eq!(ByteSize::from_kib(2).as_bytes(), 2048);
+	// 旧版本的 MB / GB 字段按 1024 进制换算并向下取整，与十进制的 as_mb 不同
+	assert_eq!(ByteSize::from_bytes(500_000_000).whole_mib(), 476);
+	assert_eq!(ByteSize::from_bytes(500_000_000).whole_gib(), 0);
+	assert_eq!(ByteSize::from_mib(1536).whole_gib(), 1);
+}
+
+#[test]
+fn test_byte_size_display() {
+	let size = ByteSize::from_mib(1536);
+
+	assert_eq!(size.to_string(), "1.50 GiB");
+	assert_eq!(format!("{:.1}", size), "1.5 GiB");
+	assert_eq!(size.display(UnitSystem::Si).to_string(), "1.61 GB");
+	assert_eq!(ByteSize::from_bytes(512).to_string(), "512 B");
+	assert_eq!(ByteSize::from_bytes(1000).display(UnitSystem::Si).to_string(), "1.00 kB");
+	assert_eq!(ByteSize::from_bytes(1000).to_string(), "1000 B");
+	assert_eq!(ByteSize::from_mib(500).to_string(), "500.00 MiB");
+}
+
+#[test]
+fn test_byte_size_arithmetic() {
+	let total = ByteSize::from_gib(2);
+	let used = ByteSize::from_mib(512);
+
+	assert_eq!(total - used, ByteSize::from_mib(1536));
+	assert_eq!(used.saturating_sub(total), ByteSize::ZERO);
+	// 两次采样的差值可能为负，`-` 不会溢出
+	assert_eq!(used - total, ByteSize::ZERO);
+	assert_eq!(total.checked_sub(used), Some(ByteSize::from_mib(1536)));
+	assert_eq!(used.checked_sub(total), None);
+	assert_eq!(used.percent_of(total), 25.0);
+	assert_eq!(used.percent_of(ByteSize::ZERO), 0.0);
+	assert_eq!([total, used].into_iter().sum::<ByteSize>(), ByteSize::from_mib(2560));
+}
+
+#[test]
+fn test_rate_and_duration_display() {
+	use puniyu_system_info::format_duration;
+
+	assert_eq!(ByteSize::display_rate(1536.0 * 1024.0).to_string(), "1.50 MiB/s");
+	assert_eq!(format!("{:.1}", ByteSize::display_rate(1536.0 * 1024.0)), "1.5 MiB/s");
+	assert_eq!(ByteSize::display_rate(512.9).to_string(), "512 B/s");
+	assert_eq!(ByteSize::display_rate(0.0).to_string(), "0 B/s");
+
+	assert_eq!(format_duration(0), "00:00:00");
+	assert_eq!(format_duration(3 * 86_400 + 4 * 3600 + 5 * 60 + 6), "3d 04:05:06");
+}
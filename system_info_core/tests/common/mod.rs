@@ -0,0 +1,12 @@
+//! 集成测试共用的辅助函数
+#![allow(dead_code)]
+
+/// `tests/fixtures` 下文件或目录的绝对路径
+pub fn fixture_path(name: &str) -> String {
+	format!("{}/tests/fixtures/{name}", env!("CARGO_MANIFEST_DIR"))
+}
+
+/// 读取 `tests/fixtures` 下的文本文件
+pub fn read_fixture(name: &str) -> String {
+	std::fs::read_to_string(fixture_path(name)).expect("fixture should exist")
+}
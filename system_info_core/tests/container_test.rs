@@ -0,0 +1,116 @@
+#![cfg(any(feature = "cpu", feature = "memory"))]
+
+use std::time::Duration;
+
+use puniyu_system_info::{CgroupVersion, ContainerInfo};
+
+mod common;
+
+#[test]
+fn test_cgroup_v2() {
+	let container =
+		ContainerInfo::from_root(common::fixture_path("cgroup_v2")).expect("cgroup v2 root");
+
+	assert_eq!(container.version, CgroupVersion::V2);
+	assert_eq!(container.memory_limit, Some(512 * 1024 * 1024));
+	assert_eq!(container.memory_usage, Some(256 * 1024 * 1024));
+	assert_eq!(container.memory_working_set(), Some(192 * 1024 * 1024));
+	assert_eq!(container.cpu_quota, Some(1.5));
+	assert_eq!(container.cpuset, Some(vec![0, 1, 2, 3]));
+	assert_eq!(container.effective_cpus(), Some(1.5));
+	assert_eq!(container.cpu_usage_usec, Some(3_000_000));
+}
+
+#[test]
+fn test_cgroup_v2_nested() {
+	let self_cgroup = "0::/system.slice/app.service\n";
+	let container =
+		ContainerInfo::from_root_with_cgroup(common::fixture_path("cgroup_v2"), self_cgroup)
+			.unwrap();
+
+	// 所在 cgroup 未设限制，取 system.slice 的内存上限与根目录的CPU配额
+	assert_eq!(container.memory_limit, Some(256 * 1024 * 1024));
+	assert_eq!(container.memory_usage, Some(1024 * 1024));
+	assert_eq!(container.cpu_quota, Some(1.5));
+	assert_eq!(container.cpuset, Some(vec![2, 5]));
+	assert_eq!(container.effective_cpus(), Some(1.5));
+	assert_eq!(container.cpu_usage_usec, Some(42));
+}
+
+#[test]
+fn test_cgroup_v1() {
+	let container =
+		ContainerInfo::from_root(common::fixture_path("cgroup_v1")).expect("cgroup v1 root");
+
+	assert_eq!(container.version, CgroupVersion::V1);
+	assert_eq!(container.memory_limit, Some(1024 * 1024 * 1024));
+	assert_eq!(container.memory_working_set(), Some(512 * 1024 * 1024));
+	assert_eq!(container.cpu_quota, Some(2.0));
+	assert_eq!(container.cpuset.as_ref().map(Vec::len), Some(8));
+	assert_eq!(container.effective_cpus(), Some(2.0));
+	assert_eq!(container.cpu_usage_usec, Some(5_000_000));
+}
+
+#[test]
+fn test_cgroup_v1_nested() {
+	let self_cgroup = "5:memory:/kubepods/pod1/app\n4:cpu,cpuacct:/kubepods/pod1/app\n";
+	let container =
+		ContainerInfo::from_root_with_cgroup(common::fixture_path("cgroup_v1"), self_cgroup)
+			.unwrap();
+
+	// 容器未设限制，取 Pod 级 cgroup 的上限；cpu 层级中不存在该目录，回退到根目录
+	assert_eq!(container.memory_limit, Some(256 * 1024 * 1024));
+	assert_eq!(container.memory_usage, Some(100 * 1024 * 1024));
+	assert_eq!(container.cpu_quota, Some(2.0));
+}
+
+#[test]
+fn test_cgroup_missing_root() {
+	assert!(ContainerInfo::from_root(common::fixture_path("cgroup_missing")).is_none());
+}
+
+#[test]
+fn test_container_cpu_usage() {
+	let current = ContainerInfo::from_root(common::fixture_path("cgroup_v2")).unwrap();
+	let previous = ContainerInfo { cpu_usage_usec: Some(1_500_000), ..current.clone() };
+
+	// 1.5 秒CPU时间 / (2 秒 * 1.5 核)
+	let usage = current.cpu_usage_since(&previous, Duration::from_secs(2));
+	assert_eq!(usage, Some(50.0));
+	assert_eq!(current.cpu_usage_since(&previous, Duration::ZERO), None);
+}
+
+#[cfg(feature = "cpu")]
+#[test]
+fn test_cpu_with_container() {
+	use puniyu_system_info::CpuInfo;
+
+	let current = ContainerInfo::from_root(common::fixture_path("cgroup_v2")).unwrap();
+	let previous = ContainerInfo { cpu_usage_usec: Some(1_500_000), ..current.clone() };
+	let host = CpuInfo::new();
+	let cpu_info = host.clone().with_container(&current, Some((&previous, Duration::from_secs(2))));
+
+	assert_eq!(cpu_info.logical_cores, host.logical_cores.min(2));
+	assert_eq!(cpu_info.usage, Some(50.0));
+	assert_eq!(host.clone().with_container(&current, None).usage, host.usage);
+}
+
+#[cfg(feature = "memory")]
+#[test]
+fn test_memory_with_container() {
+	use puniyu_system_info::{ByteSize, MemoryInfo};
+
+	let container = ContainerInfo::from_root(common::fixture_path("cgroup_v2")).unwrap();
+	let host = MemoryInfo::new();
+	let memory_info = host.clone().with_container(&container);
+
+	if host.total > ByteSize::from_mib(512) {
+		assert_eq!(memory_info.total, ByteSize::from_mib(512));
+		assert_eq!(memory_info.used, ByteSize::from_mib(192));
+		assert_eq!(memory_info.free, ByteSize::from_mib(320));
+		assert_eq!(memory_info.usage, 37.5);
+	} else {
+		assert_eq!(memory_info.total, host.total);
+	}
+	assert_eq!(memory_info.swap_total, host.swap_total);
+}
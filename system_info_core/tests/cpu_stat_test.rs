@@ -0,0 +1,62 @@
+#![cfg(feature = "cpu")]
+
+use puniyu_system_info::{CpuStat, CpuTimePercent};
+
+mod common;
+
+fn parse_fixture(name: &str) -> CpuStat {
+	CpuStat::parse(&common::read_fixture(name)).expect("fixture should parse")
+}
+
+#[test]
+fn test_parse_proc_stat() {
+	let stat = parse_fixture("proc_stat_1");
+
+	assert_eq!(stat.total.user, 10000);
+	assert_eq!(stat.total.idle, 80000);
+	assert_eq!(stat.total.iowait, 500);
+	assert_eq!(stat.cores.len(), 2);
+	assert_eq!(stat.cores[&1].system, 1500);
+}
+
+#[test]
+fn test_cpu_time_percent() {
+	let previous = parse_fixture("proc_stat_1");
+	let current = parse_fixture("proc_stat_2");
+
+	// 总增量: user 400 + system 100 + idle 300 + iowait 100 + steal 100 = 1000
+	let total = current.total.percent_since(&previous.total).expect("time should elapse");
+	assert_eq!(
+		total,
+		CpuTimePercent {
+			user: 40.0,
+			nice: 0.0,
+			system: 10.0,
+			idle: 30.0,
+			iowait: 10.0,
+			irq: 0.0,
+			softirq: 0.0,
+			steal: 10.0,
+			guest: 20.0,
+		}
+	);
+
+	let core1 = current.cores[&1].percent_since(&previous.cores[&1]).expect("time should elapse");
+	assert_eq!(core1.user, 20.0);
+	assert_eq!(core1.system, 10.0);
+	assert_eq!(core1.idle, 50.0);
+	assert_eq!(core1.iowait, 20.0);
+}
+
+#[test]
+fn test_cpu_time_percent_without_elapsed_time() {
+	let stat = parse_fixture("proc_stat_1");
+	assert!(stat.total.percent_since(&stat.total).is_none());
+}
+
+#[test]
+fn test_parse_invalid_proc_stat() {
+	assert!(CpuStat::parse("intr 1 2 3\n").is_err());
+	assert!(CpuStat::parse("cpu  1 2\n").is_err());
+	assert!(CpuStat::parse("cpu  a b c d\n").is_err());
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "disk")]
+
+use std::time::Duration;
+
+use puniyu_system_info::DiskStats;
+
+mod common;
+
+fn parse_fixture(name: &str) -> DiskStats {
+	DiskStats::parse(&common::read_fixture(name)).expect("fixture should parse")
+}
+
+#[test]
+fn test_parse_diskstats() {
+	let stats = parse_fixture("proc_diskstats_1");
+
+	assert_eq!(stats.devices.len(), 5);
+	let nvme = &stats.devices["nvme0n1"];
+	assert_eq!(nvme.reads_completed, 120000);
+	assert_eq!(nvme.sectors_read, 9600000);
+	assert_eq!(nvme.write_time, 160000);
+	assert_eq!(nvme.in_flight, 2);
+	assert_eq!(nvme.io_time, 90000);
+	assert_eq!(stats.devices["sda"].writes_completed, 2000);
+}
+
+#[test]
+fn test_disk_io_since() {
+	let previous = parse_fixture("proc_diskstats_1");
+	let current = parse_fixture("proc_diskstats_2");
+	let io =
+		current.devices["nvme0n1"].io_since(&previous.devices["nvme0n1"], Duration::from_secs(1));
+
+	assert_eq!(io.reads_completed, 400);
+	assert_eq!(io.writes_completed, 100);
+	assert_eq!(io.sectors_read, 409600);
+	assert_eq!(io.read_speed, 204800.0);
+	assert_eq!(io.write_speed, 102400.0);
+	assert_eq!(io.read_bytes_per_second, 209715200.0);
+	assert_eq!(io.io_time, 500);
+	assert_eq!(io.in_flight, 3);
+	assert_eq!(io.utilization, 50.0);
+	assert_eq!(io.read_latency, Some(2.0));
+	assert_eq!(io.write_latency, Some(6.0));
+
+	let idle = current.devices["sda"].io_since(&previous.devices["sda"], Duration::from_secs(1));
+	assert_eq!(idle.read_speed, 0.0);
+	assert_eq!(idle.utilization, 0.0);
+	assert_eq!(idle.read_latency, None);
+}
+
+#[test]
+fn test_disk_io_bytes_per_second_not_rounded() {
+	let previous = parse_fixture("proc_diskstats_1");
+	let current = parse_fixture("proc_diskstats_2");
+	let io =
+		current.devices["nvme0n1"].io_since(&previous.devices["nvme0n1"], Duration::from_secs(3));
+
+	// 字节速度直接由扇区增量计算，不经过取整后的 KB/S
+	assert_eq!(io.read_bytes_per_second, 409600.0 * 512.0 / 3.0);
+	assert_ne!(io.read_bytes_per_second, io.read_speed as f64 * 1024.0);
+}
+
+#[test]
+fn test_parse_invalid_diskstats() {
+	assert!(DiskStats::parse("").unwrap().devices.is_empty());
+	assert!(DiskStats::parse("8 0 sda 1 2 3\n").is_err());
+	assert!(DiskStats::parse("8 0 sda 1 2 3 4 5 6 7 x 9 10 11\n").is_err());
+}
@@ -0,0 +1,187 @@
+#![cfg(feature = "gpu")]
+
+use puniyu_system_info::{ByteSize, GpuInfo, GpuProcess, NvmlMemory, NvmlPciInfo, NvmlSource};
+
+mod common;
+
+#[test]
+fn test_sysfs_cards() {
+	let gpus = GpuInfo::from_sysfs(common::fixture_path("sysfs"));
+
+	// 显示接口、render 节点与非 PCI 设备被跳过，card10 排在 card1 之后
+	let bus_ids: Vec<_> = gpus.iter().map(|gpu| gpu.pci_bus_id.as_deref().unwrap()).collect();
+	assert_eq!(bus_ids, ["0000:00:02.0", "0000:03:00.0", "0000:0a:00.0"]);
+	assert_eq!(gpus.iter().map(|gpu| gpu.index).collect::<Vec<_>>(), [0, 1, 2]);
+
+	let intel = &gpus[0];
+	assert_eq!(intel.model, "Intel Device 3e92");
+	assert_eq!(intel.vendor.as_deref(), Some("Intel"));
+	assert_eq!((intel.vendor_id, intel.device_id), (Some(0x8086), Some(0x3e92)));
+	assert_eq!(intel.driver.as_deref(), Some("i915"));
+	assert_eq!(intel.memory_total, None);
+	assert_eq!(intel.usage, None);
+	assert_eq!(intel.temperature, None);
+
+	let amd = &gpus[1];
+	assert_eq!(amd.vendor.as_deref(), Some("AMD"));
+	assert_eq!(amd.driver.as_deref(), Some("amdgpu"));
+	assert_eq!(amd.memory_total, Some(ByteSize::from_bytes(17_163_091_968)));
+	assert_eq!(amd.memory_used, Some(ByteSize::from_gib(1)));
+	assert_eq!(amd.memory_free, Some(ByteSize::from_bytes(17_163_091_968 - (1 << 30))));
+	assert_eq!(amd.usage, Some(37));
+	assert_eq!(amd.temperature, Some(45.0));
+
+	let nvidia = &gpus[2];
+	assert_eq!(nvidia.model, "NVIDIA Device 2204");
+	assert_eq!(nvidia.driver.as_deref(), Some("nouveau"));
+}
+
+#[test]
+fn test_sysfs_missing_root() {
+	assert!(GpuInfo::from_sysfs(common::fixture_path("sysfs_missing")).is_empty());
+}
+
+/// 模拟三块显卡：第一块支持所有读数，第二块只能读取名称与 PCI 信息，第三块无法读取名称
+struct MockNvml;
+
+impl MockNvml {
+	fn full(index: u32, value: u32) -> Option<u32> {
+		(index == 0).then_some(value)
+	}
+}
+
+impl NvmlSource for MockNvml {
+	fn device_count(&self) -> u32 {
+		3
+	}
+
+	fn driver_version(&self) -> Option<String> {
+		Some("535.104.05".to_string())
+	}
+
+	fn cuda_version(&self) -> Option<i32> {
+		Some(12020)
+	}
+
+	fn name(&self, index: u32) -> Option<String> {
+		(index < 2).then(|| format!("NVIDIA GeForce RTX 309{index}"))
+	}
+
+	fn pci_info(&self, index: u32) -> Option<NvmlPciInfo> {
+		Some(NvmlPciInfo {
+			bus_id: format!("00000000:0{}:00.0", index + 1),
+			vendor_id: 0x10de,
+			device_id: 0x2204,
+		})
+	}
+
+	fn memory(&self, index: u32) -> Option<NvmlMemory> {
+		(index == 0).then_some(NvmlMemory { total: 24 << 30, used: 1 << 30, free: 23 << 30 })
+	}
+
+	fn gpu_utilization(&self, index: u32) -> Option<u32> {
+		Self::full(index, 35)
+	}
+
+	fn memory_utilization(&self, index: u32) -> Option<u32> {
+		Self::full(index, 12)
+	}
+
+	fn encoder_utilization(&self, index: u32) -> Option<u32> {
+		Self::full(index, 0)
+	}
+
+	fn decoder_utilization(&self, index: u32) -> Option<u32> {
+		Self::full(index, 3)
+	}
+
+	fn temperature(&self, index: u32) -> Option<u32> {
+		Self::full(index, 61)
+	}
+
+	fn fan_speed(&self, index: u32) -> Option<u32> {
+		Self::full(index, 40)
+	}
+
+	fn power_usage(&self, index: u32) -> Option<u32> {
+		Self::full(index, 112_500)
+	}
+
+	fn power_limit(&self, index: u32) -> Option<u32> {
+		Self::full(index, 350_000)
+	}
+
+	fn graphics_clock(&self, index: u32) -> Option<u32> {
+		Self::full(index, 1695)
+	}
+
+	fn memory_clock(&self, index: u32) -> Option<u32> {
+		Self::full(index, 9751)
+	}
+
+	fn pcie_tx(&self, index: u32) -> Option<u32> {
+		Self::full(index, 2048)
+	}
+
+	fn pcie_rx(&self, index: u32) -> Option<u32> {
+		Self::full(index, 512)
+	}
+
+	fn processes(&self, index: u32) -> Option<Vec<GpuProcess>> {
+		(index == 0).then(|| {
+			vec![
+				GpuProcess {
+					pid: 4242,
+					used_memory: Some(ByteSize::from_mib(512)),
+					usage: Some(27),
+				},
+				GpuProcess { pid: 4343, used_memory: None, usage: None },
+			]
+		})
+	}
+}
+
+#[test]
+fn test_nvml_telemetry() {
+	let gpus = GpuInfo::from_nvml(&MockNvml);
+	// 无法读取名称的设备被跳过
+	assert_eq!(gpus.len(), 2);
+
+	let gpu = &gpus[0];
+	assert_eq!(gpu.index, 0);
+	assert_eq!(gpu.model, "NVIDIA GeForce RTX 3090");
+	assert_eq!(gpu.vendor.as_deref(), Some("NVIDIA"));
+	assert_eq!(gpu.pci_bus_id.as_deref(), Some("0000:01:00.0"));
+	assert_eq!(gpu.driver.as_deref(), Some("nvidia"));
+	assert_eq!(gpu.driver_version.as_deref(), Some("535.104.05"));
+	assert_eq!(gpu.cuda_version.as_deref(), Some("12.2"));
+	assert_eq!(gpu.memory_used, Some(ByteSize::from_gib(1)));
+	assert_eq!(gpu.usage, Some(35));
+	assert_eq!(gpu.memory_controller_usage, Some(12));
+	assert_eq!((gpu.encoder_usage, gpu.decoder_usage), (Some(0), Some(3)));
+	assert_eq!(gpu.temperature, Some(61.0));
+	assert_eq!(gpu.fan_speed, Some(40));
+	assert_eq!((gpu.power_usage, gpu.power_limit), (Some(112.5), Some(350.0)));
+	assert_eq!((gpu.graphics_clock, gpu.memory_clock), (Some(1695), Some(9751)));
+	assert_eq!((gpu.pcie_tx, gpu.pcie_rx), (Some(2048), Some(512)));
+	let processes = gpu.processes.as_deref().unwrap();
+	assert_eq!(processes.len(), 2);
+	assert_eq!(processes[1].used_memory, None);
+}
+
+#[test]
+fn test_nvml_partial_failure() {
+	let gpus = GpuInfo::from_nvml(&MockNvml);
+
+	// 读取失败的计数器为 None，不影响其余字段
+	let gpu = &gpus[1];
+	assert_eq!(gpu.index, 1);
+	assert_eq!(gpu.pci_bus_id.as_deref(), Some("0000:02:00.0"));
+	assert_eq!(gpu.device_id, Some(0x2204));
+	assert_eq!(gpu.cuda_version.as_deref(), Some("12.2"));
+	assert_eq!(gpu.memory_total, None);
+	assert_eq!(gpu.usage, None);
+	assert_eq!(gpu.power_usage, None);
+	assert_eq!(gpu.graphics_clock, None);
+	assert_eq!(gpu.processes, None);
+}
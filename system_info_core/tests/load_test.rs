@@ -0,0 +1,55 @@
+#![cfg(feature = "load")]
+
+use puniyu_system_info::{LoadInfo, Pressure, PressureStat};
+
+mod common;
+
+#[test]
+fn test_parse_loadavg() {
+	let load_info =
+		LoadInfo::parse(&common::read_fixture("proc_loadavg")).expect("fixture should parse");
+
+	assert_eq!(load_info.one, 0.52);
+	assert_eq!(load_info.five, 0.58);
+	assert_eq!(load_info.fifteen, 0.59);
+	assert_eq!(load_info.running_tasks, Some(2));
+	assert_eq!(load_info.total_tasks, Some(1234));
+	assert!(load_info.pressure.is_none());
+}
+
+#[test]
+fn test_parse_invalid_loadavg() {
+	assert!(LoadInfo::parse("").is_err());
+	assert!(LoadInfo::parse("0.52 0.58 0.59").is_err());
+	assert!(LoadInfo::parse("0.52 0.58 abc 2/1234 56789").is_err());
+}
+
+#[test]
+fn test_parse_pressure_without_full() {
+	let pressure =
+		Pressure::parse(&common::read_fixture("proc_pressure_cpu")).expect("fixture should parse");
+
+	assert_eq!(
+		pressure.some,
+		PressureStat { avg10: 2.46, avg60: 2.88, avg300: 2.66, total: 26994362 }
+	);
+	assert!(pressure.full.is_none());
+}
+
+#[test]
+fn test_parse_pressure_with_full() {
+	let pressure =
+		Pressure::parse(&common::read_fixture("proc_pressure_io")).expect("fixture should parse");
+
+	assert_eq!(pressure.some.total, 2535190);
+	assert_eq!(
+		pressure.full,
+		Some(PressureStat { avg10: 0.01, avg60: 0.02, avg300: 0.0, total: 1670143 })
+	);
+}
+
+#[test]
+fn test_parse_invalid_pressure() {
+	assert!(Pressure::parse("").is_err());
+	assert!(Pressure::parse("some avg10=abc avg60=0.00 avg300=0.00 total=0").is_err());
+}
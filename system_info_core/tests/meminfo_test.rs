@@ -0,0 +1,55 @@
+#![cfg(feature = "memory")]
+
+use puniyu_system_info::{ByteSize, MemInfo, MemoryInfo, SystemInfoError};
+
+mod common;
+
+fn parse_fixture() -> MemInfo {
+	MemInfo::parse(&common::read_fixture("proc_meminfo")).expect("fixture should parse")
+}
+
+#[test]
+fn test_parse_meminfo() {
+	let meminfo = parse_fixture();
+
+	assert_eq!(meminfo.total, Some(16318196 * 1024));
+	assert_eq!(meminfo.available, Some(9523456 * 1024));
+	assert_eq!(meminfo.shared, Some(409600 * 1024));
+	assert_eq!(meminfo.slab_reclaimable, Some(609692 * 1024));
+	assert_eq!(meminfo.hugepages_total, Some(16));
+	assert_eq!(meminfo.hugepages_free, Some(4));
+	assert_eq!(meminfo.hugepage_size, Some(2048 * 1024));
+	assert_eq!(meminfo.committed_as, Some(12336248 * 1024));
+}
+
+#[test]
+fn test_parse_invalid_meminfo() {
+	assert!(MemInfo::parse("").is_err());
+	assert!(MemInfo::parse("MemFree: 1024 kB\n").is_err());
+	assert!(MemInfo::parse("MemTotal: abc kB\n").is_err());
+	// 换算为字节时溢出
+	assert!(matches!(
+		MemInfo::parse("MemTotal: 18446744073709551615 kB\n"),
+		Err(SystemInfoError::Parse(_))
+	));
+}
+
+#[test]
+fn test_memory_info_with_meminfo() {
+	let memory_info = MemoryInfo::new().with_meminfo(&parse_fixture());
+
+	assert_eq!(memory_info.available, Some(ByteSize::from_kib(9523456)));
+	assert_eq!(memory_info.free, ByteSize::from_kib(2572320));
+	assert_eq!(memory_info.buffers, Some(ByteSize::from_kib(395416)));
+	assert_eq!(memory_info.cached, Some(ByteSize::from_kib(6952872)));
+	assert_eq!(memory_info.slab_unreclaimable, Some(ByteSize::from_kib(231956)));
+	assert_eq!(memory_info.dirty, Some(ByteSize::from_mib(1)));
+	assert_eq!(memory_info.writeback, Some(ByteSize::ZERO));
+	assert_eq!(memory_info.hugepage_size, Some(ByteSize::from_mib(2)));
+	assert_eq!(memory_info.commit_limit, Some(ByteSize::from_kib(10256244)));
+	assert_eq!(memory_info.available.map(|size| size.as_mib().floor()), Some(9300.0));
+	assert_eq!(memory_info.hugepage_size.map(ByteSize::as_kib), Some(2048.0));
+	// 与旧版本的整数字段相同
+	assert_eq!(memory_info.available_mb(), Some(9300));
+	assert_eq!(memory_info.hugepage_size_kb(), Some(2048));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "network")]
+
+use puniyu_system_info::NetworkDevice;
+
+mod common;
+
+#[test]
+fn test_network_device_from_sysfs() {
+	let device = NetworkDevice::from_sysfs(common::fixture_path("sysfs"), "enp4s0").unwrap();
+
+	assert_eq!((device.vendor_id, device.device_id), (0x10ec, 0x8168));
+	assert_eq!(
+		(device.subsystem_vendor_id, device.subsystem_device_id),
+		(Some(0x1043), Some(0x8677))
+	);
+	assert_eq!(device.driver.as_deref(), Some("r8169"));
+	assert_eq!(device.pci_bus_id.as_deref(), Some("0000:04:00.0"));
+	// 名称需通过 PCI ID 数据库补全
+	assert_eq!(device.model, None);
+}
+
+#[test]
+fn test_virtual_network_device() {
+	for interface in ["lo", "docker0", "missing0"] {
+		assert!(NetworkDevice::from_sysfs(common::fixture_path("sysfs"), interface).is_none());
+	}
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "pci-ids")]
+
+use puniyu_system_info::PciIds;
+
+mod common;
+
+fn pci_ids() -> PciIds {
+	PciIds::load(common::fixture_path("pci.ids")).unwrap()
+}
+
+#[test]
+fn test_lookup() {
+	let ids = pci_ids();
+
+	assert_eq!(ids.len(), 4);
+	assert_eq!(ids.vendor(0x10de), Some("NVIDIA Corporation"));
+	assert_eq!(ids.vendor(0x1234), None);
+	assert_eq!(ids.device(0x10de, 0x2204), Some("GA102 [GeForce RTX 3090]"));
+	assert_eq!(ids.device(0x10de, 0x2206), Some("GA102 [GeForce RTX 3080]"));
+	assert_eq!(ids.device(0x8086, 0x2204), None);
+	assert_eq!(
+		ids.subsystem(0x10de, 0x2204, 0x1462, 0x3880),
+		Some("GeForce RTX 3090 Gaming X Trio")
+	);
+	assert_eq!(ids.subsystem(0x10de, 0x2206, 0x1462, 0x3880), None);
+	// 设备类别部分不会被当作厂商
+	assert_eq!(ids.vendor(0x02), None);
+}
+
+#[test]
+fn test_parse_invalid_lines() {
+	let ids = PciIds::parse(
+		"\tffff  orphan device\nzzzz  bad id\n10de  NVIDIA Corporation\n\t\t10de 147d  orphan subsystem\n\tbad\n",
+	);
+
+	assert_eq!(ids.len(), 1);
+	assert_eq!(ids.device(0x10de, 0xffff), None);
+	assert!(PciIds::parse("").is_empty());
+	assert!(PciIds::load(common::fixture_path("missing.ids")).is_err());
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_resolve_gpu_names() {
+	use puniyu_system_info::GpuInfo;
+
+	let ids = pci_ids();
+	let mut gpus = GpuInfo::from_sysfs(common::fixture_path("sysfs"));
+	gpus.iter_mut().for_each(|gpu| gpu.resolve_names(&ids));
+
+	let models: Vec<_> = gpus.iter().map(|gpu| gpu.model.as_str()).collect();
+	assert_eq!(
+		models,
+		[
+			"Intel CoffeeLake-S GT2 [UHD Graphics 630]",
+			"AMD Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]",
+			"NVIDIA GA102 [GeForce RTX 3090]",
+		]
+	);
+
+	// 已有的型号不会被替换
+	let mut gpu = gpus[2].clone();
+	gpu.model = "NVIDIA GeForce RTX 3090".to_string();
+	gpu.resolve_names(&ids);
+	assert_eq!(gpu.model, "NVIDIA GeForce RTX 3090");
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_resolve_network_device_names() {
+	use puniyu_system_info::NetworkDevice;
+
+	let mut device = NetworkDevice::from_sysfs(common::fixture_path("sysfs"), "enp4s0").unwrap();
+	device.resolve_names(&pci_ids());
+
+	assert_eq!(device.vendor.as_deref(), Some("Realtek Semiconductor Co., Ltd."));
+	assert_eq!(
+		device.model.as_deref(),
+		Some("RTL8111/8168/8211/8411 PCI Express Gigabit Ethernet Controller")
+	);
+	assert_eq!(device.subsystem.as_deref(), Some("PRIME B450M-A Motherboard"));
+}
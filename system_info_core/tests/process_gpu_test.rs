@@ -0,0 +1,148 @@
+#![cfg(all(feature = "process", feature = "gpu"))]
+
+use std::time::Duration;
+
+use puniyu_system_info::{ByteSize, DrmClient, GpuInfo, GpuProcess, ProcessGpuUsage};
+
+mod common;
+
+#[test]
+fn test_parse_amdgpu() {
+	let text = common::read_fixture("drm_fdinfo_1/1234/fdinfo/5");
+	let client = DrmClient::parse(&text).unwrap();
+
+	assert_eq!(client.driver, "amdgpu");
+	assert_eq!(client.client_id, Some(7));
+	assert_eq!(client.pci_bus_id.as_deref(), Some("0000:03:00.0"));
+	assert_eq!(client.memory.get("vram"), Some(&(1 << 30)));
+	assert_eq!(client.memory.get("gtt"), Some(&(2 << 20)));
+	assert_eq!(client.engines.get("gfx"), Some(&1_000_000_000));
+	// 只统计显存，不包含 GTT
+	assert_eq!(client.used_memory(), 1 << 30);
+}
+
+#[test]
+fn test_parse_non_drm() {
+	assert_eq!(DrmClient::parse("pos:\t0\nflags:\t0100002\nmnt_id:\t25\nino:\t6\n"), None);
+}
+
+#[test]
+fn test_read_process() {
+	let clients = DrmClient::read_process(common::fixture_path("drm_fdinfo_1"), 1234);
+
+	// fd 0 不是 DRM 设备，fd 5 与 fd 6 为同一客户端
+	let drivers: Vec<_> = clients.iter().map(|client| client.driver.as_str()).collect();
+	assert_eq!(drivers, ["amdgpu", "i915"]);
+
+	// i915 的驻留内存优先于总内存，集成显卡统计所有区域
+	let i915 = &clients[1];
+	assert_eq!(i915.memory.get("system0"), Some(&(32 << 20)));
+	assert_eq!(i915.capacities.get("video"), Some(&2));
+	assert_eq!(i915.used_memory(), 32 << 20);
+
+	assert!(DrmClient::read_process(common::fixture_path("drm_fdinfo_1"), 1).is_empty());
+}
+
+#[test]
+fn test_read_all() {
+	let clients = DrmClient::read_all(common::fixture_path("drm_fdinfo_1"));
+
+	let mut pids: Vec<_> = clients.keys().copied().collect();
+	pids.sort();
+	assert_eq!(pids, [1234, 5678, 9012]);
+
+	let xe = &clients[&5678][0];
+	assert_eq!(xe.pci_bus_id.as_deref(), Some("0000:0a:00.0"));
+	assert_eq!(xe.cycles.get("rcs"), Some(&(1000, 100_000)));
+	assert_eq!(xe.used_memory(), 256 << 20);
+}
+
+#[test]
+fn test_usage_since() {
+	let before = DrmClient::read_all(common::fixture_path("drm_fdinfo_1"));
+	let after = DrmClient::read_all(common::fixture_path("drm_fdinfo_2"));
+	let second = Duration::from_secs(1);
+
+	// amdgpu: gfx 250ms / 1s
+	assert_eq!(after[&1234][0].usage_since(&before[&1234][0], second), Some(25.0));
+	// i915: video 400ms / (1s * 2 个引擎) 高于 render 的 10%
+	assert_eq!(after[&1234][1].usage_since(&before[&1234][1], second), Some(20.0));
+	// xe: rcs 30000 / 50000 周期，与 elapsed 无关
+	assert_eq!(after[&5678][0].usage_since(&before[&5678][0], Duration::ZERO), Some(60.0));
+	// 时间基准为零时无法计算忙碌时间
+	assert_eq!(after[&1234][0].usage_since(&before[&1234][0], Duration::ZERO), None);
+}
+
+#[test]
+fn test_from_drm_clients() {
+	let before = DrmClient::read_all(common::fixture_path("drm_fdinfo_1"));
+	let after = DrmClient::read_all(common::fixture_path("drm_fdinfo_2"));
+
+	let usage =
+		ProcessGpuUsage::from_drm_clients(&after[&1234], &before[&1234], Duration::from_secs(1));
+	assert_eq!(usage.len(), 2);
+	assert_eq!(usage[0].pci_bus_id.as_deref(), Some("0000:03:00.0"));
+	assert_eq!(usage[0].driver.as_deref(), Some("amdgpu"));
+	assert_eq!(usage[0].memory, Some(ByteSize::from_mib(1536)));
+	assert_eq!(usage[0].usage, Some(25.0));
+	assert_eq!(usage[1].memory, Some(ByteSize::from_mib(48)));
+
+	// 首次采样只有显存
+	let usage = ProcessGpuUsage::from_drm_clients(&before[&5678], &[], Duration::ZERO);
+	assert_eq!(usage[0].memory, Some(ByteSize::from_mib(256)));
+	assert_eq!(usage[0].usage, None);
+
+	// 没有统计数据的客户端被忽略
+	assert!(ProcessGpuUsage::from_drm_clients(&before[&9012], &[], Duration::ZERO).is_empty());
+}
+
+#[test]
+fn test_from_gpus() {
+	let gpu = |bus_id: &str, processes| GpuInfo {
+		pci_bus_id: Some(bus_id.to_string()),
+		driver: Some("nvidia".to_string()),
+		processes,
+		..GpuInfo::from_sysfs(common::fixture_path("sysfs")).remove(0)
+	};
+	let gpus = [
+		gpu(
+			"0000:01:00.0",
+			Some(vec![
+				GpuProcess { pid: 100, used_memory: Some(ByteSize::from_gib(2)), usage: Some(80) },
+				GpuProcess { pid: 200, used_memory: None, usage: None },
+			]),
+		),
+		gpu(
+			"0000:02:00.0",
+			Some(vec![GpuProcess {
+				pid: 100,
+				used_memory: Some(ByteSize::from_gib(1)),
+				usage: Some(5),
+			}]),
+		),
+		gpu("0000:03:00.0", None),
+	];
+
+	let usage = ProcessGpuUsage::from_gpus(&gpus);
+	assert_eq!(usage.len(), 2);
+	let worker = &usage[&100];
+	assert_eq!(worker.len(), 2);
+	assert_eq!(worker[0].pci_bus_id.as_deref(), Some("0000:01:00.0"));
+	assert_eq!(worker[0].usage, Some(80.0));
+	assert_eq!(worker[1].memory, Some(ByteSize::from_gib(1)));
+	assert_eq!(usage[&200][0].memory, None);
+}
+
+#[test]
+fn test_process_gpu_opt_in() {
+	use puniyu_system_info::{ProcessTree, Subsystem, SystemInfo};
+
+	// 未刷新各进程的GPU占用时，进程树不包含GPU数据
+	let mut system_info = SystemInfo::new();
+	assert!(system_info.process_tree().flatten().iter().all(|process| process.gpus.is_none()));
+	assert!(ProcessTree::new().flatten().iter().all(|process| process.gpus.is_none()));
+
+	system_info.refresh(&[Subsystem::Process, Subsystem::Gpu]);
+	assert!(system_info.process_tree().find_by_pid(std::process::id()).is_some());
+	assert!(!ProcessTree::with_gpu_usage().is_empty());
+}
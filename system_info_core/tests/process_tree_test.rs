@@ -0,0 +1,203 @@
+#![cfg(feature = "process")]
+
+#[cfg(feature = "process-regex")]
+use puniyu_system_info::SystemInfoError;
+use puniyu_system_info::{ByteSize, ProcessInfo, ProcessSort, ProcessTree};
+use sysinfo::Pid;
+
+fn process(pid: u32, name: &str, cpu: f32, mib: u64, children: Vec<ProcessInfo>) -> ProcessInfo {
+	ProcessInfo {
+		pid: Pid::from_u32(pid),
+		name: name.to_string(),
+		sub_list: (!children.is_empty()).then_some(children),
+		cpu_usage: (cpu > 0.0).then_some(cpu),
+		used_memory: ByteSize::from_mib(mib),
+		..ProcessInfo::default()
+	}
+}
+
+/// init(1) ─┬─ nginx(10) ─┬─ nginx(11)
+///          │             └─ nginx(12)
+///          └─ sshd(20) ─── bash(21) ─── top(22)
+/// kthreadd(2)
+fn sample() -> ProcessTree {
+	ProcessTree::from_processes(vec![
+		process(
+			1,
+			"init",
+			0.5,
+			10,
+			vec![
+				process(
+					10,
+					"nginx",
+					1.0,
+					20,
+					vec![
+						process(11, "nginx", 5.0, 100, vec![]),
+						process(12, "nginx", 3.0, 300, vec![]),
+					],
+				),
+				process(
+					20,
+					"sshd",
+					0.0,
+					8,
+					vec![process(21, "bash", 0.0, 4, vec![process(22, "top", 2.0, 2, vec![])])],
+				),
+			],
+		),
+		process(2, "kthreadd", 0.0, 0, vec![]),
+	])
+}
+
+fn pids(processes: &[&ProcessInfo]) -> Vec<u32> {
+	processes.iter().map(|process| process.pid.as_u32()).collect()
+}
+
+#[test]
+fn test_structure() {
+	let tree = sample();
+
+	assert_eq!(tree.len(), 8);
+	assert!(!tree.is_empty());
+	assert_eq!(pids(&tree.roots()), [1, 2]);
+	assert_eq!(pids(&tree.children(10)), [11, 12]);
+	assert!(tree.children(999).is_empty());
+	assert_eq!(tree.parent(22).map(|process| process.pid.as_u32()), Some(21));
+	assert!(tree.parent(1).is_none());
+	// 节点本身不保留嵌套列表
+	assert!(tree.find_by_pid(10).unwrap().sub_list.is_none());
+}
+
+#[test]
+fn test_flatten() {
+	assert_eq!(pids(&sample().flatten()), [1, 10, 11, 12, 20, 21, 22, 2]);
+}
+
+#[test]
+fn test_find() {
+	let tree = sample();
+
+	assert_eq!(tree.find_by_pid(21).map(|process| process.name.as_str()), Some("bash"));
+	assert!(tree.find_by_pid(999).is_none());
+	assert_eq!(pids(&tree.find_by_name("nginx")), [10, 11, 12]);
+	assert!(tree.find_by_name("ngin").is_empty());
+}
+
+#[cfg(feature = "process-regex")]
+#[test]
+fn test_find_by_regex() {
+	let tree = sample();
+
+	assert_eq!(pids(&tree.find_by_regex("^(sshd|ba)").unwrap()), [20, 21]);
+	assert!(matches!(tree.find_by_regex("("), Err(SystemInfoError::InvalidPattern(_))));
+}
+
+#[test]
+fn test_sort_by() {
+	let mut tree = sample();
+
+	tree.sort_by(ProcessSort::Cpu);
+	assert_eq!(pids(&tree.flatten()), [1, 10, 11, 12, 20, 21, 22, 2]);
+	assert_eq!(pids(&tree.children(10)), [11, 12]);
+
+	tree.sort_by(ProcessSort::Memory);
+	assert_eq!(pids(&tree.flatten()), [1, 10, 12, 11, 20, 21, 22, 2]);
+
+	// 恢复为按进程ID排列
+	tree.sort_by(ProcessSort::Pid);
+	assert_eq!(pids(&tree.flatten()), [1, 10, 11, 12, 20, 21, 22, 2]);
+}
+
+#[test]
+fn test_totals() {
+	let tree = sample();
+
+	let nginx = tree.totals(10).unwrap();
+	assert_eq!(nginx.processes, 3);
+	assert_eq!(nginx.cpu_usage, 9.0);
+	assert_eq!(nginx.used_memory, ByteSize::from_mib(420));
+
+	let init = tree.totals(1).unwrap();
+	assert_eq!(init.processes, 7);
+	assert_eq!(init.cpu_usage, 11.5);
+	assert_eq!(init.used_memory, ByteSize::from_mib(444));
+
+	assert_eq!(tree.totals(22).unwrap().processes, 1);
+	assert!(tree.totals(999).is_none());
+}
+
+#[test]
+fn test_limit_depth() {
+	let tree = sample();
+
+	assert_eq!(pids(&tree.limit_depth(0).flatten()), [1, 2]);
+	assert_eq!(pids(&tree.limit_depth(1).flatten()), [1, 10, 20, 2]);
+	assert_eq!(tree.limit_depth(2).len(), 7);
+	assert_eq!(tree.limit_depth(10).len(), tree.len());
+}
+
+#[test]
+fn test_nested() {
+	let tree = sample();
+
+	let nested = tree.to_nested();
+	assert_eq!(nested.len(), 2);
+	let sshd = &nested[0].sub_list.as_ref().unwrap()[1];
+	assert_eq!(sshd.name, "sshd");
+	assert_eq!(sshd.sub_list.as_ref().unwrap()[0].sub_list.as_ref().unwrap()[0].name, "top");
+	assert!(nested[1].sub_list.is_none());
+
+	let bash = tree.subtree(21).unwrap();
+	assert_eq!(bash.sub_list.as_ref().unwrap().len(), 1);
+	assert!(tree.subtree(999).is_none());
+
+	// 往返转换保持结构
+	assert_eq!(pids(&ProcessTree::from_processes(nested).flatten()), pids(&tree.flatten()));
+}
+
+#[test]
+fn test_current_process() {
+	let tree = ProcessTree::new();
+	let pid = std::process::id();
+
+	assert!(!tree.is_empty());
+	assert_eq!(tree.flatten().len(), tree.len());
+	assert!(tree.find_by_pid(pid).is_some());
+	assert!(tree.totals(pid).unwrap().processes >= 1);
+}
+
+#[test]
+fn test_from_parents() {
+	let tree = ProcessTree::from_parents([
+		(process(1, "init", 0.0, 10, vec![]), None),
+		(process(10, "nginx", 1.0, 20, vec![]), Some(1)),
+		(process(11, "nginx", 5.0, 100, vec![]), Some(10)),
+		(process(30, "orphan", 0.0, 1, vec![]), Some(999)),
+	]);
+
+	assert_eq!(pids(&tree.roots()), [1, 30]);
+	assert_eq!(pids(&tree.flatten()), [1, 10, 11, 30]);
+	assert_eq!(tree.totals(1).unwrap().processes, 3);
+}
+
+#[test]
+fn test_parent_cycle() {
+	// PID 复用后 5 -> 6 -> 7 -> 5 成环，另有进程以自身为父进程
+	let tree = ProcessTree::from_parents([
+		(process(5, "a", 1.0, 1, vec![]), Some(6)),
+		(process(6, "b", 1.0, 1, vec![]), Some(7)),
+		(process(7, "c", 1.0, 1, vec![]), Some(5)),
+		(process(8, "d", 1.0, 1, vec![]), Some(5)),
+		(process(9, "self", 1.0, 1, vec![]), Some(9)),
+	]);
+
+	assert_eq!(pids(&tree.roots()), [7, 9]);
+	assert_eq!(pids(&tree.flatten()), [7, 6, 5, 8, 9]);
+	assert_eq!(tree.totals(7).unwrap().processes, 4);
+	assert_eq!(tree.totals(5).unwrap().processes, 2);
+	assert_eq!(tree.to_nested().len(), 2);
+	assert_eq!(tree.limit_depth(10).len(), tree.len());
+	assert!(tree.parent(7).is_none());
+}
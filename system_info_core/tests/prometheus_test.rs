@@ -0,0 +1,443 @@
+#![cfg(feature = "prometheus")]
+
+use std::collections::{HashMap, HashSet};
+
+use puniyu_system_info::exporter::prometheus;
+use puniyu_system_info::{PartialSnapshot, Snapshot};
+
+/// 解析后的样本
+#[derive(Debug)]
+struct Sample {
+	name: String,
+	labels: Vec<(String, String)>,
+	value: f64,
+}
+
+/// 按文本格式规则校验并解析输出，返回各指标族的类型与所有样本
+fn parse_exposition(text: &str) -> (HashMap<String, String>, Vec<Sample>) {
+	let mut types = HashMap::new();
+	let mut helps = HashSet::new();
+	let mut finished = HashSet::new();
+	let mut current: Option<String> = None;
+	let mut series = HashSet::new();
+	let mut samples = Vec::new();
+
+	assert!(text.ends_with('\n'), "output must end with a newline");
+	for line in text.lines() {
+		if let Some(comment) = line.strip_prefix('#') {
+			let mut parts = comment.trim_start().splitn(3, ' ');
+			match parts.next() {
+				Some("HELP") => {
+					let name = parts.next().expect("HELP without metric name");
+					assert!(is_metric_name(name), "invalid metric name `{name}`");
+					assert!(helps.insert(name.to_string()), "duplicate HELP for `{name}`");
+					unescape(parts.next().unwrap_or(""), false);
+				}
+				Some("TYPE") => {
+					let name = parts.next().expect("TYPE without metric name");
+					let kind = parts.next().expect("TYPE without type");
+					assert!(
+						["counter", "gauge", "histogram", "summary", "untyped"].contains(&kind),
+						"invalid type `{kind}`"
+					);
+					assert!(is_metric_name(name), "invalid metric name `{name}`");
+					assert!(
+						types.insert(name.to_string(), kind.to_string()).is_none(),
+						"duplicate TYPE for `{name}`"
+					);
+					assert!(!finished.contains(name), "TYPE for `{name}` after its samples");
+				}
+				_ => {}
+			}
+			continue;
+		}
+		if line.trim().is_empty() {
+			continue;
+		}
+
+		let sample = parse_sample(line);
+		let kind = types
+			.get(&sample.name)
+			.unwrap_or_else(|| panic!("sample `{}` without preceding TYPE", sample.name));
+		if current.as_deref() != Some(sample.name.as_str()) {
+			assert!(
+				finished.insert(sample.name.clone()),
+				"samples of `{}` not grouped",
+				sample.name
+			);
+			current = Some(sample.name.clone());
+		}
+		match kind.as_str() {
+			"counter" => {
+				assert!(
+					sample.name.ends_with("_total"),
+					"counter `{}` without _total",
+					sample.name
+				);
+				assert!(sample.value >= 0.0, "negative counter `{line}`");
+			}
+			"gauge" => {
+				assert!(
+					!sample.name.ends_with("_total"),
+					"gauge `{}` ends with _total",
+					sample.name
+				);
+			}
+			_ => {}
+		}
+		let mut label_names = HashSet::new();
+		for (label, _) in &sample.labels {
+			assert!(label_names.insert(label), "duplicate label `{label}` in `{line}`");
+		}
+		let mut key = sample.labels.clone();
+		key.sort();
+		assert!(series.insert((sample.name.clone(), key)), "duplicate series `{line}`");
+		samples.push(sample);
+	}
+	(types, samples)
+}
+
+fn parse_sample(line: &str) -> Sample {
+	let name_end = line.find(['{', ' ']).unwrap_or_else(|| panic!("sample without value `{line}`"));
+	let name = &line[..name_end];
+	assert!(is_metric_name(name), "invalid metric name `{name}`");
+
+	let mut rest = &line[name_end..];
+	let mut labels = Vec::new();
+	if let Some(mut body) = rest.strip_prefix('{') {
+		loop {
+			if let Some(after) = body.strip_prefix('}') {
+				rest = after;
+				break;
+			}
+			let eq = body.find('=').unwrap_or_else(|| panic!("label without value `{line}`"));
+			let label = &body[..eq];
+			assert!(is_label_name(label), "invalid label name `{label}` in `{line}`");
+			let quoted = body[eq + 1..].strip_prefix('"').expect("label value must be quoted");
+			let (value, after) = split_label_value(quoted);
+			labels.push((label.to_string(), value));
+			body = after.strip_prefix(',').unwrap_or(after);
+		}
+	}
+
+	let mut fields = rest.strip_prefix(' ').expect("missing space before value").split(' ');
+	let value = fields.next().expect("missing value");
+	let value = match value {
+		"NaN" => f64::NAN,
+		"+Inf" => f64::INFINITY,
+		"-Inf" => f64::NEG_INFINITY,
+		value => value.parse().unwrap_or_else(|_| panic!("invalid value `{value}` in `{line}`")),
+	};
+	if let Some(timestamp) = fields.next() {
+		timestamp.parse::<i64>().expect("invalid timestamp");
+	}
+	assert!(fields.next().is_none(), "trailing data in `{line}`");
+	Sample { name: name.to_string(), labels, value }
+}
+
+/// 读取到未转义的双引号为止，返回反转义后的值与剩余部分
+fn split_label_value(text: &str) -> (String, &str) {
+	let mut escaped = false;
+	for (index, c) in text.char_indices() {
+		match (escaped, c) {
+			(false, '\\') => escaped = true,
+			(false, '"') => return (unescape(&text[..index], true), &text[index + 1..]),
+			_ => escaped = false,
+		}
+	}
+	panic!("unterminated label value `{text}`");
+}
+
+fn unescape(text: &str, quote: bool) -> String {
+	let mut unescaped = String::new();
+	let mut chars = text.chars();
+	while let Some(c) = chars.next() {
+		assert_ne!(c, '\n', "raw newline in `{text}`");
+		if c != '\\' {
+			unescaped.push(c);
+			continue;
+		}
+		match chars.next() {
+			Some('\\') => unescaped.push('\\'),
+			Some('n') => unescaped.push('\n'),
+			Some('"') if quote => unescaped.push('"'),
+			other => panic!("invalid escape `\\{other:?}` in `{text}`"),
+		}
+	}
+	unescaped
+}
+
+fn is_metric_name(name: &str) -> bool {
+	let mut chars = name.chars();
+	chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == ':')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+}
+
+fn is_label_name(name: &str) -> bool {
+	let mut chars = name.chars();
+	chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+		&& chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+		&& !name.starts_with("__")
+}
+
+fn find<'a>(samples: &'a [Sample], name: &str, labels: &[(&str, &str)]) -> Option<&'a Sample> {
+	samples.iter().find(|sample| {
+		sample.name == name
+			&& labels
+				.iter()
+				.all(|(label, value)| sample.labels.iter().any(|(l, v)| l == label && v == value))
+	})
+}
+
+#[test]
+fn test_render_snapshot() {
+	let output = prometheus::render(&Snapshot::new());
+	let (types, samples) = parse_exposition(&output);
+
+	assert_eq!(types["system_info_snapshot_timestamp_seconds"], "gauge");
+	let timestamp = find(&samples, "system_info_snapshot_timestamp_seconds", &[]).unwrap();
+	assert!(timestamp.value > 1_600_000_000.0);
+	#[cfg(feature = "cpu")]
+	assert!(find(&samples, "system_info_cpu_core_usage_percent", &[("core", "0")]).is_some());
+	#[cfg(feature = "memory")]
+	assert!(find(&samples, "system_info_memory_total_bytes", &[]).is_some_and(|s| s.value > 0.0));
+	#[cfg(feature = "process")]
+	{
+		let pid = std::process::id().to_string();
+		assert!(find(&samples, "system_info_process_memory_bytes", &[("pid", &pid)]).is_some());
+	}
+	#[cfg(feature = "network")]
+	assert_eq!(
+		types.get("system_info_network_receive_bytes_total").map(String::as_str),
+		find(&samples, "system_info_network_receive_bytes_total", &[]).map(|_| "counter")
+	);
+}
+
+#[test]
+fn test_render_partial_snapshot() {
+	let snapshot = Snapshot::new();
+	let full = prometheus::render(&snapshot);
+	assert_eq!(prometheus::render_partial(&snapshot.clone().into()), full);
+
+	let empty = PartialSnapshot::with_subsystems(&[]);
+	let (_, samples) = parse_exposition(&prometheus::render_partial(&empty));
+	assert_eq!(samples.len(), 1);
+	assert_eq!(samples[0].name, "system_info_snapshot_timestamp_seconds");
+}
+
+#[test]
+#[cfg_attr(not(feature = "process"), allow(unused_variables))]
+#[cfg_attr(not(any(feature = "cpu", feature = "process", feature = "network")), allow(unused_mut))]
+fn test_render_labels_and_values() {
+	let mut snapshot = Snapshot::new();
+	#[cfg(feature = "cpu")]
+	{
+		let cpu = &mut snapshot.cpu;
+		cpu.temperature = Some(f32::NAN);
+		cpu.usage = Some(12.3);
+	}
+	#[cfg(feature = "process")]
+	{
+		let mut process = puniyu_system_info::ProcessInfo::new(std::process::id());
+		process.name = "we\"ird\\name\nx".to_string();
+		snapshot.processes = vec![process];
+	}
+	#[cfg(feature = "network")]
+	{
+		use puniyu_system_info::{ByteSize, NetworkInfo};
+
+		snapshot.networks = vec![NetworkInfo {
+			name: "eth0".to_string(),
+			ip_info: Vec::new(),
+			upload: 1.5,
+			download: 0.0,
+			upload_bytes_per_second: 1536.4,
+			download_bytes_per_second: 0.0,
+			total_upload: ByteSize::from_bytes(42),
+			total_download: ByteSize::from_gib(1),
+			mac_addr: sysinfo::MacAddr([0, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]),
+			device: None,
+		}];
+	}
+
+	let output = prometheus::render(&snapshot);
+	let (_, samples) = parse_exposition(&output);
+
+	#[cfg(feature = "cpu")]
+	{
+		assert!(output.contains("\nsystem_info_cpu_temperature_celsius NaN\n"));
+		assert!(output.contains("\nsystem_info_cpu_usage_percent 12.3\n"));
+	}
+	#[cfg(feature = "process")]
+	{
+		assert!(output.contains(r#"name="we\"ird\\name\nx""#));
+		let pid = std::process::id().to_string();
+		let sample = find(&samples, "system_info_process_memory_bytes", &[("pid", &pid)]).unwrap();
+		assert_eq!(sample.labels[1], ("name".to_string(), "we\"ird\\name\nx".to_string()));
+	}
+	#[cfg(feature = "network")]
+	{
+		assert!(output.contains(
+			"# TYPE system_info_network_receive_bytes_total counter\n\
+			 system_info_network_receive_bytes_total{interface=\"eth0\"} 1073741824\n"
+		));
+		assert!(
+			output.contains("system_info_network_transmit_bytes_total{interface=\"eth0\"} 42\n")
+		);
+		assert!(
+			output.contains(
+				"system_info_network_transmit_bytes_per_second{interface=\"eth0\"} 1536.4\n"
+			)
+		);
+		assert!(output.contains(
+			"system_info_network_info{interface=\"eth0\",mac_address=\"00:1a:2b:3c:4d:5e\"} 1\n"
+		));
+	}
+}
+
+#[cfg(feature = "disk")]
+#[test]
+fn test_render_duplicate_mounts() {
+	let mut snapshot = Snapshot::new();
+	let disks = &mut snapshot.disk.disks;
+	if let Some(disk) = disks.first().cloned() {
+		disks.push(disk);
+	}
+	parse_exposition(&prometheus::render(&snapshot));
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_render_multiple_gpus() {
+	use puniyu_system_info::{ByteSize, GpuInfo};
+
+	let gpu = |index: u32, bus_id: &str| GpuInfo {
+		index,
+		model: "NVIDIA GeForce RTX 3090".to_string(),
+		vendor: Some("NVIDIA".to_string()),
+		vendor_id: Some(0x10de),
+		device_id: Some(0x2204),
+		driver: Some("nvidia".to_string()),
+		pci_bus_id: Some(bus_id.to_string()),
+		memory_total: Some(ByteSize::from_gib(24)),
+		memory_used: Some(ByteSize::from_gib(1)),
+		memory_free: Some(ByteSize::from_gib(23)),
+		usage: Some(index as u8 * 50),
+		temperature: None,
+		memory_controller_usage: None,
+		encoder_usage: None,
+		decoder_usage: None,
+		fan_speed: None,
+		power_usage: Some(120.5),
+		power_limit: None,
+		graphics_clock: Some(1695),
+		memory_clock: None,
+		pcie_tx: None,
+		pcie_rx: None,
+		driver_version: None,
+		cuda_version: None,
+		processes: None,
+	};
+	let mut snapshot = Snapshot::new();
+	snapshot.gpus = vec![gpu(0, "0000:01:00.0"), gpu(1, "0000:02:00.0")];
+
+	let output = prometheus::render(&snapshot);
+	let (_, samples) = parse_exposition(&output);
+
+	// 型号相同的两块显卡以序号与总线地址区分
+	let usage = |index: &str| {
+		find(&samples, "system_info_gpu_usage_percent", &[("gpu", index)]).map(|s| s.value)
+	};
+	assert_eq!(usage("0"), Some(0.0));
+	assert_eq!(usage("1"), Some(50.0));
+	assert!(!output.contains("system_info_gpu_temperature_celsius"));
+	assert!(!output.contains("system_info_gpu_driver_info"));
+	assert_eq!(
+		find(&samples, "system_info_gpu_power_usage_watts", &[("gpu", "0")]).map(|s| s.value),
+		Some(120.5)
+	);
+	assert_eq!(
+		find(&samples, "system_info_gpu_clock_hertz", &[("gpu", "1"), ("clock", "graphics")])
+			.map(|s| s.value),
+		Some(1_695_000_000.0)
+	);
+	assert!(find(&samples, "system_info_gpu_clock_hertz", &[("clock", "memory")]).is_none());
+	assert!(output.contains(
+		"system_info_gpu_memory_total_bytes{gpu=\"1\",model=\"NVIDIA GeForce RTX 3090\",bus_id=\"0000:02:00.0\"} 25769803776\n"
+	));
+}
+
+#[cfg(all(feature = "process", feature = "gpu"))]
+#[test]
+fn test_render_process_gpu_usage() {
+	use puniyu_system_info::{ByteSize, ProcessGpuUsage, ProcessInfo};
+
+	let mut process = ProcessInfo::new(std::process::id());
+	process.gpus = Some(vec![
+		ProcessGpuUsage {
+			pci_bus_id: Some("0000:01:00.0".to_string()),
+			driver: Some("nvidia".to_string()),
+			memory: Some(ByteSize::from_mib(512)),
+			usage: Some(37.5),
+		},
+		ProcessGpuUsage {
+			pci_bus_id: Some("0000:03:00.0".to_string()),
+			driver: Some("amdgpu".to_string()),
+			memory: Some(ByteSize::from_mib(64)),
+			usage: None,
+		},
+	]);
+	let mut snapshot = Snapshot::new();
+	snapshot.processes = vec![process];
+
+	let output = prometheus::render(&snapshot);
+	let (_, samples) = parse_exposition(&output);
+
+	let pid = std::process::id().to_string();
+	let value = |name: &str, bus_id: &str| {
+		find(&samples, name, &[("pid", &pid), ("bus_id", bus_id)]).map(|s| s.value)
+	};
+	assert_eq!(value("system_info_process_gpu_memory_bytes", "0000:01:00.0"), Some(536870912.0));
+	assert_eq!(value("system_info_process_gpu_memory_bytes", "0000:03:00.0"), Some(67108864.0));
+	assert_eq!(value("system_info_process_gpu_usage_percent", "0000:01:00.0"), Some(37.5));
+	assert_eq!(value("system_info_process_gpu_usage_percent", "0000:03:00.0"), None);
+}
+
+#[cfg(feature = "load")]
+#[test]
+fn test_render_pressure() {
+	use puniyu_system_info::{Pressure, PressureInfo, PressureStat};
+
+	let mut snapshot = Snapshot::new();
+	let stat = |avg10, total| PressureStat { avg10, avg60: 2.5, avg300: 0.75, total };
+	snapshot.load.pressure = Some(PressureInfo {
+		cpu: Some(Pressure { some: stat(1.5, 2_500_000), full: None }),
+		memory: Some(Pressure { some: stat(0.0, 0), full: Some(stat(0.1, 123)) }),
+		io: None,
+	});
+
+	let (types, samples) = parse_exposition(&prometheus::render(&snapshot));
+	let cpu = [("resource", "cpu"), ("kind", "some")];
+	assert_eq!(find(&samples, "system_info_pressure_avg10_percent", &cpu).unwrap().value, 1.5);
+	assert_eq!(find(&samples, "system_info_pressure_avg60_percent", &cpu).unwrap().value, 2.5);
+	assert_eq!(find(&samples, "system_info_pressure_avg300_percent", &cpu).unwrap().value, 0.75);
+
+	// 累计阻塞时间为秒，可直接使用 rate()
+	assert_eq!(types["system_info_pressure_stall_seconds_total"], "counter");
+	let total = find(&samples, "system_info_pressure_stall_seconds_total", &cpu).unwrap();
+	assert_eq!(total.value, 2.5);
+	let memory_full = [("resource", "memory"), ("kind", "full")];
+	let total = find(&samples, "system_info_pressure_stall_seconds_total", &memory_full).unwrap();
+	assert_eq!(total.value, 0.000123);
+	assert!(
+		find(
+			&samples,
+			"system_info_pressure_avg10_percent",
+			&[("kind", "full"), ("resource", "cpu")]
+		)
+		.is_none()
+	);
+	assert!(
+		find(&samples, "system_info_pressure_stall_seconds_total", &[("resource", "io")]).is_none()
+	);
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "serde")]
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+/// 序列化后再反序列化，要求两次序列化结果一致
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> Value {
+	let json = serde_json::to_value(value).expect("value should serialize");
+	let decoded: T = serde_json::from_value(json.clone()).expect("value should deserialize");
+	assert_eq!(serde_json::to_value(&decoded).unwrap(), json);
+	json
+}
+
+#[test]
+fn test_byte_size_serde() {
+	use puniyu_system_info::ByteSize;
+
+	assert_eq!(round_trip(&ByteSize::from_kib(3)), json!(3072));
+}
+
+#[cfg(feature = "host")]
+#[test]
+fn test_host_info_serde() {
+	use puniyu_system_info::{HostInfo, Virtualization};
+
+	let json = round_trip(&HostInfo::new());
+	assert!(json["host_name"].is_string());
+	assert!(json["boot_time"].is_string());
+	assert_eq!(round_trip(&Virtualization::HyperV), json!("hyper-v"));
+	assert_eq!(round_trip(&Virtualization::Docker), json!("docker"));
+}
+
+#[cfg(feature = "cpu")]
+#[test]
+fn test_cpu_info_serde() {
+	use puniyu_system_info::CpuInfo;
+
+	let json = round_trip(&CpuInfo::new());
+	assert!(json["cores"].is_array());
+}
+
+#[cfg(feature = "memory")]
+#[test]
+fn test_memory_info_serde() {
+	use puniyu_system_info::MemoryInfo;
+
+	let memory_info = MemoryInfo::new();
+	let json = round_trip(&memory_info);
+	assert_eq!(json["total"], json!(memory_info.total.as_bytes()));
+}
+
+#[cfg(feature = "disk")]
+#[test]
+fn test_disk_info_serde() {
+	use puniyu_system_info::{DeviceKind, DiskInfo};
+
+	let json = round_trip(&DiskInfo::new());
+	assert!(json["disks"].is_array());
+	assert_eq!(round_trip(&DeviceKind::Nvme), json!("nvme"));
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_network_info_serde() {
+	use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+	use puniyu_system_info::{ByteSize, IpInfo, NetworkInfo};
+	use sysinfo::MacAddr;
+
+	let network_info = NetworkInfo {
+		name: "eth0".to_string(),
+		ip_info: vec![
+			IpInfo { ip_address: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 10)), netmask: 24 },
+			IpInfo { ip_address: IpAddr::V6(Ipv6Addr::LOCALHOST), netmask: 128 },
+		],
+		upload: 1.5,
+		download: 20.25,
+		upload_bytes_per_second: 1536.0,
+		download_bytes_per_second: 20736.0,
+		total_upload: ByteSize::from_mib(10),
+		total_download: ByteSize::from_gib(1),
+		mac_addr: MacAddr([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]),
+		device: None,
+	};
+	let json = round_trip(&network_info);
+
+	assert_eq!(json["mac_addr"], json!("00:1a:2b:3c:4d:5e"));
+	assert_eq!(json["ip_info"][0], json!({ "ip_address": "192.168.1.10", "netmask": 24 }));
+	assert_eq!(json["ip_info"][1]["ip_address"], json!("::1"));
+	assert_eq!(json["total_download"], json!(1024 * 1024 * 1024));
+
+	let invalid = json!({ "ip_address": "not an ip", "netmask": 24 });
+	assert!(serde_json::from_value::<IpInfo>(invalid).is_err());
+	let mut invalid = json.clone();
+	invalid["mac_addr"] = json!("00:1a:2b");
+	assert!(serde_json::from_value::<NetworkInfo>(invalid).is_err());
+
+	for network_info in NetworkInfo::all() {
+		round_trip(&network_info);
+	}
+}
+
+#[cfg(feature = "process")]
+#[test]
+fn test_process_info_serde() {
+	use puniyu_system_info::ProcessInfo;
+
+	let json = round_trip(&ProcessInfo::new(std::process::id()));
+	assert_eq!(json["pid"], json!(std::process::id()));
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_gpu_info_serde() {
+	use puniyu_system_info::{ByteSize, GpuInfo, GpuProcess};
+
+	let gpu_info = GpuInfo {
+		index: 1,
+		model: "Test GPU".to_string(),
+		vendor: Some("NVIDIA".to_string()),
+		vendor_id: Some(0x10de),
+		device_id: Some(0x2204),
+		driver: Some("nvidia".to_string()),
+		pci_bus_id: Some("0000:01:00.0".to_string()),
+		memory_total: Some(ByteSize::from_gib(8)),
+		memory_used: Some(ByteSize::from_gib(2)),
+		memory_free: Some(ByteSize::from_gib(6)),
+		usage: Some(35),
+		temperature: Some(61.0),
+		memory_controller_usage: Some(12),
+		encoder_usage: Some(0),
+		decoder_usage: None,
+		fan_speed: Some(40),
+		power_usage: Some(112.5),
+		power_limit: Some(350.0),
+		graphics_clock: Some(1695),
+		memory_clock: Some(9751),
+		pcie_tx: Some(2048),
+		pcie_rx: None,
+		driver_version: Some("535.104.05".to_string()),
+		cuda_version: Some("12.2".to_string()),
+		processes: Some(vec![GpuProcess {
+			pid: 4242,
+			used_memory: Some(ByteSize::from_mib(512)),
+			usage: Some(27),
+		}]),
+	};
+	let json = round_trip(&gpu_info);
+	assert_eq!(json["usage"], json!(35));
+	assert_eq!(json["pci_bus_id"], json!("0000:01:00.0"));
+	assert_eq!(json["cuda_version"], json!("12.2"));
+	assert_eq!(json["processes"][0]["pid"], json!(4242));
+}
+
+#[cfg(feature = "load")]
+#[test]
+fn test_load_info_serde() {
+	use puniyu_system_info::LoadInfo;
+
+	round_trip(&LoadInfo::new());
+}
@@ -0,0 +1,254 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use puniyu_system_info::{
+	PartialSnapshot, Snapshot, Subsystem, SystemInfo, SystemInfoError, parse_interval,
+};
+
+#[test]
+fn test_snapshot() {
+	let before = Utc::now();
+	let snapshot = Snapshot::new();
+	assert!(snapshot.timestamp >= before);
+	assert!(snapshot.timestamp <= Utc::now());
+
+	#[cfg(feature = "process")]
+	assert!(!snapshot.processes.is_empty());
+	#[cfg(feature = "memory")]
+	assert!(snapshot.memory.total.as_bytes() > 0);
+
+	let later = SystemInfo::new().snapshot();
+	assert!(later.timestamp >= snapshot.timestamp);
+}
+
+#[test]
+fn test_snapshot_subsystems() {
+	assert!("unknown".parse::<Subsystem>().is_err());
+	#[cfg(feature = "memory")]
+	{
+		assert_eq!(" Memory ".parse::<Subsystem>().unwrap(), Subsystem::Memory);
+		for subsystem in Subsystem::ALL {
+			assert_eq!(subsystem.as_str().parse::<Subsystem>().unwrap(), *subsystem);
+			assert_eq!(
+				subsystem.to_string().to_uppercase().parse::<Subsystem>().unwrap(),
+				*subsystem
+			);
+		}
+	}
+
+	#[cfg(all(feature = "memory", feature = "process"))]
+	{
+		let snapshot = PartialSnapshot::with_subsystems(&[Subsystem::Memory]);
+		assert!(snapshot.memory.is_some());
+		assert!(snapshot.processes.is_none());
+
+		let mut system_info = SystemInfo::new();
+		system_info.refresh(&[Subsystem::Process]);
+		let snapshot = system_info.snapshot_of(&[Subsystem::Process]);
+		assert!(snapshot.memory.is_none());
+		assert!(snapshot.processes.is_some());
+	}
+}
+
+#[test]
+fn test_parse_interval() {
+	assert_eq!(parse_interval("15").unwrap(), Duration::from_secs(15));
+	assert_eq!(parse_interval(" 0.5 ").unwrap(), Duration::from_millis(500));
+	for value in ["0", "-1", "inf", "NaN", "", "1s"] {
+		assert!(matches!(parse_interval(value), Err(SystemInfoError::InvalidInterval(_))));
+	}
+}
+
+#[test]
+fn test_sampled() {
+	#[cfg(feature = "cpu")]
+	{
+		use puniyu_system_info::SAMPLE_INTERVAL;
+
+		assert!(Subsystem::Cpu.is_sampled());
+		let started = std::time::Instant::now();
+		SystemInfo::sampled(&[Subsystem::Cpu]);
+		assert!(started.elapsed() >= SAMPLE_INTERVAL);
+	}
+	#[cfg(feature = "host")]
+	assert!(!Subsystem::Host.is_sampled());
+}
+
+#[test]
+fn test_partial_snapshot_from_snapshot() {
+	let snapshot = Snapshot::new();
+	let partial = PartialSnapshot::from(snapshot.clone());
+
+	assert_eq!(partial.timestamp, snapshot.timestamp);
+	#[cfg(feature = "memory")]
+	assert_eq!(partial.memory.unwrap().total, snapshot.memory.total);
+	#[cfg(feature = "process")]
+	assert_eq!(partial.processes.unwrap().len(), snapshot.processes.len());
+
+	// 未采集的模块序列化时省略，只保留采集时间
+	#[cfg(feature = "serde")]
+	{
+		let empty = PartialSnapshot::with_subsystems(&[]);
+		assert_eq!(serde_json::to_value(&empty).unwrap().as_object().unwrap().len(), 1);
+	}
+}
+
+#[test]
+fn test_snapshot_diff_unchanged() {
+	let snapshot = Snapshot::new();
+	let diff = snapshot.diff(&snapshot.clone());
+
+	assert!(diff.is_empty());
+	assert_eq!(diff.from, diff.to);
+	#[cfg(feature = "memory")]
+	assert_eq!(diff.memory, Default::default());
+}
+
+#[cfg(feature = "process")]
+#[test]
+fn test_snapshot_diff_processes() {
+	use puniyu_system_info::ProcessInfo;
+
+	let before = Snapshot::new();
+	let mut after = before.clone();
+
+	let mut started = ProcessInfo::new(std::process::id());
+	started.pid = sysinfo::Pid::from_u32(u32::MAX - 1);
+	started.name = "started".to_string();
+	let mut child = started.clone();
+	child.pid = sysinfo::Pid::from_u32(u32::MAX - 2);
+	child.name = "child".to_string();
+	started.sub_list = Some(vec![child]);
+	after.processes.push(started);
+	let exited = after.processes.remove(0);
+
+	let diff = before.diff(&after);
+	let mut names: Vec<&str> = diff.processes_started.iter().map(|p| p.name.as_str()).collect();
+	names.sort();
+	assert_eq!(names, ["child", "started"]);
+	assert!(diff.processes_started.iter().all(|p| p.sub_list.is_none()));
+	assert!(diff.processes_exited.iter().any(|p| p.pid == exited.pid));
+	assert!(!diff.is_empty());
+
+	let reversed = after.diff(&before);
+	assert_eq!(reversed.processes_exited.len(), 2);
+}
+
+#[cfg(feature = "disk")]
+#[test]
+fn test_snapshot_diff_disks() {
+	use puniyu_system_info::{ByteSize, DeviceKind, DiskDetail};
+
+	let before = Snapshot::new();
+	let mut after = before.clone();
+	after.disk.disks.push(DiskDetail {
+		name: "/dev/sdz1".to_string(),
+		mount: "/mnt/usb".to_string(),
+		total_space: ByteSize::from_gib(16),
+		used_space: ByteSize::ZERO,
+		free_space: ByteSize::from_gib(16),
+		usage: 0.0,
+		file_system: "vfat".to_string(),
+		kind: DeviceKind::Unknown,
+		removable: true,
+		read_only: false,
+		mount_options: Vec::new(),
+		inodes_total: None,
+		inodes_used: None,
+		inodes_free: None,
+		inode_usage: None,
+		io: None,
+	});
+
+	let diff = before.diff(&after);
+	assert_eq!(diff.disks_mounted.len(), 1);
+	assert_eq!(diff.disks_mounted[0].mount, "/mnt/usb");
+	assert!(diff.disks_unmounted.is_empty());
+
+	let diff = after.diff(&before);
+	assert_eq!(diff.disks_unmounted.len(), 1);
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_snapshot_diff_networks() {
+	use std::net::{IpAddr, Ipv4Addr};
+
+	use puniyu_system_info::{ByteSize, IpInfo, NetworkInfo};
+	use sysinfo::MacAddr;
+
+	let network = |name: &str, ips: &[[u8; 4]]| NetworkInfo {
+		name: name.to_string(),
+		ip_info: ips
+			.iter()
+			.map(|ip| IpInfo { ip_address: IpAddr::V4(Ipv4Addr::from(*ip)), netmask: 24 })
+			.collect(),
+		upload: 0.0,
+		download: 0.0,
+		upload_bytes_per_second: 0.0,
+		download_bytes_per_second: 0.0,
+		total_upload: ByteSize::ZERO,
+		total_download: ByteSize::ZERO,
+		mac_addr: MacAddr([0u8; 6]),
+		device: None,
+	};
+
+	let mut before = Snapshot::new();
+	before.networks = vec![network("eth0", &[[10, 0, 0, 2]]), network("wg0", &[[10, 8, 0, 1]])];
+	let mut after = before.clone();
+	after.networks = vec![network("eth0", &[[10, 0, 0, 3]]), network("tun0", &[[172, 16, 0, 1]])];
+
+	let diff = before.diff(&after);
+	assert_eq!(diff.interfaces_up.iter().map(|n| n.name.as_str()).collect::<Vec<_>>(), ["tun0"]);
+	assert_eq!(diff.interfaces_down.iter().map(|n| n.name.as_str()).collect::<Vec<_>>(), ["wg0"]);
+	assert_eq!(diff.ip_changes.len(), 1);
+	assert_eq!(diff.ip_changes[0].interface, "eth0");
+	assert_eq!(diff.ip_changes[0].added[0].ip_address, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3)));
+	assert_eq!(diff.ip_changes[0].removed[0].ip_address, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)));
+}
+
+#[cfg(feature = "memory")]
+#[test]
+fn test_snapshot_diff_memory() {
+	use puniyu_system_info::ByteSize;
+
+	let mut before = Snapshot::new();
+	let memory = &mut before.memory;
+	memory.used = ByteSize::from_mib(1024);
+	memory.free = ByteSize::from_mib(3072);
+	memory.usage = 25.0;
+	memory.swap_used = Some(ByteSize::ZERO);
+	let mut after = before.clone();
+	let memory = &mut after.memory;
+	memory.used = ByteSize::from_mib(1536);
+	memory.free = ByteSize::from_mib(2560);
+	memory.usage = 37.5;
+	memory.swap_used = None;
+
+	let delta = before.diff(&after).memory;
+	assert_eq!(delta.used, 512 * 1024 * 1024);
+	assert_eq!(delta.free, -512 * 1024 * 1024);
+	assert_eq!(delta.total, 0);
+	assert_eq!(delta.usage, 12.5);
+	assert_eq!(delta.swap_used, None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_snapshot_json() {
+	let snapshot = Snapshot::new();
+	let json = snapshot.to_json().unwrap();
+	let decoded: Snapshot = serde_json::from_str(&json).unwrap();
+
+	assert_eq!(decoded.timestamp, snapshot.timestamp);
+	assert_eq!(decoded.to_json().unwrap(), json);
+	assert!(snapshot.to_json_pretty().unwrap().starts_with("{\n"));
+
+	let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+	assert!(value["timestamp"].is_string());
+	#[cfg(feature = "process")]
+	assert!(value["processes"].is_array());
+
+	let diff = serde_json::to_value(snapshot.diff(&decoded)).unwrap();
+	assert!(diff["from"].is_string());
+}
@@ -1,9 +1,11 @@
+#[cfg(any(feature = "process", feature = "memory", feature = "disk", feature = "gpu"))]
+use puniyu_system_info::ByteSize;
 use puniyu_system_info::SystemInfo;
 
 #[cfg(feature = "host")]
 #[test]
 fn test_host_info() {
-	let host_info = SystemInfo::host();
+	let host_info = SystemInfo::new().host();
 
 	assert!(!host_info.host_name.is_empty());
 	assert!(!host_info.os_name.is_empty());
@@ -15,7 +17,7 @@ fn test_host_info() {
 #[cfg(feature = "cpu")]
 #[test]
 fn test_cpu_info() {
-	let cpu_info = SystemInfo::cpu();
+	let cpu_info = SystemInfo::new().cpu();
 
 	assert!(!cpu_info.model_name.is_empty());
 	assert!(cpu_info.physical_cores > 0);
@@ -31,23 +33,36 @@ fn test_cpu_info() {
 		assert!(temperature > 0.0);
 		assert!(temperature < 150.0);
 	}
+
+	assert!(!cpu_info.cores.is_empty());
+	assert!(cpu_info.cores.windows(2).all(|pair| pair[0].id < pair[1].id));
+	#[cfg(target_os = "linux")]
+	let stat = puniyu_system_info::CpuStat::read().unwrap();
+	for core in &cpu_info.cores {
+		#[cfg(target_os = "linux")]
+		assert!(stat.cores.contains_key(&core.id));
+		assert!((0.0..=100.0).contains(&core.usage));
+		assert!(core.frequency >= 0.0);
+	}
 }
 
 #[cfg(feature = "process")]
 #[test]
 fn test_process_info() {
-	let process_info = SystemInfo::process();
+	let mut system_info = SystemInfo::new();
+	let process_info = system_info.process();
 
 	assert!(process_info.pid.as_u32() > 0);
 	assert!(!process_info.name.is_empty());
 
 	std::thread::sleep(std::time::Duration::from_secs(1));
 
-	let process_info = SystemInfo::process();
+	system_info.refresh_process();
+	let process_info = system_info.process();
 	assert!(process_info.start_time > 0);
 	assert!(process_info.run_time > 0);
 
-	assert!(process_info.used_memory >= 0.0);
+	assert!(process_info.used_memory > ByteSize::ZERO);
 
 	if let Some(cpu_usage) = process_info.cpu_usage {
 		assert!(cpu_usage <= 100.0);
@@ -61,37 +76,90 @@ fn test_process_info() {
 #[cfg(feature = "memory")]
 #[test]
 fn test_memory_info() {
-	let memory_info = SystemInfo::memory();
+	let memory_info = SystemInfo::new().memory();
 
-	assert!(memory_info.total > 0);
-	assert!(memory_info.used > 0);
-	assert!(memory_info.free > 0);
+	assert!(memory_info.total > ByteSize::ZERO);
+	assert!(memory_info.used > ByteSize::ZERO);
+	assert!(memory_info.free > ByteSize::ZERO);
 	assert!(memory_info.usage >= 0.0);
+
+	#[cfg(target_os = "linux")]
+	assert!(memory_info.available.is_some_and(|available| available <= memory_info.total));
 }
 
 #[cfg(feature = "disk")]
 #[test]
 fn test_disk_info() {
-	let disk_info = SystemInfo::disk();
+	let disk_info = SystemInfo::new().disk();
+
+	assert!(disk_info.total_space > ByteSize::ZERO);
+	assert!(disk_info.total_used_space > ByteSize::ZERO);
+	assert!(disk_info.total_free_space > ByteSize::ZERO);
+}
+
+#[cfg(feature = "disk")]
+#[test]
+fn test_disk_details() {
+	let disk_info = SystemInfo::new().disk();
+
+	for disk in &disk_info.disks {
+		assert!(!disk.file_system.is_empty());
+		if let (Some(total), Some(used), Some(free)) =
+			(disk.inodes_total, disk.inodes_used, disk.inodes_free)
+		{
+			assert_eq!(used + free, total);
+		}
+	}
+
+	#[cfg(target_os = "linux")]
+	if let Some(root) = disk_info.disks.iter().find(|disk| disk.mount == "/") {
+		assert!(!root.mount_options.is_empty());
+		assert_eq!(root.read_only, root.mount_options.iter().any(|option| option == "ro"));
+	}
+
+	let filtered = disk_info.clone().without_pseudo_filesystems();
+	assert!(filtered.disks.iter().all(|disk| !disk.is_pseudo()));
+	assert!(filtered.total_space <= disk_info.total_space);
+}
+
+#[cfg(feature = "disk")]
+#[test]
+fn test_disk_io_stats() {
+	use puniyu_system_info::DiskInfo;
+
+	let disk_info = DiskInfo::with_interval(std::time::Duration::from_millis(200));
+
+	assert!(disk_info.read_speed >= 0.0);
+	assert!(disk_info.write_speed >= 0.0);
+	for io in disk_info.disks.iter().filter_map(|disk| disk.io) {
+		assert!((0.0..=100.0).contains(&io.utilization));
+	}
+}
+
+#[cfg(feature = "load")]
+#[test]
+fn test_load_info() {
+	let load_info = SystemInfo::new().load();
+
+	assert!(load_info.one >= 0.0);
+	assert!(load_info.five >= 0.0);
+	assert!(load_info.fifteen >= 0.0);
 
-	assert!(disk_info.total_space > 0);
-	assert!(disk_info.total_used_space > 0);
-	assert!(disk_info.total_free_space > 0);
+	if let (Some(running), Some(total)) = (load_info.running_tasks, load_info.total_tasks) {
+		assert!(running <= total);
+	}
 }
 
 #[cfg(feature = "gpu")]
 #[test]
 fn test_gpu_info() {
-	if let Some(gpu) = SystemInfo::gpu() {
+	if let Some(gpu) = SystemInfo::new().gpu() {
 		assert!(!gpu.model.is_empty());
 		if let Some(total) = gpu.memory_total {
-			assert!(total > 0.0);
-		}
-		if let Some(used) = gpu.memory_used {
-			assert!(used >= 0.0);
-		}
-		if let Some(free) = gpu.memory_free {
-			assert!(free >= 0.0);
+			assert!(total > ByteSize::ZERO);
+			if let (Some(used), Some(free)) = (gpu.memory_used, gpu.memory_free) {
+				assert!(used + free <= total);
+			}
 		}
 		if let Some(usage) = gpu.usage {
 			assert!(usage <= 100);
@@ -100,3 +168,82 @@ fn test_gpu_info() {
 		dbg!("未检测到GPU");
 	}
 }
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_gpus() {
+	let system_info = SystemInfo::new();
+	let gpus = system_info.gpus();
+	for (index, gpu) in gpus.iter().enumerate() {
+		assert_eq!(gpu.index as usize, index);
+		assert!(!gpu.model.is_empty());
+		if let Some(bus_id) = &gpu.pci_bus_id {
+			assert_eq!(bus_id.split(':').count(), 3);
+		}
+	}
+	assert_eq!(system_info.gpu().map(|gpu| gpu.model), gpus.first().map(|gpu| gpu.model.clone()));
+}
+
+#[test]
+fn test_sampler_refresh() {
+	let mut system_info = SystemInfo::new();
+	std::thread::sleep(std::time::Duration::from_millis(250));
+	system_info.refresh_all();
+
+	#[cfg(feature = "cpu")]
+	if let Some(usage) = system_info.cpu().usage {
+		assert!((0.0..=100.0).contains(&usage));
+	}
+
+	#[cfg(all(feature = "cpu", target_os = "linux"))]
+	{
+		let times = system_info.cpu().times.expect("/proc/stat should be sampled twice");
+		assert!((0.0..=100.0).contains(&times.idle));
+	}
+
+	#[cfg(feature = "network")]
+	for network in system_info.network() {
+		assert!(network.upload >= 0.0);
+		assert!(network.download >= 0.0);
+	}
+
+	#[cfg(feature = "disk")]
+	{
+		let disk_info = system_info.disk();
+		assert!(disk_info.read_speed >= 0.0);
+		assert!(disk_info.write_speed >= 0.0);
+	}
+}
+
+#[cfg(feature = "host")]
+#[test]
+fn test_try_host_info() {
+	match SystemInfo::new().try_host() {
+		Ok(host_info) => assert!(!host_info.host_name.is_empty()),
+		Err(err) => assert!(!err.to_string().is_empty()),
+	}
+}
+
+#[cfg(feature = "cpu")]
+#[test]
+fn test_try_cpu_info() {
+	let cpu_info = SystemInfo::new().try_cpu().expect("cpu should be detected");
+	assert!(!cpu_info.model_name.is_empty());
+}
+
+#[cfg(feature = "memory")]
+#[test]
+fn test_try_memory_info() {
+	let memory_info = SystemInfo::new().try_memory().expect("memory should be readable");
+	assert!(memory_info.total > ByteSize::ZERO);
+}
+
+#[cfg(feature = "gpu")]
+#[test]
+fn test_try_gpu_info() {
+	use puniyu_system_info::SystemInfoError;
+
+	if let Err(err) = SystemInfo::new().try_gpu() {
+		assert!(matches!(err, SystemInfoError::GpuNotFound));
+	}
+}
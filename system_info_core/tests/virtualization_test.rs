@@ -0,0 +1,38 @@
+#![cfg(feature = "host")]
+
+use puniyu_system_info::Virtualization;
+
+mod common;
+
+fn detect_fixture(name: &str) -> Virtualization {
+	Virtualization::detect_from_root(common::fixture_path(&format!("virt/{name}")))
+}
+
+#[test]
+fn test_detect_container() {
+	assert_eq!(detect_fixture("docker"), Virtualization::Docker);
+	assert_eq!(detect_fixture("podman"), Virtualization::Podman);
+	assert_eq!(detect_fixture("lxc"), Virtualization::Lxc);
+	assert_eq!(detect_fixture("kubernetes"), Virtualization::Container);
+	assert!(detect_fixture("docker").is_container());
+	assert!(!detect_fixture("docker").is_virtual_machine());
+}
+
+#[test]
+fn test_detect_virtual_machine() {
+	assert_eq!(detect_fixture("wsl"), Virtualization::Wsl);
+	assert_eq!(detect_fixture("kvm"), Virtualization::Kvm);
+	assert_eq!(detect_fixture("xen"), Virtualization::Xen);
+	assert_eq!(detect_fixture("vmware"), Virtualization::VMware);
+	assert_eq!(detect_fixture("hyperv"), Virtualization::HyperV);
+	assert_eq!(detect_fixture("generic"), Virtualization::VirtualMachine);
+	assert!(detect_fixture("kvm").is_virtual_machine());
+}
+
+#[test]
+fn test_detect_bare_metal() {
+	assert_eq!(detect_fixture("bare_metal"), Virtualization::None);
+	assert_eq!(detect_fixture("missing"), Virtualization::None);
+	assert_eq!(Virtualization::None.to_string(), "none");
+	assert_eq!(Virtualization::HyperV.to_string(), "hyper-v");
+}
@@ -0,0 +1,62 @@
+//! 以 HTTP 提供系统信息的导出服务
+//!
+//! - `/metrics` — Prometheus 文本格式
+//! - `/snapshot` — JSON 格式的 [PartialSnapshot](system_info::PartialSnapshot)，只包含启用的模块
+
+mod sampler;
+mod server;
+
+use std::net::{SocketAddr, TcpListener};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use clap::Parser;
+use system_info::{Subsystem, parse_interval};
+
+use crate::sampler::Sampler;
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Serve system information as Prometheus metrics and JSON snapshots")]
+struct Args {
+	/// Address to listen on, port 0 picks a free port
+	#[arg(short, long, default_value = "0.0.0.0:9101")]
+	listen: SocketAddr,
+	/// Seconds between two refreshes of the sampler
+	#[arg(short, long, default_value = "15", value_parser = parse_interval)]
+	interval: Duration,
+	/// Comma separated subsystems to collect, defaults to all
+	#[arg(short, long, value_delimiter = ',', value_parser = parse_subsystem)]
+	subsystems: Vec<Subsystem>,
+	/// Report CPU and memory against the cgroup limits of the container
+	#[arg(long)]
+	container_aware: bool,
+}
+
+fn parse_subsystem(value: &str) -> Result<Subsystem, String> {
+	value.parse().map_err(|err| {
+		let names: Vec<&str> = Subsystem::ALL.iter().map(Subsystem::as_str).collect();
+		format!("{err}, expected one of: {}", names.join(", "))
+	})
+}
+
+fn main() -> ExitCode {
+	let args = Args::parse();
+	let subsystems =
+		if args.subsystems.is_empty() { Subsystem::ALL.to_vec() } else { args.subsystems };
+
+	let listener = match TcpListener::bind(args.listen) {
+		Ok(listener) => listener,
+		Err(err) => {
+			eprintln!("failed to listen on {}: {err}", args.listen);
+			return ExitCode::FAILURE;
+		}
+	};
+	let sampler = Sampler::start(subsystems, args.interval, args.container_aware);
+	match listener.local_addr() {
+		Ok(addr) => println!("listening on http://{addr}"),
+		Err(err) => eprintln!("failed to read listen address: {err}"),
+	}
+
+	server::serve(listener, sampler);
+	ExitCode::SUCCESS
+}
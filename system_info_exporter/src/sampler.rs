@@ -0,0 +1,60 @@
+use std::sync::{Arc, RwLock};
+use std::thread;
+use std::time::Duration;
+
+use system_info::exporter::prometheus;
+use system_info::{Subsystem, SystemInfo};
+
+/// 最近一次刷新后渲染好的响应内容
+#[derive(Debug, Default)]
+pub(crate) struct Rendered {
+	/// Prometheus 文本格式
+	pub(crate) metrics: String,
+	/// JSON 格式的快照
+	pub(crate) snapshot: String,
+}
+
+/// 在后台线程中按固定间隔刷新采样器，并缓存渲染结果供各请求共享
+#[derive(Debug, Clone)]
+pub(crate) struct Sampler {
+	rendered: Arc<RwLock<Arc<Rendered>>>,
+}
+
+impl Sampler {
+	/// 完成首次采样后返回，之后每隔 `interval` 在后台刷新一次
+	pub(crate) fn start(
+		subsystems: Vec<Subsystem>,
+		interval: Duration,
+		container_aware: bool,
+	) -> Self {
+		let mut system_info = SystemInfo::sampled(&subsystems);
+		system_info.set_container_aware(container_aware);
+
+		let sampler =
+			Self { rendered: Arc::new(RwLock::new(Arc::new(render(&system_info, &subsystems)))) };
+		let shared = sampler.rendered.clone();
+		thread::spawn(move || {
+			loop {
+				thread::sleep(interval);
+				system_info.refresh(&subsystems);
+				let rendered = Arc::new(render(&system_info, &subsystems));
+				*shared.write().unwrap_or_else(|err| err.into_inner()) = rendered;
+			}
+		});
+		sampler
+	}
+
+	/// 最近一次刷新的渲染结果
+	pub(crate) fn latest(&self) -> Arc<Rendered> {
+		self.rendered.read().unwrap_or_else(|err| err.into_inner()).clone()
+	}
+}
+
+fn render(system_info: &SystemInfo, subsystems: &[Subsystem]) -> Rendered {
+	let snapshot = system_info.snapshot_of(subsystems);
+	Rendered {
+		metrics: prometheus::render_partial(&snapshot),
+		// 快照中只有字符串键与基本类型，序列化不会失败
+		snapshot: snapshot.to_json().expect("snapshot should serialize to json"),
+	}
+}
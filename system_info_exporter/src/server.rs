@@ -0,0 +1,128 @@
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use system_info::exporter::prometheus;
+
+use crate::sampler::Sampler;
+
+/// 处理请求的线程数，响应已由 [Sampler] 预先生成，少量线程即可
+const WORKERS: usize = 4;
+/// 读写的超时时间，避免空闲连接长期占用工作线程
+const IO_TIMEOUT: Duration = Duration::from_secs(2);
+/// 请求行与请求头的最大长度
+const MAX_HEADER_BYTES: u64 = 16 * 1024;
+
+const INDEX: &str =
+	"system-info-exporter\n\n/metrics   Prometheus metrics\n/snapshot  JSON snapshot\n";
+
+/// 在固定数量的工作线程中接受并处理连接，并发连接数不超过 [WORKERS]
+///
+/// 无法创建工作线程时记录错误并以已有的线程继续服务，当前线程也作为其中一个工作线程
+pub(crate) fn serve(listener: TcpListener, sampler: Sampler) {
+	for worker in 1..WORKERS {
+		let listener = match listener.try_clone() {
+			Ok(listener) => listener,
+			Err(err) => {
+				eprintln!("failed to clone listener: {err}");
+				break;
+			}
+		};
+		let sampler = sampler.clone();
+		if let Err(err) = thread::Builder::new()
+			.name(format!("exporter-worker-{worker}"))
+			.spawn(move || accept(&listener, &sampler))
+		{
+			eprintln!("failed to spawn worker thread: {err}");
+			break;
+		}
+	}
+	accept(&listener, &sampler);
+}
+
+/// 依次接受并处理连接，直到监听出错
+fn accept(listener: &TcpListener, sampler: &Sampler) {
+	for stream in listener.incoming() {
+		match stream {
+			Ok(stream) => match handle(stream, sampler) {
+				Ok(()) => {}
+				// 空闲连接超时断开
+				Err(err) if matches!(err.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+				Err(err) => eprintln!("failed to handle request: {err}"),
+			},
+			Err(err) => eprintln!("failed to accept connection: {err}"),
+		}
+	}
+}
+
+fn handle(stream: TcpStream, sampler: &Sampler) -> std::io::Result<()> {
+	stream.set_read_timeout(Some(IO_TIMEOUT))?;
+	stream.set_write_timeout(Some(IO_TIMEOUT))?;
+	// 请求体不会被读取，请求行与请求头超出长度限制时按已读取的部分处理
+	let mut reader = BufReader::new((&stream).take(MAX_HEADER_BYTES));
+
+	let mut request_line = String::new();
+	reader.read_line(&mut request_line)?;
+	let mut header = String::new();
+	while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+		header.clear();
+	}
+
+	let mut parts = request_line.split_whitespace();
+	let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+		return respond(&stream, "400 Bad Request", "text/plain", b"bad request\n", true);
+	};
+	let with_body = method != "HEAD";
+	if method != "GET" && method != "HEAD" {
+		return respond(
+			&stream,
+			"405 Method Not Allowed",
+			"text/plain",
+			b"method not allowed\n",
+			true,
+		);
+	}
+
+	let path = target.split('?').next().unwrap_or(target);
+	match path {
+		"/metrics" => {
+			let rendered = sampler.latest();
+			respond(
+				&stream,
+				"200 OK",
+				prometheus::CONTENT_TYPE,
+				rendered.metrics.as_bytes(),
+				with_body,
+			)
+		}
+		"/snapshot" => {
+			let rendered = sampler.latest();
+			respond(&stream, "200 OK", "application/json", rendered.snapshot.as_bytes(), with_body)
+		}
+		"/" => respond(&stream, "200 OK", "text/plain; charset=utf-8", INDEX.as_bytes(), with_body),
+		_ => respond(&stream, "404 Not Found", "text/plain", b"not found\n", with_body),
+	}
+}
+
+fn respond(
+	mut stream: &TcpStream,
+	status: &str,
+	content_type: &str,
+	body: &[u8],
+	with_body: bool,
+) -> std::io::Result<()> {
+	let mut response = format!(
+		"HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n",
+		body.len()
+	);
+	if status.starts_with("405") {
+		response.push_str("Allow: GET, HEAD\r\n");
+	}
+	response.push_str("\r\n");
+	stream.write_all(response.as_bytes())?;
+	if with_body {
+		stream.write_all(body)?;
+	}
+	stream.flush()
+}
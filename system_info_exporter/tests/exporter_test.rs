@@ -0,0 +1,131 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::process::{Child, Command, Stdio};
+
+/// 测试结束时结束导出服务进程
+struct Exporter {
+	child: Child,
+	addr: SocketAddr,
+}
+
+impl Exporter {
+	fn start(args: &[&str]) -> Self {
+		let mut child = Command::new(env!("CARGO_BIN_EXE_system-info-exporter"))
+			.args(["--listen", "127.0.0.1:0"])
+			.args(args)
+			.stdout(Stdio::piped())
+			.spawn()
+			.expect("exporter should start");
+
+		let mut line = String::new();
+		BufReader::new(child.stdout.take().unwrap()).read_line(&mut line).unwrap();
+		let addr = line
+			.trim()
+			.strip_prefix("listening on http://")
+			.unwrap_or_else(|| panic!("unexpected startup line `{line}`"))
+			.parse()
+			.unwrap();
+		Self { child, addr }
+	}
+
+	/// 发送请求，返回状态行、响应头与响应体
+	fn request(&self, method: &str, path: &str) -> (String, String, String) {
+		let mut stream = TcpStream::connect(self.addr).unwrap();
+		write!(stream, "{method} {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").unwrap();
+		let mut response = String::new();
+		stream.read_to_string(&mut response).unwrap();
+
+		let (head, body) = response.split_once("\r\n\r\n").expect("response without header end");
+		let (status, headers) = head.split_once("\r\n").unwrap_or((head, ""));
+		(status.to_string(), headers.to_string(), body.to_string())
+	}
+}
+
+impl Drop for Exporter {
+	fn drop(&mut self) {
+		let _ = self.child.kill();
+		let _ = self.child.wait();
+	}
+}
+
+#[test]
+fn test_metrics_endpoint() {
+	let exporter = Exporter::start(&["--interval", "0.2", "--subsystems", "memory,network"]);
+
+	let (status, headers, body) = exporter.request("GET", "/metrics");
+	assert_eq!(status, "HTTP/1.1 200 OK");
+	assert!(headers.contains("Content-Type: text/plain; version=0.0.4"));
+	assert!(headers.contains(&format!("Content-Length: {}", body.len())));
+	assert!(body.contains("# TYPE system_info_memory_total_bytes gauge\n"));
+	assert!(body.contains("# TYPE system_info_network_receive_bytes_total counter\n"));
+	assert!(!body.contains("system_info_cpu_"));
+	assert!(!body.contains("system_info_process_"));
+
+	let (status, _, body) = exporter.request("HEAD", "/metrics");
+	assert_eq!(status, "HTTP/1.1 200 OK");
+	assert!(body.is_empty());
+}
+
+#[test]
+fn test_snapshot_endpoint() {
+	let exporter = Exporter::start(&["--interval", "0.2", "--subsystems", "host,memory"]);
+
+	let (status, headers, body) = exporter.request("GET", "/snapshot");
+	assert_eq!(status, "HTTP/1.1 200 OK");
+	assert!(headers.contains("Content-Type: application/json"));
+	let first: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert!(first["memory"]["total"].as_u64().unwrap() > 0);
+	assert!(first["host"]["host_name"].is_string());
+	assert!(first.get("cpu").is_none());
+	assert!(first.get("processes").is_none());
+
+	// 后台刷新后返回新的快照
+	std::thread::sleep(std::time::Duration::from_millis(600));
+	let (_, _, body) = exporter.request("GET", "/snapshot?pretty");
+	let second: serde_json::Value = serde_json::from_str(&body).unwrap();
+	assert_ne!(first["timestamp"], second["timestamp"]);
+}
+
+#[test]
+fn test_unknown_routes() {
+	let exporter = Exporter::start(&["--subsystems", "memory"]);
+
+	let (status, _, body) = exporter.request("GET", "/");
+	assert_eq!(status, "HTTP/1.1 200 OK");
+	assert!(body.contains("/metrics"));
+	assert_eq!(exporter.request("GET", "/missing").0, "HTTP/1.1 404 Not Found");
+
+	let (status, headers, _) = exporter.request("POST", "/metrics");
+	assert_eq!(status, "HTTP/1.1 405 Method Not Allowed");
+	assert!(headers.contains("Allow: GET, HEAD"));
+}
+
+#[test]
+fn test_idle_connections() {
+	let exporter = Exporter::start(&["--subsystems", "memory"]);
+
+	// 空闲连接超过工作线程数时，超时断开后仍能继续服务
+	let idle: Vec<TcpStream> =
+		(0..16).map(|_| TcpStream::connect(exporter.addr).unwrap()).collect();
+	let (status, _, _) = exporter.request("GET", "/metrics");
+	assert_eq!(status, "HTTP/1.1 200 OK");
+	drop(idle);
+}
+
+#[test]
+fn test_invalid_arguments() {
+	let run = |args: &[&str]| {
+		Command::new(env!("CARGO_BIN_EXE_system-info-exporter"))
+			.args(args)
+			.stderr(Stdio::piped())
+			.output()
+			.unwrap()
+	};
+
+	let output = run(&["--subsystems", "memory,bogus"]);
+	assert!(!output.status.success());
+	assert!(String::from_utf8_lossy(&output.stderr).contains("unknown subsystem `bogus`"));
+
+	let output = run(&["--interval", "0"]);
+	assert!(!output.status.success());
+}
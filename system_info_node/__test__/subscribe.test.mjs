@@ -0,0 +1,36 @@
+import assert from 'node:assert/strict'
+import { setTimeout as sleep } from 'node:timers/promises'
+import { test } from 'node:test'
+
+import { subscribe } from '../dist/index.js'
+
+test('unsubscribe stops further callbacks', async () => {
+  let calls = 0
+  const subscription = subscribe({ interval: 10 }, (err, info) => {
+    assert.equal(err, null)
+    assert.ok(info.memory.total > 0)
+    calls += 1
+  })
+
+  while (calls === 0) {
+    await sleep(10)
+  }
+  subscription.unsubscribe()
+  assert.equal(subscription.active, false)
+
+  // 已排队或采样中的推送都不应再调用回调
+  const stoppedAt = calls
+  await sleep(500)
+  assert.equal(calls, stoppedAt)
+})
+
+test('unsubscribe before the first push', async () => {
+  let calls = 0
+  const subscription = subscribe({ interval: 10 }, () => {
+    calls += 1
+  })
+  subscription.unsubscribe()
+
+  await sleep(500)
+  assert.equal(calls, 0)
+})
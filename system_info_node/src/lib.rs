@@ -1,73 +1,97 @@
 use napi_derive::napi;
-use system_info::SystemInfo;
+use system_info::{
+	CpuInfo, DiskInfo, GpuInfo, HostInfo, LoadInfo, MemoryInfo, NetworkInfo, ProcessInfo,
+};
 
+mod subscription;
+pub use subscription::{SubscribeOptions, Subscription, subscribe};
+mod task;
+pub use task::{
+	get_all_system_info_async, get_cpu_info_async, get_current_network_async,
+	get_current_process_info_async, get_disk_info_async, get_gpu_info_async, get_gpus_async,
+	get_host_info_async, get_load_info_async, get_memory_info_async, get_network_info_async,
+	get_process_info_async,
+};
 mod types;
 
 #[napi]
 /// 获取主机信息
 pub fn get_host_info() -> types::HostInfo {
-	SystemInfo::host().into()
+	HostInfo::new().into()
 }
 
 #[napi]
 /// 获取网络信息
 pub fn get_network_info() -> Vec<types::NetworkInfo> {
-	let network_infos = SystemInfo::network();
+	let network_infos = NetworkInfo::all();
 	network_infos.into_iter().map(|info| info.into()).collect()
 }
 
 #[napi]
 /// 获取当前网络信息
 pub fn get_current_network() -> types::NetworkInfo {
-	SystemInfo::current_network().into()
+	NetworkInfo::new().into()
 }
 #[napi]
 /// 获取进程信息
 pub fn get_process_info(pid: u32) -> types::ProcessInfo {
-	SystemInfo::process_with_pid(pid).into()
+	ProcessInfo::new(pid).into()
 }
 
 #[napi]
 /// 获取当前进程信息
 pub fn get_current_process_info() -> types::ProcessInfo {
-	SystemInfo::process().into()
+	ProcessInfo::default().into()
 }
 
 #[napi]
 /// 获取CPU信息
 pub fn get_cpu_info() -> types::CpuInfo {
-	SystemInfo::cpu().into()
+	CpuInfo::new().into()
 }
 
 #[napi]
 /// 获取内存信息
 pub fn get_memory_info() -> types::MemoryInfo {
-	SystemInfo::memory().into()
+	MemoryInfo::new().into()
 }
 
 #[napi]
 /// 获取硬盘信息
 pub fn get_disk_info() -> types::DiskInfo {
-	SystemInfo::disk().into()
+	DiskInfo::new().into()
 }
 
 #[napi]
-/// 获取GPU信息
+/// 获取第一个GPU的信息
 pub fn get_gpu_info() -> Option<types::GpuInfo> {
-	SystemInfo::gpu().map(|g| g.into())
+	GpuInfo::new().map(|g| g.into())
 }
 
 #[napi]
-/// 获取全部系统信息（主机、CPU、内存、磁盘、网络、当前进程、GPU）
+/// 获取所有GPU的信息
+pub fn get_gpus() -> Vec<types::GpuInfo> {
+	GpuInfo::all().into_iter().map(|g| g.into()).collect()
+}
+
+#[napi]
+/// 获取系统负载信息
+pub fn get_load_info() -> types::LoadInfo {
+	LoadInfo::new().into()
+}
+
+#[napi]
+/// 获取全部系统信息（主机、CPU、内存、磁盘、网络、当前进程、GPU、负载）
 pub fn get_all_system_info() -> types::AllSystemInfo {
 	types::AllSystemInfo {
-		host: SystemInfo::host().into(),
-		cpu: SystemInfo::cpu().into(),
-		memory: SystemInfo::memory().into(),
-		disk: SystemInfo::disk().into(),
-		networks: SystemInfo::network().into_iter().map(|info| info.into()).collect(),
-		current_network: SystemInfo::current_network().into(),
-		current_process: SystemInfo::process().into(),
-		gpu: SystemInfo::gpu().map(|g| g.into()),
+		host: HostInfo::new().into(),
+		cpu: CpuInfo::new().into(),
+		memory: MemoryInfo::new().into(),
+		disk: DiskInfo::new().into(),
+		networks: NetworkInfo::all().into_iter().map(|info| info.into()).collect(),
+		current_network: NetworkInfo::new().into(),
+		current_process: ProcessInfo::default().into(),
+		gpu: GpuInfo::new().map(|g| g.into()),
+		load: LoadInfo::new().into(),
 	}
 }
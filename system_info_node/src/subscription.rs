@@ -0,0 +1,147 @@
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use napi::bindgen_prelude::{FnArgs, Function, Null, Unknown};
+use napi::threadsafe_function::{ThreadsafeCallContext, ThreadsafeFunctionCallMode};
+use napi::{Env, Error, Result, Status};
+use napi_derive::napi;
+use system_info::SystemInfo;
+
+use crate::types;
+
+/// 默认推送间隔(单位: 毫秒)
+const DEFAULT_INTERVAL: u32 = 1000;
+/// 创建采样器后到首次推送之间的等待时间
+const FIRST_SAMPLE_DELAY: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, Default)]
+#[napi(object)]
+pub struct SubscribeOptions {
+	/// 推送间隔(单位: 毫秒)，默认 1000
+	pub interval: Option<u32>,
+}
+
+/// 后台线程的停止信号，可在等待期间立即唤醒线程
+#[derive(Debug, Default)]
+struct StopSignal {
+	stopped: Mutex<bool>,
+	condvar: Condvar,
+}
+
+impl StopSignal {
+	fn stop(&self) {
+		*self.stopped.lock().unwrap_or_else(|err| err.into_inner()) = true;
+		self.condvar.notify_all();
+	}
+
+	fn is_stopped(&self) -> bool {
+		*self.stopped.lock().unwrap_or_else(|err| err.into_inner())
+	}
+
+	/// 等待指定时间，期间收到停止信号时返回 `true`
+	fn wait(&self, timeout: Duration) -> bool {
+		let stopped = self.stopped.lock().unwrap_or_else(|err| err.into_inner());
+		let (stopped, _) = self
+			.condvar
+			.wait_timeout_while(stopped, timeout, |stopped| !*stopped)
+			.unwrap_or_else(|err| err.into_inner());
+		*stopped
+	}
+}
+
+/// [subscribe] 返回的订阅句柄
+///
+/// 句柄被垃圾回收不会停止推送，需显式调用 `unsubscribe()`
+#[napi]
+pub struct Subscription {
+	signal: Arc<StopSignal>,
+}
+
+#[napi]
+impl Subscription {
+	/// 停止推送，可重复调用，返回后回调不会再被调用
+	#[napi]
+	pub fn unsubscribe(&self) {
+		self.signal.stop();
+	}
+
+	/// 是否仍在推送
+	#[napi(getter)]
+	pub fn active(&self) -> bool {
+		!self.signal.is_stopped()
+	}
+}
+
+#[napi]
+/// 订阅系统信息，在后台线程中持续采样并按间隔推送全部系统信息
+///
+/// 首次推送在约 200ms 后进行，之后每隔 `interval` 毫秒推送一次，回调参数为 `(err, info)`
+pub fn subscribe(
+	env: Env,
+	options: Option<SubscribeOptions>,
+	callback: Function<FnArgs<(Null, types::AllSystemInfo)>, Unknown<'static>>,
+) -> Result<Subscription> {
+	let interval = options.unwrap_or_default().interval.unwrap_or(DEFAULT_INTERVAL);
+	if interval == 0 {
+		return Err(Error::new(
+			Status::InvalidArg,
+			"interval must be a positive number of milliseconds",
+		));
+	}
+	let interval = Duration::from_millis(interval as u64);
+
+	let signal = Arc::new(StopSignal::default());
+	// 推送在 JS 线程中再次检查停止信号，`unsubscribe()` 之前已排队的推送也不会调用回调
+	let callback = callback.create_ref()?;
+	let js_signal = signal.clone();
+	let noop = env.create_function_from_closure::<(), (), _>("subscription", |_| Ok(()))?;
+	let push = noop.build_threadsafe_function().build_callback(
+		move |ctx: ThreadsafeCallContext<types::AllSystemInfo>| {
+			if !js_signal.is_stopped() {
+				callback.borrow_back(&ctx.env)?.call(FnArgs::from((Null, ctx.value)))?;
+			}
+			Ok(())
+		},
+	)?;
+
+	let thread_signal = signal.clone();
+	thread::Builder::new()
+		.name("system-info-subscription".to_string())
+		.spawn(move || {
+			let mut system_info = SystemInfo::new();
+			let mut delay = FIRST_SAMPLE_DELAY;
+			// 回调在线程退出时随闭包一起释放，之后 Node 进程不再因订阅而保持运行
+			while !thread_signal.wait(delay) {
+				system_info.refresh_all();
+				let info = collect(&system_info);
+				// 采样期间已取消订阅
+				if thread_signal.is_stopped() {
+					break;
+				}
+				let status = push.call(info, ThreadsafeFunctionCallMode::NonBlocking);
+				// 环境正在关闭
+				if status == Status::Closing {
+					break;
+				}
+				delay = interval;
+			}
+		})
+		.map_err(|err| Error::from_reason(format!("failed to spawn subscription thread: {err}")))?;
+
+	Ok(Subscription { signal })
+}
+
+fn collect(system_info: &SystemInfo) -> types::AllSystemInfo {
+	types::AllSystemInfo {
+		host: system_info.host().into(),
+		cpu: system_info.cpu().into(),
+		memory: system_info.memory().into(),
+		disk: system_info.disk().into(),
+		networks: system_info.network().into_iter().map(|info| info.into()).collect(),
+		current_network: system_info.current_network().into(),
+		current_process: system_info.process().into(),
+		gpu: system_info.gpu().map(|g| g.into()),
+		load: system_info.load().into(),
+	}
+}
@@ -0,0 +1,99 @@
+//! 各同步函数对应的异步版本，在 libuv 线程池中执行，返回 `Promise`
+
+use napi::bindgen_prelude::AsyncTask;
+use napi::{Env, Result, Task};
+use napi_derive::napi;
+
+use crate::types;
+
+/// 生成在线程池中调用同步函数的 [Task] 及返回 [AsyncTask] 的导出函数
+macro_rules! async_task {
+	(
+		#[doc = $doc:literal]
+		fn $name:ident($($arg:ident: $arg_ty:ty),*) -> $task:ident<$output:ty> = $sync:path;
+	) => {
+		pub struct $task {
+			$($arg: $arg_ty,)*
+		}
+
+		#[napi]
+		impl Task for $task {
+			type Output = $output;
+			type JsValue = $output;
+
+			fn compute(&mut self) -> Result<Self::Output> {
+				Ok($sync($(self.$arg),*))
+			}
+
+			fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+				Ok(output)
+			}
+		}
+
+		#[napi]
+		#[doc = $doc]
+		pub fn $name($($arg: $arg_ty),*) -> AsyncTask<$task> {
+			AsyncTask::new($task { $($arg),* })
+		}
+	};
+}
+
+async_task! {
+	#[doc = "异步获取主机信息"]
+	fn get_host_info_async() -> HostInfoTask<types::HostInfo> = crate::get_host_info;
+}
+
+async_task! {
+	#[doc = "异步获取网络信息"]
+	fn get_network_info_async() -> NetworkInfoTask<Vec<types::NetworkInfo>> = crate::get_network_info;
+}
+
+async_task! {
+	#[doc = "异步获取当前网络信息"]
+	fn get_current_network_async() -> CurrentNetworkTask<types::NetworkInfo> = crate::get_current_network;
+}
+
+async_task! {
+	#[doc = "异步获取进程信息"]
+	fn get_process_info_async(pid: u32) -> ProcessInfoTask<types::ProcessInfo> = crate::get_process_info;
+}
+
+async_task! {
+	#[doc = "异步获取当前进程信息"]
+	fn get_current_process_info_async() -> CurrentProcessInfoTask<types::ProcessInfo> = crate::get_current_process_info;
+}
+
+async_task! {
+	#[doc = "异步获取CPU信息"]
+	fn get_cpu_info_async() -> CpuInfoTask<types::CpuInfo> = crate::get_cpu_info;
+}
+
+async_task! {
+	#[doc = "异步获取内存信息"]
+	fn get_memory_info_async() -> MemoryInfoTask<types::MemoryInfo> = crate::get_memory_info;
+}
+
+async_task! {
+	#[doc = "异步获取硬盘信息"]
+	fn get_disk_info_async() -> DiskInfoTask<types::DiskInfo> = crate::get_disk_info;
+}
+
+async_task! {
+	#[doc = "异步获取第一个GPU的信息"]
+	fn get_gpu_info_async() -> GpuInfoTask<Option<types::GpuInfo>> = crate::get_gpu_info;
+}
+
+async_task! {
+	#[doc = "异步获取所有GPU的信息"]
+	fn get_gpus_async() -> GpusTask<Vec<types::GpuInfo>> = crate::get_gpus;
+}
+
+async_task! {
+	#[doc = "异步获取系统负载信息"]
+	fn get_load_info_async() -> LoadInfoTask<types::LoadInfo> = crate::get_load_info;
+}
+
+async_task! {
+	#[doc = "异步获取全部系统信息（主机、CPU、内存、磁盘、网络、当前进程、GPU、负载）"]
+	fn get_all_system_info_async() -> AllSystemInfoTask<types::AllSystemInfo> = crate::get_all_system_info;
+}
@@ -20,6 +20,8 @@ pub struct HostInfo {
 	pub boot_time: DateTime<Utc>,
 	/// 系统运行时间， 单位：秒
 	pub uptime: u32,
+	/// 容器或虚拟化类型，如 `docker`、`kvm`，未检测到时为 `none`
+	pub virtualization: String,
 }
 
 impl From<system_info::HostInfo> for HostInfo {
@@ -33,6 +35,7 @@ impl From<system_info::HostInfo> for HostInfo {
 			time_zone: host_info.time_zone,
 			boot_time: host_info.boot_time,
 			uptime: host_info.uptime as u32,
+			virtualization: host_info.virtualization.to_string(),
 		}
 	}
 }
@@ -54,6 +57,8 @@ pub struct NetworkInfo {
 	pub total_download: f64,
 	/// 网卡mac地址
 	pub mac_addr: String,
+	/// 网卡的PCI设备信息，虚拟网卡为 null
+	pub device: Option<NetworkDevice>,
 }
 
 impl From<system_info::NetworkInfo> for NetworkInfo {
@@ -63,9 +68,43 @@ impl From<system_info::NetworkInfo> for NetworkInfo {
 			ip_info: network_info.ip_info.into_iter().map(|ip_info| ip_info.into()).collect(),
 			upload: network_info.upload,
 			download: network_info.download,
-			total_upload: network_info.total_upload,
-			total_download: network_info.total_download,
+			total_upload: network_info.total_upload.as_mib(),
+			total_download: network_info.total_download.as_mib(),
 			mac_addr: network_info.mac_addr.to_string(),
+			device: network_info.device.map(|device| device.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct NetworkDevice {
+	/// PCI厂商ID
+	pub vendor_id: u32,
+	/// PCI设备ID
+	pub device_id: u32,
+	/// 厂商名称
+	pub vendor: Option<String>,
+	/// 设备名称
+	pub model: Option<String>,
+	/// 子系统名称
+	pub subsystem: Option<String>,
+	/// 内核驱动
+	pub driver: Option<String>,
+	/// PCI总线地址
+	pub pci_bus_id: Option<String>,
+}
+
+impl From<system_info::NetworkDevice> for NetworkDevice {
+	fn from(device: system_info::NetworkDevice) -> Self {
+		Self {
+			vendor_id: device.vendor_id as u32,
+			device_id: device.device_id as u32,
+			vendor: device.vendor,
+			model: device.model,
+			subsystem: device.subsystem,
+			driver: device.driver,
+			pci_bus_id: device.pci_bus_id,
 		}
 	}
 }
@@ -104,6 +143,8 @@ pub struct ProcessInfo {
 	pub memory_usage: Option<f64>,
 	/// 进程已用内存(单位: MB)
 	pub used_memory: f64,
+	/// 进程在各GPU上的显存占用与使用率
+	pub gpus: Option<Vec<ProcessGpuUsage>>,
 }
 
 impl From<system_info::ProcessInfo> for ProcessInfo {
@@ -111,12 +152,39 @@ impl From<system_info::ProcessInfo> for ProcessInfo {
 		Self {
 			pid: process_info.pid.as_u32(),
 			name: process_info.name,
-			sub_list: process_info.sub_list.map(|list| list.into_iter().map(|p| p.into()).collect()),
+			sub_list: process_info
+				.sub_list
+				.map(|list| list.into_iter().map(|p| p.into()).collect()),
 			start_time: process_info.start_time as u32,
 			run_time: process_info.run_time as u32,
 			cpu_usage: process_info.cpu_usage.map(|d| d as f64),
 			memory_usage: process_info.memory_usage.map(|d| d as f64),
-			used_memory: process_info.used_memory,
+			used_memory: process_info.used_memory.as_mib(),
+			gpus: process_info.gpus.map(|gpus| gpus.into_iter().map(|gpu| gpu.into()).collect()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct ProcessGpuUsage {
+	/// GPU的PCI总线地址
+	pub pci_bus_id: Option<String>,
+	/// 内核驱动
+	pub driver: Option<String>,
+	/// 占用的显存(单位: MB)
+	pub memory: Option<f64>,
+	/// GPU使用率
+	pub usage: Option<f64>,
+}
+
+impl From<system_info::ProcessGpuUsage> for ProcessGpuUsage {
+	fn from(usage: system_info::ProcessGpuUsage) -> Self {
+		Self {
+			pci_bus_id: usage.pci_bus_id,
+			driver: usage.driver,
+			memory: usage.memory.map(|v| v.as_mib()),
+			usage: usage.usage.map(|v| v as f64),
 		}
 	}
 }
@@ -136,6 +204,10 @@ pub struct CpuInfo {
 	pub usage: Option<f64>,
 	/// CPU温度(单位: °C)
 	pub temperature: Option<f64>,
+	/// 各逻辑CPU详细信息
+	pub cores: Vec<CoreInfo>,
+	/// CPU时间占比，仅 Linux 下可用
+	pub times: Option<CpuTimePercent>,
 }
 
 impl From<system_info::CpuInfo> for CpuInfo {
@@ -147,6 +219,80 @@ impl From<system_info::CpuInfo> for CpuInfo {
 			frequency: cpu_info.frequency as f64,
 			usage: cpu_info.usage.map(|d| d as f64),
 			temperature: cpu_info.temperature.map(|d| d as f64),
+			cores: cpu_info.cores.into_iter().map(|core| core.into()).collect(),
+			times: cpu_info.times.map(|times| times.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct CoreInfo {
+	/// 逻辑CPU编号
+	pub id: u32,
+	/// 使用率
+	pub usage: f64,
+	/// 当前频率(单位: GHz)
+	pub frequency: f64,
+	/// 所属物理核心编号
+	pub core_id: Option<u32>,
+	/// 所属CPU封装(插槽)编号
+	pub package_id: Option<u32>,
+	/// 所属NUMA节点编号
+	pub numa_node: Option<u32>,
+	/// CPU时间占比，仅 Linux 下可用
+	pub times: Option<CpuTimePercent>,
+}
+
+impl From<system_info::CoreInfo> for CoreInfo {
+	fn from(core_info: system_info::CoreInfo) -> Self {
+		Self {
+			id: core_info.id,
+			usage: core_info.usage as f64,
+			frequency: core_info.frequency as f64,
+			core_id: core_info.core_id,
+			package_id: core_info.package_id,
+			numa_node: core_info.numa_node,
+			times: core_info.times.map(|times| times.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct CpuTimePercent {
+	/// 用户态(含 guest)
+	pub user: f64,
+	/// 低优先级用户态
+	pub nice: f64,
+	/// 内核态
+	pub system: f64,
+	/// 空闲
+	pub idle: f64,
+	/// 等待IO
+	pub iowait: f64,
+	/// 硬中断
+	pub irq: f64,
+	/// 软中断
+	pub softirq: f64,
+	/// 被虚拟化宿主占用
+	pub steal: f64,
+	/// 运行虚拟机
+	pub guest: f64,
+}
+
+impl From<system_info::CpuTimePercent> for CpuTimePercent {
+	fn from(times: system_info::CpuTimePercent) -> Self {
+		Self {
+			user: times.user as f64,
+			nice: times.nice as f64,
+			system: times.system as f64,
+			idle: times.idle as f64,
+			iowait: times.iowait as f64,
+			irq: times.irq as f64,
+			softirq: times.softirq as f64,
+			steal: times.steal as f64,
+			guest: times.guest as f64,
 		}
 	}
 }
@@ -155,34 +301,76 @@ impl From<system_info::CpuInfo> for CpuInfo {
 #[napi(object)]
 pub struct MemoryInfo {
 	/// 总内存(单位: MB)
-	pub total: u32,
+	pub total: f64,
 	/// 内存使用率
 	pub usage: f64,
 	/// 已用内存(单位: MB)
-	pub used: u32,
-	/// 可用内存(单位: MB)
-	pub free: u32,
+	pub used: f64,
+	/// 空闲内存(单位: MB)
+	///
+	/// Linux 下为 `/proc/meminfo` 的 `MemFree`，不含可回收的页缓存，通常远小于实际可分配的内存；
+	/// 其余平台为总内存减去已用内存。判断还能分配多少内存请使用 `available`
+	pub free: f64,
 	/// 交换内存(单位: MB)
-	pub swap_total: Option<u32>,
+	pub swap_total: Option<f64>,
 	/// 交换内存已用(单位: MB)
-	pub swap_used: Option<u32>,
+	pub swap_used: Option<f64>,
 	/// 交换内存可用(单位: MB)
-	pub swap_free: Option<u32>,
+	pub swap_free: Option<f64>,
 	/// 交换内存使用率
 	pub swap_usage: Option<f64>,
+	/// 可分配内存(单位: MB)，仅 Linux 下可用
+	pub available: Option<f64>,
+	/// 块设备缓冲区(单位: MB)，仅 Linux 下可用
+	pub buffers: Option<f64>,
+	/// 页缓存(单位: MB)，仅 Linux 下可用
+	pub cached: Option<f64>,
+	/// 共享内存(单位: MB)，仅 Linux 下可用
+	pub shared: Option<f64>,
+	/// 可回收的内核 slab(单位: MB)，仅 Linux 下可用
+	pub slab_reclaimable: Option<f64>,
+	/// 不可回收的内核 slab(单位: MB)，仅 Linux 下可用
+	pub slab_unreclaimable: Option<f64>,
+	/// 等待写回磁盘的脏页(单位: MB)，仅 Linux 下可用
+	pub dirty: Option<f64>,
+	/// 正在写回磁盘的页(单位: MB)，仅 Linux 下可用
+	pub writeback: Option<f64>,
+	/// 大页总数，仅 Linux 下可用
+	pub hugepages_total: Option<u32>,
+	/// 空闲大页数，仅 Linux 下可用
+	pub hugepages_free: Option<u32>,
+	/// 大页大小(单位: KB)，仅 Linux 下可用
+	pub hugepage_size: Option<f64>,
+	/// 已承诺分配的内存(单位: MB)，仅 Linux 下可用
+	pub committed_as: Option<f64>,
+	/// 可承诺分配的内存上限(单位: MB)，仅 Linux 下可用
+	pub commit_limit: Option<f64>,
 }
 
 impl From<system_info::MemoryInfo> for MemoryInfo {
 	fn from(memory_info: system_info::MemoryInfo) -> Self {
 		Self {
-			total: memory_info.total as u32,
+			total: memory_info.total.as_mib(),
 			usage: memory_info.usage as f64,
-			used: memory_info.used as u32,
-			free: memory_info.free as u32,
-			swap_total: memory_info.swap_total.map(|d| d as u32),
-			swap_used: memory_info.swap_used.map(|d| d as u32),
-			swap_free: memory_info.swap_free.map(|d| d as u32),
+			used: memory_info.used.as_mib(),
+			free: memory_info.free.as_mib(),
+			swap_total: memory_info.swap_total.map(|d| d.as_mib()),
+			swap_used: memory_info.swap_used.map(|d| d.as_mib()),
+			swap_free: memory_info.swap_free.map(|d| d.as_mib()),
 			swap_usage: memory_info.swap_usage.map(|d| d as f64),
+			available: memory_info.available.map(|d| d.as_mib()),
+			buffers: memory_info.buffers.map(|d| d.as_mib()),
+			cached: memory_info.cached.map(|d| d.as_mib()),
+			shared: memory_info.shared.map(|d| d.as_mib()),
+			slab_reclaimable: memory_info.slab_reclaimable.map(|d| d.as_mib()),
+			slab_unreclaimable: memory_info.slab_unreclaimable.map(|d| d.as_mib()),
+			dirty: memory_info.dirty.map(|d| d.as_mib()),
+			writeback: memory_info.writeback.map(|d| d.as_mib()),
+			hugepages_total: memory_info.hugepages_total.map(|d| d as u32),
+			hugepages_free: memory_info.hugepages_free.map(|d| d as u32),
+			hugepage_size: memory_info.hugepage_size.map(|d| d.as_kib()),
+			committed_as: memory_info.committed_as.map(|d| d.as_mib()),
+			commit_limit: memory_info.commit_limit.map(|d| d.as_mib()),
 		}
 	}
 }
@@ -191,11 +379,11 @@ impl From<system_info::MemoryInfo> for MemoryInfo {
 #[napi(object)]
 pub struct DiskInfo {
 	/// 总磁盘空间(单位: GB)
-	pub total_space: u32,
+	pub total_space: f64,
 	/// 总已用磁盘空间(单位: GB)
-	pub total_used_space: u32,
+	pub total_used_space: f64,
 	/// 总可用磁盘空间(单位: GB)
-	pub total_free_space: u32,
+	pub total_free_space: f64,
 	/// 总体磁盘使用率
 	pub total_usage: f64,
 	/// 磁盘读速度(单位: MB/S)
@@ -209,9 +397,9 @@ pub struct DiskInfo {
 impl From<system_info::DiskInfo> for DiskInfo {
 	fn from(disk_info: system_info::DiskInfo) -> Self {
 		Self {
-			total_space: disk_info.total_space as u32,
-			total_used_space: disk_info.total_used_space as u32,
-			total_free_space: disk_info.total_free_space as u32,
+			total_space: disk_info.total_space.as_gib(),
+			total_used_space: disk_info.total_used_space.as_gib(),
+			total_free_space: disk_info.total_free_space.as_gib(),
 			total_usage: disk_info.total_usage,
 			read_speed: disk_info.read_speed as f64,
 			write_speed: disk_info.write_speed as f64,
@@ -227,13 +415,33 @@ pub struct DiskDetail {
 	/// 磁盘挂载点
 	pub mount: String,
 	/// 总磁盘空间(单位: GB)
-	pub total_space: u32,
+	pub total_space: f64,
 	/// 已用磁盘空间(单位: GB)
-	pub used_space: u32,
+	pub used_space: f64,
 	/// 可用磁盘空间(单位: GB)
-	pub free_space: u32,
+	pub free_space: f64,
 	/// 磁盘使用率
 	pub usage: f64,
+	/// 文件系统类型，如 `ext4`、`ntfs`、`tmpfs`
+	pub file_system: String,
+	/// 底层设备类型，可能为 `ssd`、`hdd`、`nvme`、`network`、`tmpfs`、`unknown`
+	pub kind: String,
+	/// 是否为可移动设备
+	pub removable: bool,
+	/// 是否以只读方式挂载
+	pub read_only: bool,
+	/// 挂载选项，仅 Linux 下可用
+	pub mount_options: Vec<String>,
+	/// inode 总数
+	pub inodes_total: Option<f64>,
+	/// 已用 inode 数
+	pub inodes_used: Option<f64>,
+	/// 可用 inode 数
+	pub inodes_free: Option<f64>,
+	/// inode 使用率
+	pub inode_usage: Option<f64>,
+	/// 块设备在采样间隔内的IO统计，仅 Linux 下可用
+	pub io: Option<DiskIoStats>,
 }
 
 impl From<system_info::DiskDetail> for DiskDetail {
@@ -241,10 +449,65 @@ impl From<system_info::DiskDetail> for DiskDetail {
 		Self {
 			name: disk_detail.name,
 			mount: disk_detail.mount,
-			total_space: disk_detail.total_space as u32,
-			used_space: disk_detail.used_space as u32,
-			free_space: disk_detail.free_space as u32,
+			total_space: disk_detail.total_space.as_gib(),
+			used_space: disk_detail.used_space.as_gib(),
+			free_space: disk_detail.free_space.as_gib(),
 			usage: disk_detail.usage as f64,
+			file_system: disk_detail.file_system,
+			kind: disk_detail.kind.to_string(),
+			removable: disk_detail.removable,
+			read_only: disk_detail.read_only,
+			mount_options: disk_detail.mount_options,
+			inodes_total: disk_detail.inodes_total.map(|inodes| inodes as f64),
+			inodes_used: disk_detail.inodes_used.map(|inodes| inodes as f64),
+			inodes_free: disk_detail.inodes_free.map(|inodes| inodes as f64),
+			inode_usage: disk_detail.inode_usage.map(|usage| usage as f64),
+			io: disk_detail.io.map(Into::into),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct DiskIoStats {
+	/// 完成的读请求数
+	pub reads_completed: f64,
+	/// 完成的写请求数
+	pub writes_completed: f64,
+	/// 读取的扇区数(每扇区 512 字节)
+	pub sectors_read: f64,
+	/// 写入的扇区数(每扇区 512 字节)
+	pub sectors_written: f64,
+	/// 读速度(单位: KB/S)
+	pub read_speed: f64,
+	/// 写入速度(单位: KB/S)
+	pub write_speed: f64,
+	/// 设备处理IO的时间(单位: 毫秒)
+	pub io_time: f64,
+	/// 采样结束时正在处理的请求数
+	pub in_flight: u32,
+	/// 设备繁忙时间占比(单位: %)
+	pub utilization: f64,
+	/// 读请求平均延迟(单位: 毫秒)
+	pub read_latency: Option<f64>,
+	/// 写请求平均延迟(单位: 毫秒)
+	pub write_latency: Option<f64>,
+}
+
+impl From<system_info::DiskIoStats> for DiskIoStats {
+	fn from(io: system_info::DiskIoStats) -> Self {
+		Self {
+			reads_completed: io.reads_completed as f64,
+			writes_completed: io.writes_completed as f64,
+			sectors_read: io.sectors_read as f64,
+			sectors_written: io.sectors_written as f64,
+			read_speed: io.read_speed as f64,
+			write_speed: io.write_speed as f64,
+			io_time: io.io_time as f64,
+			in_flight: io.in_flight as u32,
+			utilization: io.utilization as f64,
+			read_latency: io.read_latency.map(|latency| latency as f64),
+			write_latency: io.write_latency.map(|latency| latency as f64),
 		}
 	}
 }
@@ -252,8 +515,20 @@ impl From<system_info::DiskDetail> for DiskDetail {
 #[derive(Debug, Clone)]
 #[napi(object)]
 pub struct GpuInfo {
+	/// GPU序号
+	pub index: u32,
 	/// GPU型号
 	pub model: String,
+	/// 厂商名称
+	pub vendor: Option<String>,
+	/// PCI厂商ID
+	pub vendor_id: Option<u32>,
+	/// PCI设备ID
+	pub device_id: Option<u32>,
+	/// 内核驱动
+	pub driver: Option<String>,
+	/// PCI总线地址，如 `0000:01:00.0`
+	pub pci_bus_id: Option<String>,
 	/// GPU总内存(单位: MB)
 	pub memory_total: Option<f64>,
 	/// GPU已用内存(单位: MB)
@@ -262,16 +537,177 @@ pub struct GpuInfo {
 	pub memory_free: Option<f64>,
 	/// GPU使用率
 	pub usage: Option<u32>,
+	/// GPU温度(单位: 摄氏度)
+	pub temperature: Option<f64>,
+	/// 显存控制器使用率
+	pub memory_controller_usage: Option<u32>,
+	/// 视频编码器使用率
+	pub encoder_usage: Option<u32>,
+	/// 视频解码器使用率
+	pub decoder_usage: Option<u32>,
+	/// 风扇转速(%)
+	pub fan_speed: Option<u32>,
+	/// 功耗(单位: 瓦)
+	pub power_usage: Option<f64>,
+	/// 功耗上限(单位: 瓦)
+	pub power_limit: Option<f64>,
+	/// 图形核心频率(单位: MHz)
+	pub graphics_clock: Option<u32>,
+	/// 显存频率(单位: MHz)
+	pub memory_clock: Option<u32>,
+	/// PCIe 发送速率(单位: KB/s)
+	pub pcie_tx: Option<u32>,
+	/// PCIe 接收速率(单位: KB/s)
+	pub pcie_rx: Option<u32>,
+	/// 驱动版本
+	pub driver_version: Option<String>,
+	/// CUDA 版本
+	pub cuda_version: Option<String>,
+	/// 使用该GPU的进程
+	pub processes: Option<Vec<GpuProcess>>,
 }
 
 impl From<system_info::GpuInfo> for GpuInfo {
 	fn from(gpu_info: system_info::GpuInfo) -> Self {
 		Self {
+			index: gpu_info.index,
 			model: gpu_info.model,
-			memory_total: gpu_info.memory_total.map(|v| v as f64),
-			memory_used: gpu_info.memory_used.map(|v| v as f64),
-			memory_free: gpu_info.memory_free.map(|v| v as f64),
+			vendor: gpu_info.vendor,
+			vendor_id: gpu_info.vendor_id.map(|v| v as u32),
+			device_id: gpu_info.device_id.map(|v| v as u32),
+			driver: gpu_info.driver,
+			pci_bus_id: gpu_info.pci_bus_id,
+			memory_total: gpu_info.memory_total.map(|v| v.as_mib()),
+			memory_used: gpu_info.memory_used.map(|v| v.as_mib()),
+			memory_free: gpu_info.memory_free.map(|v| v.as_mib()),
 			usage: gpu_info.usage.map(|v| v as u32),
+			temperature: gpu_info.temperature.map(|v| v as f64),
+			memory_controller_usage: gpu_info.memory_controller_usage.map(|v| v as u32),
+			encoder_usage: gpu_info.encoder_usage.map(|v| v as u32),
+			decoder_usage: gpu_info.decoder_usage.map(|v| v as u32),
+			fan_speed: gpu_info.fan_speed.map(|v| v as u32),
+			power_usage: gpu_info.power_usage.map(|v| v as f64),
+			power_limit: gpu_info.power_limit.map(|v| v as f64),
+			graphics_clock: gpu_info.graphics_clock,
+			memory_clock: gpu_info.memory_clock,
+			pcie_tx: gpu_info.pcie_tx,
+			pcie_rx: gpu_info.pcie_rx,
+			driver_version: gpu_info.driver_version,
+			cuda_version: gpu_info.cuda_version,
+			processes: gpu_info
+				.processes
+				.map(|processes| processes.into_iter().map(|process| process.into()).collect()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct GpuProcess {
+	/// 进程ID
+	pub pid: u32,
+	/// 占用的显存(单位: MB)
+	pub used_memory: Option<f64>,
+	/// SM 使用率
+	pub usage: Option<u32>,
+}
+
+impl From<system_info::GpuProcess> for GpuProcess {
+	fn from(process: system_info::GpuProcess) -> Self {
+		Self {
+			pid: process.pid,
+			used_memory: process.used_memory.map(|v| v.as_mib()),
+			usage: process.usage.map(|v| v as u32),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct LoadInfo {
+	/// 1分钟平均负载
+	pub one: f64,
+	/// 5分钟平均负载
+	pub five: f64,
+	/// 15分钟平均负载
+	pub fifteen: f64,
+	/// 正在运行的任务数，仅 Linux 下可用
+	pub running_tasks: Option<u32>,
+	/// 任务总数，仅 Linux 下可用
+	pub total_tasks: Option<u32>,
+	/// 压力阻塞信息(PSI)，仅 Linux 下可用
+	pub pressure: Option<PressureInfo>,
+}
+
+impl From<system_info::LoadInfo> for LoadInfo {
+	fn from(load_info: system_info::LoadInfo) -> Self {
+		Self {
+			one: load_info.one,
+			five: load_info.five,
+			fifteen: load_info.fifteen,
+			running_tasks: load_info.running_tasks,
+			total_tasks: load_info.total_tasks,
+			pressure: load_info.pressure.map(|p| p.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct PressureInfo {
+	/// CPU压力
+	pub cpu: Option<Pressure>,
+	/// 内存压力
+	pub memory: Option<Pressure>,
+	/// IO压力
+	pub io: Option<Pressure>,
+}
+
+impl From<system_info::PressureInfo> for PressureInfo {
+	fn from(pressure_info: system_info::PressureInfo) -> Self {
+		Self {
+			cpu: pressure_info.cpu.map(|p| p.into()),
+			memory: pressure_info.memory.map(|p| p.into()),
+			io: pressure_info.io.map(|p| p.into()),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct Pressure {
+	/// 至少一个任务阻塞的时间占比
+	pub some: PressureStat,
+	/// 所有非空闲任务同时阻塞的时间占比
+	pub full: Option<PressureStat>,
+}
+
+impl From<system_info::Pressure> for Pressure {
+	fn from(pressure: system_info::Pressure) -> Self {
+		Self { some: pressure.some.into(), full: pressure.full.map(|p| p.into()) }
+	}
+}
+
+#[derive(Debug, Clone)]
+#[napi(object)]
+pub struct PressureStat {
+	/// 最近10秒的阻塞时间占比(单位: %)
+	pub avg10: f64,
+	/// 最近60秒的阻塞时间占比(单位: %)
+	pub avg60: f64,
+	/// 最近300秒的阻塞时间占比(单位: %)
+	pub avg300: f64,
+	/// 累计阻塞时间(单位: 微秒)
+	pub total: f64,
+}
+
+impl From<system_info::PressureStat> for PressureStat {
+	fn from(stat: system_info::PressureStat) -> Self {
+		Self {
+			avg10: stat.avg10 as f64,
+			avg60: stat.avg60 as f64,
+			avg300: stat.avg300 as f64,
+			total: stat.total as f64,
 		}
 	}
 }
@@ -295,4 +731,6 @@ pub struct AllSystemInfo {
 	pub current_process: ProcessInfo,
 	/// GPU信息，无法检测时为 null
 	pub gpu: Option<GpuInfo>,
+	/// 负载信息
+	pub load: LoadInfo,
 }
@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use system_info::{PartialSnapshot, ProcessInfo, ProcessTree, Subsystem, SystemInfo};
+
+/// 面板使用的模块，GPU 探测开销较大，不在每次刷新时采集
+pub const SUBSYSTEMS: &[Subsystem] = &[
+	Subsystem::Host,
+	Subsystem::Cpu,
+	Subsystem::Memory,
+	Subsystem::Disk,
+	Subsystem::Network,
+	Subsystem::Process,
+	Subsystem::Load,
+];
+
+/// 进程表的排序列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+	Pid,
+	Name,
+	Cpu,
+	Memory,
+	Time,
+}
+
+impl SortKey {
+	/// 默认的排序方向，数值列从大到小，PID 与名称从小到大
+	fn descending_by_default(self) -> bool {
+		matches!(self, Self::Cpu | Self::Memory | Self::Time)
+	}
+
+	fn compare(self, a: &ProcessInfo, b: &ProcessInfo) -> Ordering {
+		match self {
+			Self::Pid => a.pid.cmp(&b.pid),
+			Self::Name => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+			Self::Cpu => a.cpu_usage.unwrap_or(0.0).total_cmp(&b.cpu_usage.unwrap_or(0.0)),
+			Self::Memory => a.used_memory.cmp(&b.used_memory),
+			Self::Time => a.run_time.cmp(&b.run_time),
+		}
+	}
+}
+
+/// 按键对应的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+	/// 按指定列排序，已按该列排序时切换方向
+	Sort(SortKey),
+	/// 切换排序方向
+	Reverse,
+	/// 选中上一个进程
+	Up,
+	/// 选中下一个进程
+	Down,
+	/// 向上翻页
+	PageUp,
+	/// 向下翻页
+	PageDown,
+	/// 选中第一个进程
+	Home,
+	/// 选中最后一个进程
+	End,
+	/// 退出
+	Quit,
+}
+
+impl Action {
+	/// 按键对应的操作，未绑定的按键返回 `None`
+	pub fn from_key(key: KeyEvent) -> Option<Self> {
+		Some(match key.code {
+			KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Self::Quit,
+			KeyCode::Char('q') | KeyCode::Esc => Self::Quit,
+			KeyCode::Char('c') => Self::Sort(SortKey::Cpu),
+			KeyCode::Char('m') => Self::Sort(SortKey::Memory),
+			KeyCode::Char('p') => Self::Sort(SortKey::Pid),
+			KeyCode::Char('n') => Self::Sort(SortKey::Name),
+			KeyCode::Char('t') => Self::Sort(SortKey::Time),
+			KeyCode::Char('r') => Self::Reverse,
+			KeyCode::Up | KeyCode::Char('k') => Self::Up,
+			KeyCode::Down | KeyCode::Char('j') => Self::Down,
+			KeyCode::PageUp => Self::PageUp,
+			KeyCode::PageDown => Self::PageDown,
+			KeyCode::Home | KeyCode::Char('g') => Self::Home,
+			KeyCode::End | KeyCode::Char('G') => Self::End,
+			_ => return None,
+		})
+	}
+}
+
+/// 面板状态：最近一次快照及进程表的排序与选中行
+#[derive(Debug)]
+pub struct App {
+	pub(crate) snapshot: PartialSnapshot,
+	/// 展开子进程并排序后的进程列表
+	pub(crate) processes: Vec<ProcessInfo>,
+	pub(crate) sort: SortKey,
+	pub(crate) descending: bool,
+	pub(crate) selected: usize,
+	quit: bool,
+}
+
+/// 翻页时移动的行数
+const PAGE: usize = 10;
+
+impl App {
+	/// 基于快照创建面板，进程默认按 CPU 使用率从高到低排序
+	pub fn new(snapshot: PartialSnapshot) -> Self {
+		let mut app = Self {
+			snapshot,
+			processes: Vec::new(),
+			sort: SortKey::Cpu,
+			descending: true,
+			selected: 0,
+			quit: false,
+		};
+		app.rebuild_processes();
+		app
+	}
+
+	/// 刷新采样器并更新快照，选中的进程仍存在时保持选中
+	pub fn refresh(&mut self, system_info: &mut SystemInfo) {
+		system_info.refresh(SUBSYSTEMS);
+		self.update(system_info.snapshot_of(SUBSYSTEMS));
+	}
+
+	/// 使用新的快照更新面板，选中的进程仍存在时保持选中
+	pub fn update(&mut self, snapshot: PartialSnapshot) {
+		self.keep_selection(|app| {
+			app.snapshot = snapshot;
+			app.rebuild_processes();
+		});
+	}
+
+	/// 当前快照
+	pub fn snapshot(&self) -> &PartialSnapshot {
+		&self.snapshot
+	}
+
+	/// 按当前排序展开后的进程列表
+	pub fn processes(&self) -> &[ProcessInfo] {
+		&self.processes
+	}
+
+	/// 当前排序列与方向
+	pub fn sort(&self) -> (SortKey, bool) {
+		(self.sort, self.descending)
+	}
+
+	/// 选中的进程
+	pub fn selected(&self) -> Option<&ProcessInfo> {
+		self.processes.get(self.selected)
+	}
+
+	/// 是否已请求退出
+	pub fn should_quit(&self) -> bool {
+		self.quit
+	}
+
+	/// 执行按键对应的操作
+	pub fn apply(&mut self, action: Action) {
+		let last = self.processes.len().saturating_sub(1);
+		match action {
+			Action::Sort(key) => self.keep_selection(|app| {
+				if key == app.sort {
+					app.descending = !app.descending;
+				} else {
+					app.sort = key;
+					app.descending = key.descending_by_default();
+				}
+				app.sort_processes();
+			}),
+			Action::Reverse => self.keep_selection(|app| {
+				app.descending = !app.descending;
+				app.sort_processes();
+			}),
+			Action::Up => self.selected = self.selected.saturating_sub(1),
+			Action::Down => self.selected = (self.selected + 1).min(last),
+			Action::PageUp => self.selected = self.selected.saturating_sub(PAGE),
+			Action::PageDown => self.selected = (self.selected + PAGE).min(last),
+			Action::Home => self.selected = 0,
+			Action::End => self.selected = last,
+			Action::Quit => self.quit = true,
+		}
+	}
+
+	/// 重新排序或更新后仍选中原来的进程，该进程已退出时保持选中行号
+	fn keep_selection(&mut self, f: impl FnOnce(&mut Self)) {
+		let pid = self.selected().map(|process| process.pid);
+		f(self);
+		if let Some(index) =
+			pid.and_then(|pid| self.processes.iter().position(|process| process.pid == pid))
+		{
+			self.selected = index;
+		}
+	}
+
+	fn rebuild_processes(&mut self) {
+		let tree = ProcessTree::from_processes(self.snapshot.processes.clone().unwrap_or_default());
+		self.processes = tree.flatten().into_iter().cloned().collect();
+		self.sort_processes();
+	}
+
+	fn sort_processes(&mut self) {
+		let (sort, descending) = (self.sort, self.descending);
+		self.processes.sort_by(|a, b| {
+			let ordering = sort.compare(a, b);
+			let ordering = if descending { ordering.reverse() } else { ordering };
+			// 相同值按 PID 排序，保证刷新前后顺序稳定
+			ordering.then_with(|| a.pid.cmp(&b.pid))
+		});
+		self.selected = self.selected.min(self.processes.len().saturating_sub(1));
+	}
+}
@@ -0,0 +1,48 @@
+//! 类似 top 的终端系统监控面板
+//!
+//! [App] 保存最近一次快照及进程表的排序与选中状态，并实现了 ratatui 的 [Widget]；
+//! [render_to_string] 可在没有终端的情况下渲染一帧，用于无界面模式与快照测试。
+
+mod app;
+mod ui;
+
+pub use app::{Action, App, SUBSYSTEMS, SortKey};
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::Span;
+use ratatui::widgets::Widget;
+
+/// 将面板渲染到指定大小的缓冲区
+pub fn render_to_buffer(app: &App, width: u16, height: u16) -> Buffer {
+	let area = Rect::new(0, 0, width, height);
+	let mut buffer = Buffer::empty(area);
+	app.render(area, &mut buffer);
+	buffer
+}
+
+/// 将缓冲区转换为纯文本，忽略颜色等样式，每行末尾的空格会被去除
+pub fn buffer_to_string(buffer: &Buffer) -> String {
+	let area = buffer.area;
+	let mut text = String::new();
+	for y in area.top()..area.bottom() {
+		let mut line = String::new();
+		// 宽字符之后被其覆盖的单元格不输出
+		let mut skip = 0;
+		for x in area.left()..area.right() {
+			let symbol = buffer[(x, y)].symbol();
+			if skip == 0 {
+				line.push_str(symbol);
+			}
+			skip = skip.max(Span::raw(symbol).width()).saturating_sub(1);
+		}
+		text.push_str(line.trim_end());
+		text.push('\n');
+	}
+	text
+}
+
+/// 将面板渲染为指定大小的纯文本
+pub fn render_to_string(app: &App, width: u16, height: u16) -> String {
+	buffer_to_string(&render_to_buffer(app, width, height))
+}
@@ -0,0 +1,107 @@
+//! 类似 top 的终端系统监控面板
+//!
+//! 使用 `--headless` 时不接管终端，按间隔将每一帧以纯文本输出到标准输出
+
+use std::io;
+use std::process::ExitCode;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::Parser;
+use ratatui::DefaultTerminal;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+use system_info::{SystemInfo, parse_interval};
+use system_info_tui::{Action, App, SUBSYSTEMS};
+
+#[derive(Debug, Parser)]
+#[command(version, about = "Interactive terminal dashboard for system information")]
+struct Args {
+	/// Seconds between two refreshes of the sampler
+	#[arg(short, long, default_value = "1", value_parser = parse_interval)]
+	interval: Duration,
+	/// Print frames as plain text instead of taking over the terminal
+	#[arg(long)]
+	headless: bool,
+	/// Number of frames to print in headless mode
+	#[arg(long, default_value_t = 1, requires = "headless")]
+	frames: usize,
+	/// Frame size in headless mode, as WIDTHxHEIGHT
+	#[arg(long, default_value = "120x40", requires = "headless", value_parser = parse_size)]
+	size: (u16, u16),
+}
+
+fn parse_size(value: &str) -> Result<(u16, u16), String> {
+	let (width, height) =
+		value.split_once(['x', 'X']).ok_or_else(|| "expected WIDTHxHEIGHT".to_string())?;
+	let parse = |value: &str| match value.trim().parse::<u16>() {
+		Ok(0) => Err("size must be positive".to_string()),
+		Ok(value) => Ok(value),
+		Err(err) => Err(format!("{err}")),
+	};
+	Ok((parse(width)?, parse(height)?))
+}
+
+fn main() -> ExitCode {
+	let args = Args::parse();
+
+	let system_info = SystemInfo::sampled(SUBSYSTEMS);
+	let app = App::new(system_info.snapshot_of(SUBSYSTEMS));
+
+	if args.headless {
+		headless(app, system_info, &args);
+		return ExitCode::SUCCESS;
+	}
+
+	let mut terminal = ratatui::init();
+	let result = run(&mut terminal, app, system_info, args.interval);
+	ratatui::restore();
+	match result {
+		Ok(()) => ExitCode::SUCCESS,
+		Err(err) => {
+			eprintln!("system-info-top: {err}");
+			ExitCode::FAILURE
+		}
+	}
+}
+
+/// 依次输出各帧，帧之间以空行分隔
+fn headless(mut app: App, mut system_info: SystemInfo, args: &Args) {
+	let (width, height) = args.size;
+	for frame in 0..args.frames {
+		if frame > 0 {
+			thread::sleep(args.interval);
+			app.refresh(&mut system_info);
+			println!();
+		}
+		print!("{}", system_info_tui::render_to_string(&app, width, height));
+	}
+}
+
+fn run(
+	terminal: &mut DefaultTerminal,
+	mut app: App,
+	mut system_info: SystemInfo,
+	interval: Duration,
+) -> io::Result<()> {
+	let mut refreshed_at = Instant::now();
+	loop {
+		terminal.draw(|frame| frame.render_widget(&app, frame.area()))?;
+
+		// 等待按键直到下一次刷新
+		let timeout = interval.saturating_sub(refreshed_at.elapsed());
+		if event::poll(timeout)?
+			&& let Event::Key(key) = event::read()?
+			&& key.kind == KeyEventKind::Press
+			&& let Some(action) = Action::from_key(key)
+		{
+			app.apply(action);
+		}
+		if app.should_quit() {
+			return Ok(());
+		}
+		if refreshed_at.elapsed() >= interval {
+			app.refresh(&mut system_info);
+			refreshed_at = Instant::now();
+		}
+	}
+}
@@ -0,0 +1,257 @@
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::symbols;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{
+	Block, Cell, LineGauge, Paragraph, Row, StatefulWidget, Table, TableState, Widget,
+};
+use system_info::{ByteSize, CpuInfo, DiskInfo, MemoryInfo, NetworkInfo, format_duration};
+
+use crate::app::{App, SortKey};
+
+/// 内存、磁盘与网络面板的高度(含边框)
+const RESOURCE_HEIGHT: u16 = 7;
+/// 每列最多显示的逻辑CPU数
+const CORES_PER_COLUMN: usize = 8;
+/// CPU 面板最多分为几列
+const MAX_CORE_COLUMNS: usize = 4;
+
+const HELP: &str = " q quit  ↑/↓ select  c cpu  m memory  p pid  n name  t time  r reverse";
+
+impl Widget for &App {
+	fn render(self, area: Rect, buf: &mut Buffer) {
+		let core_rows = self.snapshot.cpu.as_ref().map_or(0, |cpu| core_layout(cpu.cores.len()).1);
+		let [header, cpu, resources, processes, footer] = Layout::vertical([
+			Constraint::Length(1),
+			Constraint::Length(core_rows as u16 + 2),
+			Constraint::Length(RESOURCE_HEIGHT),
+			Constraint::Min(3),
+			Constraint::Length(1),
+		])
+		.areas(area);
+
+		self.render_header(header, buf);
+		if let Some(info) = &self.snapshot.cpu {
+			render_cpu(info, cpu, buf);
+		}
+		let [memory, disk, network] = Layout::horizontal([Constraint::Fill(1); 3]).areas(resources);
+		if let Some(info) = &self.snapshot.memory {
+			render_memory(info, memory, buf);
+		}
+		if let Some(info) = &self.snapshot.disk {
+			render_disk(info, disk, buf);
+		}
+		if let Some(info) = &self.snapshot.networks {
+			render_network(info, network, buf);
+		}
+		self.render_processes(processes, buf);
+		Paragraph::new(HELP).dark_gray().render(footer, buf);
+	}
+}
+
+impl App {
+	fn render_header(&self, area: Rect, buf: &mut Buffer) {
+		let mut spans = Vec::new();
+		if let Some(host) = &self.snapshot.host {
+			spans.push(Span::from(host.host_name.clone()).bold());
+			spans.push(Span::from(format!("  {} {}", host.os_name, host.os_version)));
+			spans.push(Span::from(format!("  up {}", format_duration(host.uptime))));
+		}
+		if let Some(load) = &self.snapshot.load {
+			spans.push(Span::from(format!(
+				"  load {:.2} {:.2} {:.2}",
+				load.one, load.five, load.fifteen
+			)));
+			if let (Some(running), Some(total)) = (load.running_tasks, load.total_tasks) {
+				spans.push(Span::from(format!("  tasks {running}/{total}")));
+			}
+		}
+		spans.push(Span::from(format!("  {}", self.snapshot.timestamp.format("%H:%M:%S UTC"))));
+		Line::from(spans).render(area, buf);
+	}
+
+	fn render_processes(&self, area: Rect, buf: &mut Buffer) {
+		let header = [
+			("PID", Some(SortKey::Pid)),
+			("NAME", Some(SortKey::Name)),
+			("CPU%", Some(SortKey::Cpu)),
+			// 内存排序时只标记 MEMORY 列
+			("MEM%", None),
+			("MEMORY", Some(SortKey::Memory)),
+			("TIME", Some(SortKey::Time)),
+		]
+		.into_iter()
+		.map(|(title, key)| {
+			let mut title = Line::from(title);
+			if key == Some(self.sort) {
+				title.push_span(if self.descending { "▼" } else { "▲" });
+				title = title.cyan();
+			}
+			// 除名称外均为数值列，与内容一样右对齐
+			if key != Some(SortKey::Name) {
+				title = title.right_aligned();
+			}
+			Cell::from(title)
+		});
+
+		let rows = self.processes.iter().map(|process| {
+			Row::new([
+				Cell::from(Line::from(process.pid.to_string()).right_aligned()),
+				Cell::from(process.name.clone()),
+				Cell::from(Line::from(percent(process.cpu_usage)).right_aligned()),
+				Cell::from(Line::from(percent(process.memory_usage)).right_aligned()),
+				Cell::from(Line::from(process.used_memory.to_string()).right_aligned()),
+				Cell::from(Line::from(format_duration(process.run_time)).right_aligned()),
+			])
+		});
+
+		let table = Table::new(
+			rows,
+			[
+				Constraint::Length(7),
+				Constraint::Fill(1),
+				Constraint::Length(6),
+				Constraint::Length(6),
+				Constraint::Length(11),
+				Constraint::Length(12),
+			],
+		)
+		.header(Row::new(header).add_modifier(Modifier::BOLD))
+		.block(Block::bordered().title(format!(" Processes ({}) ", self.processes.len())))
+		.row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+		.highlight_symbol("> ");
+
+		let mut state = TableState::default()
+			.with_selected((!self.processes.is_empty()).then_some(self.selected));
+		StatefulWidget::render(table, area, buf, &mut state);
+	}
+}
+
+/// 按逻辑CPU数计算列数与行数
+fn core_layout(cores: usize) -> (usize, usize) {
+	let columns = cores.div_ceil(CORES_PER_COLUMN).clamp(1, MAX_CORE_COLUMNS);
+	(columns, cores.div_ceil(columns))
+}
+
+fn render_cpu(cpu: &CpuInfo, area: Rect, buf: &mut Buffer) {
+	let mut title = format!(" CPU  {}  {}%", cpu.model_name, percent(cpu.usage));
+	if let Some(temperature) = cpu.temperature {
+		title.push_str(&format!("  {temperature:.1}°C"));
+	}
+	title.push(' ');
+	let block = Block::bordered().title(title);
+	let inner = block.inner(area);
+	block.render(area, buf);
+
+	let (columns, rows) = core_layout(cpu.cores.len());
+	let column_areas =
+		Layout::horizontal(vec![Constraint::Fill(1); columns]).spacing(2).split(inner);
+	for (index, core) in cpu.cores.iter().enumerate() {
+		let (column, row) = (index / rows, index % rows);
+		let Some(&column_area) = column_areas.get(column) else {
+			break;
+		};
+		if row as u16 >= column_area.height {
+			continue;
+		}
+		let cell = Rect { y: column_area.y + row as u16, height: 1, ..column_area };
+		gauge(format!("{:>3} {:>5.1}%", core.id, core.usage), core.usage as f64 / 100.0)
+			.render(cell, buf);
+	}
+}
+
+fn render_memory(memory: &MemoryInfo, area: Rect, buf: &mut Buffer) {
+	let block = Block::bordered().title(" Memory ");
+	let inner = block.inner(area);
+	block.render(area, buf);
+
+	let [mem, swap, available, cached] = Layout::vertical([Constraint::Length(1); 4]).areas(inner);
+	gauge(
+		format!("Mem  {}/{}", memory.used, memory.total),
+		memory.used.percent_of(memory.total) / 100.0,
+	)
+	.render(mem, buf);
+	match (memory.swap_total, memory.swap_used) {
+		(Some(total), Some(used)) if total > ByteSize::ZERO => {
+			gauge(format!("Swap {used}/{total}"), used.percent_of(total) / 100.0).render(swap, buf);
+		}
+		_ => Line::from("Swap -").render(swap, buf),
+	}
+	Line::from(format!("Available {}", bytes(memory.available))).render(available, buf);
+	Line::from(format!("Cached    {}", bytes(memory.cached))).render(cached, buf);
+}
+
+fn render_disk(disk: &DiskInfo, area: Rect, buf: &mut Buffer) {
+	let block = Block::bordered().title(" Disk ");
+	let inner = block.inner(area);
+	block.render(area, buf);
+
+	let mut lines = inner.rows();
+	if let Some(row) = lines.next() {
+		Line::from(vec![
+			Span::from("Read  ").green(),
+			Span::from(ByteSize::display_rate(disk.read_bytes_per_second).to_string()),
+		])
+		.render(row, buf);
+	}
+	if let Some(row) = lines.next() {
+		Line::from(vec![
+			Span::from("Write ").red(),
+			Span::from(ByteSize::display_rate(disk.write_bytes_per_second).to_string()),
+		])
+		.render(row, buf);
+	}
+	for (detail, row) in disk.disks.iter().filter(|detail| !detail.is_pseudo()).zip(lines) {
+		gauge(format!("{:>5.1}% {}", detail.usage, detail.mount), detail.usage as f64 / 100.0)
+			.render(row, buf);
+	}
+}
+
+fn render_network(networks: &[NetworkInfo], area: Rect, buf: &mut Buffer) {
+	let block = Block::bordered().title(" Network ");
+	let inner = block.inner(area);
+	block.render(area, buf);
+
+	let mut networks: Vec<&NetworkInfo> = networks.iter().collect();
+	networks.sort_by(|a, b| a.name.cmp(&b.name));
+	let width = networks.iter().map(|network| network.name.chars().count()).max().unwrap_or(0);
+	for (network, row) in networks.into_iter().zip(inner.rows()) {
+		Line::from(vec![
+			Span::from(format!("{:<width$} ", network.name)),
+			Span::from("↓ ").green(),
+			Span::from(format!(
+				"{:<12}",
+				ByteSize::display_rate(network.download_bytes_per_second).to_string()
+			)),
+			Span::from("↑ ").red(),
+			Span::from(ByteSize::display_rate(network.upload_bytes_per_second).to_string()),
+		])
+		.render(row, buf);
+	}
+}
+
+/// 单行进度条，颜色随占比由绿变红
+fn gauge(label: String, ratio: f64) -> LineGauge<'static> {
+	let ratio = if ratio.is_finite() { ratio.clamp(0.0, 1.0) } else { 0.0 };
+	let color = match ratio {
+		ratio if ratio >= 0.85 => Color::Red,
+		ratio if ratio >= 0.6 => Color::Yellow,
+		_ => Color::Green,
+	};
+	LineGauge::default()
+		.label(label)
+		.ratio(ratio)
+		.filled_symbol(symbols::line::THICK_HORIZONTAL)
+		.unfilled_symbol(symbols::line::HORIZONTAL)
+		.filled_style(Style::new().fg(color))
+		.unfilled_style(Style::new().fg(Color::DarkGray))
+}
+
+fn percent(value: Option<f32>) -> String {
+	value.map_or_else(|| "-".to_string(), |value| format!("{value:.1}"))
+}
+
+fn bytes(value: Option<ByteSize>) -> String {
+	value.map_or_else(|| "-".to_string(), |value| value.to_string())
+}
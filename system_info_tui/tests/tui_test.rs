@@ -0,0 +1,157 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use system_info::PartialSnapshot;
+use system_info_tui::{Action, App, SortKey};
+
+fn fixture(name: &str) -> PathBuf {
+	PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn app() -> App {
+	let json = std::fs::read_to_string(fixture("snapshot.json")).unwrap();
+	let snapshot: PartialSnapshot = serde_json::from_str(&json).unwrap();
+	App::new(snapshot)
+}
+
+fn pids(app: &App) -> Vec<u32> {
+	app.processes().iter().map(|process| process.pid.as_u32()).collect()
+}
+
+/// 与 `tests/fixtures` 下的帧比较，设置 `UPDATE_SNAPSHOTS=1` 时改为写入当前输出
+fn assert_frame(name: &str, frame: &str) {
+	let path = fixture(name);
+	if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+		std::fs::write(&path, frame).unwrap();
+		return;
+	}
+	let expected = std::fs::read_to_string(&path).unwrap();
+	assert!(expected == frame, "frame differs from {}:\n{frame}", path.display());
+}
+
+#[test]
+fn test_render_frame() {
+	let app = app();
+	assert_frame("frame.txt", &system_info_tui::render_to_string(&app, 100, 30));
+}
+
+#[test]
+fn test_render_sorted_selection() {
+	let mut app = app();
+	app.apply(Action::Sort(SortKey::Memory));
+	app.apply(Action::Down);
+	assert_frame("frame_memory.txt", &system_info_tui::render_to_string(&app, 100, 30));
+}
+
+#[test]
+fn test_render_small_area() {
+	let app = app();
+	for (width, height) in [(0, 0), (1, 1), (20, 5), (40, 12)] {
+		let frame = system_info_tui::render_to_string(&app, width, height);
+		assert_eq!(frame.lines().count(), height as usize);
+	}
+}
+
+#[test]
+fn test_sort() {
+	let mut app = app();
+	// 子进程展开到同一层，默认按 CPU 使用率从高到低
+	assert_eq!(app.sort(), (SortKey::Cpu, true));
+	assert_eq!(pids(&app), [412, 2301, 880, 2210, 1, 2, 530, 881]);
+
+	app.apply(Action::Sort(SortKey::Memory));
+	assert_eq!(app.sort(), (SortKey::Memory, true));
+	assert_eq!(pids(&app), [412, 880, 2301, 881, 1, 530, 2210, 2]);
+
+	// 再次选择同一列时切换方向
+	app.apply(Action::Sort(SortKey::Memory));
+	assert_eq!(app.sort(), (SortKey::Memory, false));
+	assert_eq!(pids(&app).first(), Some(&2));
+
+	app.apply(Action::Sort(SortKey::Name));
+	assert_eq!(app.sort(), (SortKey::Name, false));
+	assert_eq!(app.processes()[0].name, "bash");
+
+	app.apply(Action::Reverse);
+	assert_eq!(app.sort(), (SortKey::Name, true));
+	assert_eq!(app.processes()[0].name, "vacuumdb");
+
+	app.apply(Action::Sort(SortKey::Pid));
+	assert_eq!(pids(&app), [1, 2, 412, 530, 880, 881, 2210, 2301]);
+}
+
+#[test]
+fn test_selection() {
+	let mut app = app();
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 412);
+	app.apply(Action::Up);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 412);
+	app.apply(Action::Down);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 2301);
+
+	// 重新排序后仍选中同一进程
+	app.apply(Action::Sort(SortKey::Pid));
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 2301);
+
+	app.apply(Action::Home);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 1);
+	app.apply(Action::PageDown);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 2301);
+	app.apply(Action::PageUp);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 1);
+	app.apply(Action::End);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 2301);
+
+	// 刷新后选中的进程已退出时保持行号
+	let mut snapshot = app.snapshot().clone();
+	let processes = snapshot.processes.as_mut().unwrap();
+	processes[0].sub_list.as_mut().unwrap()[1].sub_list = None;
+	app.update(snapshot);
+	assert_eq!(app.processes().len(), 6);
+	assert_eq!(app.selected().unwrap().pid.as_u32(), 881);
+
+	app.update(PartialSnapshot { processes: Some(Vec::new()), ..app.snapshot().clone() });
+	assert!(app.selected().is_none());
+	app.apply(Action::Down);
+	assert!(app.selected().is_none());
+}
+
+#[test]
+fn test_key_bindings() {
+	let key = |code| Action::from_key(KeyEvent::new(code, KeyModifiers::NONE));
+	assert_eq!(key(KeyCode::Char('q')), Some(Action::Quit));
+	assert_eq!(key(KeyCode::Char('c')), Some(Action::Sort(SortKey::Cpu)));
+	assert_eq!(key(KeyCode::Char('m')), Some(Action::Sort(SortKey::Memory)));
+	assert_eq!(key(KeyCode::Down), Some(Action::Down));
+	assert_eq!(key(KeyCode::Char('x')), None);
+	assert_eq!(
+		Action::from_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+		Some(Action::Quit)
+	);
+
+	let mut app = app();
+	assert!(!app.should_quit());
+	app.apply(Action::Quit);
+	assert!(app.should_quit());
+}
+
+#[test]
+fn test_headless_binary() {
+	let output = Command::new(env!("CARGO_BIN_EXE_system-info-top"))
+		.args(["--headless", "--size", "80x24", "--frames", "2", "--interval", "0.2"])
+		.output()
+		.unwrap();
+	assert!(output.status.success());
+	let stdout = String::from_utf8(output.stdout).unwrap();
+	// 两帧之间以空行分隔
+	assert_eq!(stdout.lines().count(), 24 * 2 + 1);
+	assert_eq!(stdout.matches("Processes (").count(), 2);
+	assert!(stdout.lines().all(|line| line.chars().count() <= 80));
+
+	let output = Command::new(env!("CARGO_BIN_EXE_system-info-top"))
+		.args(["--headless", "--size", "80"])
+		.output()
+		.unwrap();
+	assert!(!output.status.success());
+}